@@ -1,6 +1,6 @@
 use core::{convert::TryFrom, fmt, num::NonZeroU32};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug)]
 #[allow(clippy::module_name_repetitions)]
@@ -16,7 +16,7 @@ impl fmt::Display for PartitionRankOutOfBounds {
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(try_from = "PartitionRaw")]
 #[allow(clippy::module_name_repetitions)]
 pub struct Partition {
@@ -176,8 +176,7 @@ pub trait PeekableActiveLineageSampler<
     )]
     fn peek_time_of_next_event(
         &mut self,
-        habitat: &H,
-        turnover_rate: &T,
+        simulation: &PartialSimulation<H, G, R, S, X, D, C, T, N, E>,
         rng: &mut G,
     ) -> Result<PositiveF64, EmptyActiveLineageSamplerError>;
 }
@@ -199,8 +198,7 @@ pub trait OptionallyPeekableActiveLineageSampler<
 {
     fn peek_optional_time_of_next_event(
         &mut self,
-        habitat: &H,
-        turnover_rate: &T,
+        simulation: &PartialSimulation<H, G, R, S, X, D, C, T, N, E>,
         rng: &mut G,
     ) -> Option<PositiveF64>;
 }
@@ -222,8 +220,7 @@ impl<
 {
     default fn peek_optional_time_of_next_event(
         &mut self,
-        _habitat: &H,
-        _turnover_rate: &T,
+        _simulation: &PartialSimulation<H, G, R, S, X, D, C, T, N, E>,
         _rng: &mut G,
     ) -> Option<PositiveF64> {
         None
@@ -247,11 +244,9 @@ impl<
 {
     fn peek_optional_time_of_next_event(
         &mut self,
-        habitat: &H,
-        turnover_rate: &T,
+        simulation: &PartialSimulation<H, G, R, S, X, D, C, T, N, E>,
         rng: &mut G,
     ) -> Option<PositiveF64> {
-        self.peek_time_of_next_event(habitat, turnover_rate, rng)
-            .ok()
+        self.peek_time_of_next_event(simulation, rng).ok()
     }
 }
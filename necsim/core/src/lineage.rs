@@ -18,9 +18,18 @@ use crate::{
 #[repr(transparent)]
 pub struct GlobalLineageReference(NonZeroOneU64);
 
+impl GlobalLineageReference {
+    /// Returns the numeric identifier of this lineage, which is also used
+    ///  when the reference is displayed or serialised
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        self.0.get() - 2
+    }
+}
+
 impl fmt::Display for GlobalLineageReference {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0.get() - 2)
+        write!(f, "{}", self.id())
     }
 }
 
@@ -34,7 +43,7 @@ impl<'de> Deserialize<'de> for GlobalLineageReference {
 
 impl Serialize for GlobalLineageReference {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        (self.0.get() - 2).serialize(serializer)
+        self.id().serialize(serializer)
     }
 }
 
@@ -222,3 +231,35 @@ impl PartialEq<BackedUp<Self>> for MigratingLineage {
         self.eq(&**other)
     }
 }
+
+/// Lineage which was still active when the simulation stopped early, e.g.
+///  since it reached one of its limits, and whose ancestry is thus unknown
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnresolvedLineage {
+    pub global_reference: GlobalLineageReference,
+    pub location: Location,
+    pub last_event_time: NonNegativeF64,
+}
+
+impl UnresolvedLineage {
+    /// Returns the unresolved state of the `lineage` iff it is still active
+    #[must_use]
+    pub fn from_active_lineage(lineage: &Lineage) -> Option<Self> {
+        lineage.indexed_location().map(|indexed_location| Self {
+            global_reference: lineage.global_reference().clone(),
+            location: indexed_location.location().clone(),
+            last_event_time: lineage.last_event_time(),
+        })
+    }
+}
+
+impl From<MigratingLineage> for UnresolvedLineage {
+    fn from(immigrant: MigratingLineage) -> Self {
+        Self {
+            global_reference: immigrant.global_reference,
+            location: immigrant.dispersal_target,
+            last_event_time: immigrant.event_time.into(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     impl_finalise, impl_report,
+    lineage::UnresolvedLineage,
     reporter::{boolean::Or, Reporter},
 };
 
@@ -56,10 +57,16 @@ where
     });
 
     impl_finalise!((self) {
-        self.front.finalise();
-        self.tail.finalise();
+        let mut summary = self.front.finalise();
+        summary.merge(self.tail.finalise());
+        summary
     });
 
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        self.front.report_unresolved(lineage);
+        self.tail.report_unresolved(lineage);
+    }
+
     fn initialise(&mut self) -> Result<(), alloc::string::String> {
         self.front.initialise().and_then(|_| self.tail.initialise())
     }
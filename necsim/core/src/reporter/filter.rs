@@ -4,9 +4,10 @@ use alloc::fmt;
 
 use crate::{
     impl_finalise, impl_report,
+    lineage::UnresolvedLineage,
     reporter::{
         boolean::{And, Boolean},
-        Reporter,
+        Reporter, ReporterSummary,
     },
 };
 
@@ -69,10 +70,18 @@ where
 
     impl_finalise!((self) {
         if Self::ReportSpeciation::VALUE || Self::ReportDispersal::VALUE || Self::ReportProgress::VALUE {
-            self.reporter.finalise();
+            self.reporter.finalise()
+        } else {
+            ReporterSummary::default()
         }
     });
 
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        if Self::ReportSpeciation::VALUE || Self::ReportDispersal::VALUE {
+            self.reporter.report_unresolved(lineage);
+        }
+    }
+
     fn initialise(&mut self) -> Result<(), alloc::string::String> {
         if Self::ReportSpeciation::VALUE
             || Self::ReportDispersal::VALUE
@@ -69,24 +69,28 @@ macro_rules! impl_report {
 macro_rules! impl_finalise {
     ($(#[$metas:meta])* ($self:ident) $code:block) => {
         $(#[$metas])*
-        fn finalise($self) where Self:Sized {
-            $code
+        fn finalise($self) -> $crate::reporter::ReporterSummary where Self:Sized {
+            $crate::reporter::ReporterSummary::from($code)
         }
 
         $(#[$metas])*
-        unsafe fn finalise_boxed($self: $crate::alloc::boxed::Box<Self>) {
-            $code
+        unsafe fn finalise_boxed(
+            $self: $crate::alloc::boxed::Box<Self>
+        ) -> $crate::reporter::ReporterSummary {
+            $crate::reporter::ReporterSummary::from($code)
         }
     };
     ($(#[$metas:meta])* (mut $self:ident) $code:block) => {
         $(#[$metas])*
-        fn finalise(mut $self) where Self:Sized {
-            $code
+        fn finalise(mut $self) -> $crate::reporter::ReporterSummary where Self:Sized {
+            $crate::reporter::ReporterSummary::from($code)
         }
 
         $(#[$metas])*
-        unsafe fn finalise_boxed(mut $self: $crate::alloc::boxed::Box<Self>) {
-            $code
+        unsafe fn finalise_boxed(
+            mut $self: $crate::alloc::boxed::Box<Self>
+        ) -> $crate::reporter::ReporterSummary {
+            $crate::reporter::ReporterSummary::from($code)
         }
     };
 }
@@ -1,10 +1,14 @@
-use crate::event::{DispersalEvent, SpeciationEvent};
+use crate::{
+    event::{DispersalEvent, SpeciationEvent},
+    lineage::UnresolvedLineage,
+};
 
 mod combinator;
 mod filter;
 mod group;
 mod r#impl;
 mod null;
+mod summary;
 
 use boolean::Boolean;
 use used::MaybeUsed;
@@ -18,6 +22,8 @@ pub use combinator::ReporterCombinator;
 pub use filter::FilteredReporter;
 #[allow(clippy::module_name_repetitions)]
 pub use null::NullReporter;
+#[allow(clippy::module_name_repetitions)]
+pub use summary::{ReporterSummary, SummaryValue};
 
 pub trait Reporter: core::fmt::Debug {
     type ReportSpeciation: Boolean;
@@ -30,6 +36,12 @@ pub trait Reporter: core::fmt::Debug {
 
     fn report_progress(&mut self, remaining: &MaybeUsed<u64, Self::ReportProgress>);
 
+    /// This `report_unresolved` hook is called once for every lineage which
+    /// was still active when the simulation stopped early, e.g. since it
+    /// reached one of its limits. It is called after all events have been
+    /// reported.
+    fn report_unresolved(&mut self, _lineage: &UnresolvedLineage) {}
+
     /// This `initialise` hook can be used to commit to make final
     /// initialisation steps which have side effects.
     ///
@@ -41,18 +53,25 @@ pub trait Reporter: core::fmt::Debug {
         Ok(())
     }
 
-    fn finalise(self)
+    /// This `finalise` hook can be used to report the final results of
+    /// the reporter, which can also be returned as a structured summary.
+    fn finalise(self) -> ReporterSummary
     where
         Self: Sized,
     {
         core::mem::drop(self);
+
+        ReporterSummary::default()
     }
 
     /// # Safety
     ///
     /// This method should not be implemented manually
     //  please - use the`impl_finalise` macro instead.
-    unsafe fn finalise_boxed(self: alloc::boxed::Box<Self>) {
+    #[must_use]
+    unsafe fn finalise_boxed(self: alloc::boxed::Box<Self>) -> ReporterSummary {
         core::mem::drop(self);
+
+        ReporterSummary::default()
     }
 }
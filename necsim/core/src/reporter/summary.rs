@@ -0,0 +1,135 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use serde::Serialize;
+
+/// Structured summary of the results which a `Reporter` has gathered and
+///  returns from `finalise`.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct ReporterSummary {
+    entries: BTreeMap<String, SummaryValue>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum SummaryValue {
+    Bool(bool),
+    Unsigned(u64),
+    Float(f64),
+    String(String),
+    List(Vec<SummaryValue>),
+    Summary(ReporterSummary),
+}
+
+impl ReporterSummary {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with<K: Into<String>, V: Into<SummaryValue>>(mut self, key: K, value: V) -> Self {
+        self.insert(key, value);
+
+        self
+    }
+
+    /// Inserts the `value` under `key`. If the `key` already exists, the
+    ///  values are collected in a `SummaryValue::List`.
+    pub fn insert<K: Into<String>, V: Into<SummaryValue>>(&mut self, key: K, value: V) {
+        let key = key.into();
+        let value = value.into();
+
+        let value = match self.entries.remove(&key) {
+            None => value,
+            Some(SummaryValue::List(mut values)) => {
+                values.push(value);
+
+                SummaryValue::List(values)
+            },
+            Some(previous) => SummaryValue::List(alloc::vec![previous, value]),
+        };
+
+        self.entries.insert(key, value);
+    }
+
+    /// Merges all entries from `other` into `self`, see `insert`.
+    pub fn merge(&mut self, other: Self) {
+        for (key, value) in other.entries {
+            self.insert(key, value);
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&SummaryValue> {
+        self.entries.get(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SummaryValue)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+/// Reporters which do not produce a summary return an empty one.
+impl From<()> for ReporterSummary {
+    fn from(_: ()) -> Self {
+        Self::default()
+    }
+}
+
+impl From<bool> for SummaryValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<u64> for SummaryValue {
+    fn from(value: u64) -> Self {
+        Self::Unsigned(value)
+    }
+}
+
+impl From<usize> for SummaryValue {
+    fn from(value: usize) -> Self {
+        Self::Unsigned(value as u64)
+    }
+}
+
+impl From<f64> for SummaryValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<String> for SummaryValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for SummaryValue {
+    fn from(value: &str) -> Self {
+        Self::String(String::from(value))
+    }
+}
+
+impl<V: Into<SummaryValue>> From<Vec<V>> for SummaryValue {
+    fn from(values: Vec<V>) -> Self {
+        Self::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<ReporterSummary> for SummaryValue {
+    fn from(summary: ReporterSummary) -> Self {
+        Self::Summary(summary)
+    }
+}
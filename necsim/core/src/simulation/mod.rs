@@ -13,6 +13,7 @@ use crate::{
         OptionallyPeekableActiveLineageSampler, PeekableActiveLineageSampler, RngCore,
         SpeciationProbability, TurnoverRate,
     },
+    lineage::UnresolvedLineage,
     reporter::Reporter,
 };
 
@@ -42,8 +43,11 @@ impl<
             .peek_next_immigration()
             .map(|migrating_lineage| migrating_lineage.event_time);
         let next_local_time = self
-            .active_lineage_sampler
-            .peek_time_of_next_event(&self.habitat, &self.turnover_rate, &mut self.rng)
+            .with_mut_split_active_lineage_sampler_and_rng(
+                |active_lineage_sampler, simulation, rng| {
+                    active_lineage_sampler.peek_time_of_next_event(simulation, rng)
+                },
+            )
             .ok();
 
         match (next_immigration_time, next_local_time) {
@@ -71,9 +75,12 @@ impl<
         A: ActiveLineageSampler<H, G, R, S, X, D, C, T, N, E, I>,
     > Simulation<H, G, R, S, X, D, C, T, N, E, I, A>
 {
+    pub fn number_active_lineages(&self) -> usize {
+        self.active_lineage_sampler().number_active_lineages()
+    }
+
     pub fn get_balanced_remaining_work(&self) -> Wrapping<u64> {
-        let local_remaining =
-            Wrapping(self.active_lineage_sampler().number_active_lineages() as u64);
+        let local_remaining = Wrapping(self.number_active_lineages() as u64);
 
         local_remaining + self.migration_balance
     }
@@ -92,11 +99,7 @@ impl<
             // Peek the time of the next local event
             let optional_next_event_time = self.with_mut_split_active_lineage_sampler_and_rng(
                 |active_lineage_sampler, simulation, rng| {
-                    active_lineage_sampler.peek_optional_time_of_next_event(
-                        &simulation.habitat,
-                        &simulation.turnover_rate,
-                        rng,
-                    )
+                    active_lineage_sampler.peek_optional_time_of_next_event(simulation, rng)
                 },
             );
 
@@ -124,6 +127,26 @@ impl<
         (self.active_lineage_sampler.get_last_event_time(), steps)
     }
 
+    /// Reports all lineages which are still active, including those which
+    ///  are still waiting to immigrate, as unresolved. This method should
+    ///  only be called once the simulation has stopped early, e.g. since it
+    ///  reached one of its limits.
+    pub fn report_unresolved_lineages<P: Reporter>(&mut self, reporter: &mut P) {
+        for reference in self.lineage_store.iter_local_lineage_references() {
+            if let Some(lineage) = self
+                .lineage_store
+                .get(reference)
+                .and_then(UnresolvedLineage::from_active_lineage)
+            {
+                reporter.report_unresolved(&lineage);
+            }
+        }
+
+        while let Some(immigrant) = self.immigration_entry.next_optional_immigration(None) {
+            reporter.report_unresolved(&immigrant.into());
+        }
+    }
+
     #[inline]
     pub fn simulate<P: Reporter>(mut self, reporter: &mut P) -> (NonNegativeF64, u64, G) {
         let (time, steps) = self.simulate_incremental_early_stop(|_, _| false, reporter);
@@ -96,8 +96,7 @@ impl<
 
         // The next event time must be calculated before the next active lineage is
         //  popped
-        let optional_next_event_time =
-            self.peek_time_of_next_event(&simulation.habitat, &simulation.turnover_rate, rng);
+        let optional_next_event_time = self.peek_time_of_next_event(simulation, rng);
 
         let (next_event_time, last_active_lineage_reference) = match (
             optional_next_event_time,
@@ -272,10 +271,31 @@ impl<
         I,
     > for ClassicalActiveLineageSampler<H, G, R, S, X, D, N, I>
 {
+    #[allow(clippy::type_complexity)]
     fn peek_time_of_next_event(
         &mut self,
-        _habitat: &H,
-        _turnover_rate: &UniformTurnoverRate,
+        _simulation: &PartialSimulation<
+            H,
+            G,
+            R,
+            S,
+            X,
+            D,
+            UnconditionalCoalescenceSampler<H, R, S>,
+            UniformTurnoverRate,
+            N,
+            UnconditionalEventSampler<
+                H,
+                G,
+                R,
+                S,
+                X,
+                D,
+                UnconditionalCoalescenceSampler<H, R, S>,
+                UniformTurnoverRate,
+                N,
+            >,
+        >,
         rng: &mut G,
     ) -> Result<PositiveF64, EmptyActiveLineageSamplerError> {
         use necsim_core::cogs::RngSampler;
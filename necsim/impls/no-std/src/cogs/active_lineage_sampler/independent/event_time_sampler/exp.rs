@@ -1,5 +1,5 @@
 use necsim_core::{
-    cogs::{Habitat, HabitatPrimeableRng, PrimeableRng, RngSampler, TurnoverRate},
+    cogs::{Habitat, HabitatPrimeableRng, PrimeableRng, RngSampler},
     intrinsics::floor,
     landscape::IndexedLocation,
 };
@@ -25,9 +25,7 @@ impl ExpEventTimeSampler {
 }
 
 #[contract_trait]
-impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
-    for ExpEventTimeSampler
-{
+impl<H: Habitat, G: PrimeableRng> EventTimeSampler<H, G> for ExpEventTimeSampler {
     #[inline]
     fn next_event_time_at_indexed_location_weakly_after(
         &self,
@@ -35,20 +33,8 @@ impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
         time: NonNegativeF64,
         habitat: &H,
         rng: &mut G,
-        turnover_rate: &T,
+        lambda: PositiveF64,
     ) -> NonNegativeF64 {
-        // Safety: The turnover rate is >= 0.0 and must actually be > 0.0 because
-        //  * `indexed_location` is habitable by this method's precondition
-        //  * Therefore, `turnover_rate` must return a positive value by its
-        //    postcondition
-        let lambda = unsafe {
-            PositiveF64::new_unchecked(
-                turnover_rate
-                    .get_turnover_rate_at_location(indexed_location.location(), habitat)
-                    .get(),
-            )
-        };
-
         #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
         let mut time_step = floor(time.get() / self.delta_t.get()) as u64;
 
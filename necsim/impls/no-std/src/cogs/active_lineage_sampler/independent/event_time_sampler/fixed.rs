@@ -1,9 +1,9 @@
 use necsim_core::{
-    cogs::{Habitat, HabitatPrimeableRng, PrimeableRng, TurnoverRate},
+    cogs::{Habitat, HabitatPrimeableRng, PrimeableRng},
     intrinsics::floor,
     landscape::IndexedLocation,
 };
-use necsim_core_bond::NonNegativeF64;
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 use super::EventTimeSampler;
 
@@ -19,9 +19,7 @@ impl Default for FixedEventTimeSampler {
 }
 
 #[contract_trait]
-impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
-    for FixedEventTimeSampler
-{
+impl<H: Habitat, G: PrimeableRng> EventTimeSampler<H, G> for FixedEventTimeSampler {
     #[inline]
     fn next_event_time_at_indexed_location_weakly_after(
         &self,
@@ -29,17 +27,14 @@ impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
         time: NonNegativeF64,
         habitat: &H,
         rng: &mut G,
-        turnover_rate: &T,
+        lambda: PositiveF64,
     ) -> NonNegativeF64 {
-        let lambda =
-            turnover_rate.get_turnover_rate_at_location(indexed_location.location(), habitat);
-
         #[allow(clippy::cast_possible_truncation)]
         #[allow(clippy::cast_sign_loss)]
         let time_step = floor(time.get() * lambda.get()) as u64 + 1;
 
         rng.prime_with_habitat(habitat, indexed_location, time_step);
 
-        NonNegativeF64::from(time_step) / lambda
+        NonNegativeF64::from(time_step) / NonNegativeF64::from(lambda)
     }
 }
@@ -1,5 +1,5 @@
 use necsim_core::{
-    cogs::{Habitat, HabitatPrimeableRng, PrimeableRng, RngSampler, TurnoverRate},
+    cogs::{Habitat, HabitatPrimeableRng, PrimeableRng, RngSampler},
     intrinsics::{floor, neg_exp},
     landscape::IndexedLocation,
 };
@@ -22,9 +22,7 @@ impl GeometricEventTimeSampler {
 }
 
 #[contract_trait]
-impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
-    for GeometricEventTimeSampler
-{
+impl<H: Habitat, G: PrimeableRng> EventTimeSampler<H, G> for GeometricEventTimeSampler {
     #[inline]
     fn next_event_time_at_indexed_location_weakly_after(
         &self,
@@ -32,13 +30,9 @@ impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
         time: NonNegativeF64,
         habitat: &H,
         rng: &mut G,
-        turnover_rate: &T,
+        lambda: PositiveF64,
     ) -> NonNegativeF64 {
-        let event_probability_per_step = neg_exp(
-            turnover_rate.get_turnover_rate_at_location(indexed_location.location(), habitat)
-                * self.delta_t,
-        )
-        .one_minus();
+        let event_probability_per_step = neg_exp((lambda * self.delta_t).into()).one_minus();
 
         #[allow(clippy::cast_possible_truncation)]
         #[allow(clippy::cast_sign_loss)]
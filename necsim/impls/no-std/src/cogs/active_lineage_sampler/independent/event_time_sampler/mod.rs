@@ -1,8 +1,8 @@
 use necsim_core::{
-    cogs::{Habitat, PrimeableRng, TurnoverRate},
+    cogs::{Habitat, PrimeableRng},
     landscape::IndexedLocation,
 };
-use necsim_core_bond::NonNegativeF64;
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 pub mod exp;
 pub mod fixed;
@@ -12,9 +12,7 @@ pub mod poisson;
 #[allow(clippy::module_name_repetitions)]
 #[allow(clippy::inline_always, clippy::inline_fn_without_body)]
 #[contract_trait]
-pub trait EventTimeSampler<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>>:
-    Clone + core::fmt::Debug
-{
+pub trait EventTimeSampler<H: Habitat, G: PrimeableRng>: Clone + core::fmt::Debug {
     #[debug_requires(
         habitat.get_habitat_at_location(indexed_location.location()) > 0,
         "indexed_location must be habitable"
@@ -26,6 +24,6 @@ pub trait EventTimeSampler<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>>:
         time: NonNegativeF64,
         habitat: &H,
         rng: &mut G,
-        turnover_rate: &T,
+        lambda: PositiveF64,
     ) -> NonNegativeF64;
 }
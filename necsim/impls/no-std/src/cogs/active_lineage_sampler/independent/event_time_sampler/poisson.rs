@@ -1,5 +1,5 @@
 use necsim_core::{
-    cogs::{Habitat, HabitatPrimeableRng, PrimeableRng, RngSampler, TurnoverRate},
+    cogs::{Habitat, HabitatPrimeableRng, PrimeableRng, RngSampler},
     intrinsics::{exp, floor, safe_sqrt},
     landscape::IndexedLocation,
 };
@@ -25,9 +25,7 @@ impl PoissonEventTimeSampler {
 }
 
 #[contract_trait]
-impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
-    for PoissonEventTimeSampler
-{
+impl<H: Habitat, G: PrimeableRng> EventTimeSampler<H, G> for PoissonEventTimeSampler {
     #[inline]
     fn next_event_time_at_indexed_location_weakly_after(
         &self,
@@ -35,10 +33,8 @@ impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
         time: NonNegativeF64,
         habitat: &H,
         rng: &mut G,
-        turnover_rate: &T,
+        lambda: PositiveF64,
     ) -> NonNegativeF64 {
-        let lambda =
-            turnover_rate.get_turnover_rate_at_location(indexed_location.location(), habitat);
         let lambda_per_step = lambda * self.delta_t;
         let no_event_probability_per_step = exp(-lambda_per_step.get());
 
@@ -68,7 +64,7 @@ impl<H: Habitat, G: PrimeableRng, T: TurnoverRate<H>> EventTimeSampler<H, G, T>
                 // Fallback in case no_event_probability_per_step underflows
                 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
                 let normal_as_poisson = rng
-                    .sample_2d_normal(lambda_per_step.get(), safe_sqrt(lambda_per_step))
+                    .sample_2d_normal(lambda_per_step.get(), safe_sqrt(lambda_per_step.into()))
                     .0
                     .max(0.0_f64) as u32;
 
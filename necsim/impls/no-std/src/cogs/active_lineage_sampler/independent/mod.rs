@@ -35,7 +35,7 @@ pub struct IndependentActiveLineageSampler<
     D: DispersalSampler<H, G>,
     T: TurnoverRate<H>,
     N: SpeciationProbability<H>,
-    J: EventTimeSampler<H, G, T>,
+    J: EventTimeSampler<H, G>,
 > {
     active_lineage: Option<Lineage>,
     next_event_time: Option<PositiveF64>,
@@ -50,7 +50,7 @@ impl<
         D: DispersalSampler<H, G>,
         T: TurnoverRate<H>,
         N: SpeciationProbability<H>,
-        J: EventTimeSampler<H, G, T>,
+        J: EventTimeSampler<H, G>,
     > IndependentActiveLineageSampler<H, G, X, D, T, N, J>
 {
     #[must_use]
@@ -62,6 +62,22 @@ impl<
             marker: PhantomData::<(H, G, X, D, T, N)>,
         }
     }
+
+    /// Replaces the single active lineage without requiring the event
+    ///  sampler type of the simulation to be known.
+    #[must_use]
+    #[inline]
+    pub fn replace_active_lineage(&mut self, active_lineage: Option<Lineage>) -> Option<Lineage> {
+        self.next_event_time = None;
+
+        // `core::mem::replace()` would be semantically better
+        //  - but `clone()` does not spill to local memory
+        let old_active_lineage = self.active_lineage.clone();
+
+        self.active_lineage = active_lineage;
+
+        old_active_lineage
+    }
 }
 
 #[contract_trait]
@@ -72,7 +88,7 @@ impl<
         D: DispersalSampler<H, G>,
         T: TurnoverRate<H>,
         N: SpeciationProbability<H>,
-        J: EventTimeSampler<H, G, T>,
+        J: EventTimeSampler<H, G>,
     > Backup for IndependentActiveLineageSampler<H, G, X, D, T, N, J>
 {
     unsafe fn backup_unchecked(&self) -> Self {
@@ -11,7 +11,7 @@ use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 use crate::cogs::{
     coalescence_sampler::independent::IndependentCoalescenceSampler,
-    event_sampler::independent::IndependentEventSampler,
+    event_sampler::independent::IndependentEventRate,
     immigration_entry::never::NeverImmigrationEntry,
     lineage_store::independent::IndependentLineageStore,
 };
@@ -26,7 +26,8 @@ impl<
         D: DispersalSampler<H, G>,
         T: TurnoverRate<H>,
         N: SpeciationProbability<H>,
-        J: EventTimeSampler<H, G, T>,
+        E: IndependentEventRate<H, G, X, D, T, N>,
+        J: EventTimeSampler<H, G>,
     >
     ActiveLineageSampler<
         H,
@@ -38,7 +39,7 @@ impl<
         IndependentCoalescenceSampler<H>,
         T,
         N,
-        IndependentEventSampler<H, G, X, D, T, N>,
+        E,
         NeverImmigrationEntry,
     > for IndependentActiveLineageSampler<H, G, X, D, T, N, J>
 {
@@ -70,7 +71,7 @@ impl<
             IndependentCoalescenceSampler<H>,
             T,
             N,
-            IndependentEventSampler<H, G, X, D, T, N>,
+            E,
         >,
         rng: &mut G,
     ) -> Option<(
@@ -79,9 +80,7 @@ impl<
         NonNegativeF64,
         PositiveF64,
     )> {
-        let next_event_time = self
-            .peek_time_of_next_event(&simulation.habitat, &simulation.turnover_rate, rng)
-            .ok()?;
+        let next_event_time = self.peek_time_of_next_event(simulation, rng).ok()?;
         self.next_event_time = None;
 
         let chosen_lineage = self.active_lineage.as_mut()?;
@@ -98,7 +97,7 @@ impl<
     }
 
     #[debug_requires(
-        self.number_active_lineages() == 0,
+        self.active_lineage.as_ref().map_or(true, |lineage| !lineage.is_active()),
         "does not overwrite the independent lineage"
     )]
     #[allow(clippy::type_complexity)]
@@ -118,7 +117,7 @@ impl<
             IndependentCoalescenceSampler<H>,
             T,
             N,
-            IndependentEventSampler<H, G, X, D, T, N>,
+            E,
         >,
         _rng: &mut G,
     ) {
@@ -145,7 +144,7 @@ impl<
             IndependentCoalescenceSampler<H>,
             T,
             N,
-            IndependentEventSampler<H, G, X, D, T, N>,
+            E,
         >,
         _rng: &mut G,
     ) {
@@ -162,7 +161,8 @@ impl<
         D: DispersalSampler<H, G>,
         T: TurnoverRate<H>,
         N: SpeciationProbability<H>,
-        J: EventTimeSampler<H, G, T>,
+        E: IndependentEventRate<H, G, X, D, T, N>,
+        J: EventTimeSampler<H, G>,
     >
     PeekableActiveLineageSampler<
         H,
@@ -174,15 +174,26 @@ impl<
         IndependentCoalescenceSampler<H>,
         T,
         N,
-        IndependentEventSampler<H, G, X, D, T, N>,
+        E,
         NeverImmigrationEntry,
     > for IndependentActiveLineageSampler<H, G, X, D, T, N, J>
 {
+    #[allow(clippy::type_complexity)]
     #[inline]
     fn peek_time_of_next_event(
         &mut self,
-        habitat: &H,
-        turnover_rate: &T,
+        simulation: &PartialSimulation<
+            H,
+            G,
+            GlobalLineageReference,
+            IndependentLineageStore<H>,
+            X,
+            D,
+            IndependentCoalescenceSampler<H>,
+            T,
+            N,
+            E,
+        >,
         rng: &mut G,
     ) -> Result<PositiveF64, EmptyActiveLineageSamplerError> {
         if self.next_event_time.is_none() {
@@ -192,14 +203,22 @@ impl<
                     .indexed_location()
                     .ok_or(EmptyActiveLineageSamplerError)?;
 
+                let event_rate =
+                    simulation.with_split_event_sampler(|event_sampler, simulation| {
+                        event_sampler.get_event_rate_at_location(
+                            lineage_indexed_location.location(),
+                            simulation,
+                        )
+                    });
+
                 let next_event_time = self
                     .event_time_sampler
                     .next_event_time_at_indexed_location_weakly_after(
                         lineage_indexed_location,
                         active_lineage.last_event_time(),
-                        habitat,
+                        &simulation.habitat,
                         rng,
-                        turnover_rate,
+                        event_rate,
                     );
 
                 self.next_event_time = Some(PositiveF64::max_after(
@@ -7,7 +7,7 @@ use necsim_core::lineage::{GlobalLineageReference, Lineage};
 
 use crate::cogs::{
     coalescence_sampler::independent::IndependentCoalescenceSampler,
-    event_sampler::independent::IndependentEventSampler,
+    event_sampler::independent::IndependentEventRate,
     immigration_entry::never::NeverImmigrationEntry,
     lineage_store::independent::IndependentLineageStore,
 };
@@ -21,7 +21,8 @@ impl<
         D: DispersalSampler<H, G>,
         T: TurnoverRate<H>,
         N: SpeciationProbability<H>,
-        J: EventTimeSampler<H, G, T>,
+        E: IndependentEventRate<H, G, X, D, T, N>,
+        J: EventTimeSampler<H, G>,
     >
     SingularActiveLineageSampler<
         H,
@@ -33,21 +34,13 @@ impl<
         IndependentCoalescenceSampler<H>,
         T,
         N,
-        IndependentEventSampler<H, G, X, D, T, N>,
+        E,
         NeverImmigrationEntry,
     > for IndependentActiveLineageSampler<H, G, X, D, T, N, J>
 {
     #[must_use]
     #[inline]
     fn replace_active_lineage(&mut self, active_lineage: Option<Lineage>) -> Option<Lineage> {
-        self.next_event_time = None;
-
-        // `core::mem::replace()` would be semantically better
-        //  - but `clone()` does not spill to local memory
-        let old_active_lineage = self.active_lineage.clone();
-
-        self.active_lineage = active_lineage;
-
-        old_active_lineage
+        IndependentActiveLineageSampler::replace_active_lineage(self, active_lineage)
     }
 }
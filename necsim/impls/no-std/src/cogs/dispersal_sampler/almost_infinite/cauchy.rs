@@ -0,0 +1,106 @@
+use core::marker::PhantomData;
+
+use necsim_core::{
+    cogs::{Backup, DispersalSampler, RngCore, SeparableDispersalSampler},
+    intrinsics::sqrt,
+    landscape::Location,
+};
+use necsim_core_bond::{ClosedUnitF64, PositiveF64};
+
+use crate::cogs::habitat::almost_infinite::AlmostInfiniteHabitat;
+
+use super::{discrete_dispersal_target, radial_dispersal_offset, radial_self_dispersal};
+
+/// Two-dimensional Cauchy dispersal kernel
+///  `f(r) = gamma / (2 * pi * (r^2 + gamma^2)^(3/2))`
+///  with scale `gamma`, whose dispersal distance has an infinite mean.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
+pub struct AlmostInfiniteCauchyDispersalSampler<G: RngCore> {
+    scale: PositiveF64,
+    self_dispersal: ClosedUnitF64,
+    marker: PhantomData<G>,
+}
+
+impl<G: RngCore> AlmostInfiniteCauchyDispersalSampler<G> {
+    #[must_use]
+    pub fn new(scale: PositiveF64) -> Self {
+        let self_dispersal = radial_self_dispersal(|distance| {
+            1.0_f64 - scale.get() / sqrt(distance * distance + scale.get() * scale.get())
+        });
+
+        Self {
+            scale,
+            self_dispersal,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> Backup for AlmostInfiniteCauchyDispersalSampler<G> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            scale: self.scale,
+            self_dispersal: self.self_dispersal,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> DispersalSampler<AlmostInfiniteHabitat, G>
+    for AlmostInfiniteCauchyDispersalSampler<G>
+{
+    #[must_use]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &AlmostInfiniteHabitat,
+        rng: &mut G,
+    ) -> Location {
+        use necsim_core::cogs::RngSampler;
+
+        // Inverse transform sampling of the dispersal distance r with the
+        //  cumulative distribution function
+        //  F(r) = 1 - gamma / sqrt(r^2 + gamma^2)
+        let u01 = rng.sample_uniform().one_minus().get();
+        let distance = self.scale.get() * sqrt(1.0_f64 / (u01 * u01) - 1.0_f64);
+
+        let theta = rng.sample_uniform().get() * core::f64::consts::TAU;
+
+        discrete_dispersal_target(location, habitat, radial_dispersal_offset(distance, theta))
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> SeparableDispersalSampler<AlmostInfiniteHabitat, G>
+    for AlmostInfiniteCauchyDispersalSampler<G>
+{
+    #[must_use]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &AlmostInfiniteHabitat,
+        rng: &mut G,
+    ) -> Location {
+        let mut target_location = self.sample_dispersal_from_location(location, habitat, rng);
+
+        // For now, we just use rejection sampling here
+        while &target_location == location {
+            target_location = self.sample_dispersal_from_location(location, habitat, rng);
+        }
+
+        target_location
+    }
+
+    #[must_use]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        _location: &Location,
+        _habitat: &AlmostInfiniteHabitat,
+    ) -> ClosedUnitF64 {
+        self.self_dispersal
+    }
+}
@@ -0,0 +1,115 @@
+use core::marker::PhantomData;
+
+use necsim_core::{
+    cogs::{Backup, DispersalSampler, RngCore, SeparableDispersalSampler},
+    intrinsics::sqrt,
+    landscape::Location,
+};
+use necsim_core_bond::{ClosedUnitF64, PositiveF64};
+
+use crate::cogs::habitat::almost_infinite::AlmostInfiniteHabitat;
+
+use super::{discrete_dispersal_target, radial_dispersal_offset, radial_self_dispersal};
+
+/// Clark's 2Dt dispersal kernel
+///  `f(r) = p / (pi * u) * (1 + r^2 / u)^(-(p + 1))`
+///  with scale `u` and shape `p`, which becomes fatter-tailed as `p`
+///  decreases.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
+pub struct AlmostInfiniteClark2DtDispersalSampler<G: RngCore> {
+    scale_u: PositiveF64,
+    shape_p: PositiveF64,
+    self_dispersal: ClosedUnitF64,
+    marker: PhantomData<G>,
+}
+
+impl<G: RngCore> AlmostInfiniteClark2DtDispersalSampler<G> {
+    #[must_use]
+    pub fn new(scale_u: PositiveF64, shape_p: PositiveF64) -> Self {
+        let self_dispersal = radial_self_dispersal(|distance| {
+            1.0_f64
+                - libm::pow(
+                    1.0_f64 + distance * distance / scale_u.get(),
+                    -shape_p.get(),
+                )
+        });
+
+        Self {
+            scale_u,
+            shape_p,
+            self_dispersal,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> Backup for AlmostInfiniteClark2DtDispersalSampler<G> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            scale_u: self.scale_u,
+            shape_p: self.shape_p,
+            self_dispersal: self.self_dispersal,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> DispersalSampler<AlmostInfiniteHabitat, G>
+    for AlmostInfiniteClark2DtDispersalSampler<G>
+{
+    #[must_use]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &AlmostInfiniteHabitat,
+        rng: &mut G,
+    ) -> Location {
+        use necsim_core::cogs::RngSampler;
+
+        // Inverse transform sampling of the dispersal distance r with the
+        //  cumulative distribution function F(r) = 1 - (1 + r^2 / u)^(-p)
+        let u01 = rng.sample_uniform().one_minus();
+        let distance = sqrt(
+            self.scale_u.get() * (libm::pow(u01.get(), -1.0_f64 / self.shape_p.get()) - 1.0_f64),
+        );
+
+        let theta = rng.sample_uniform().get() * core::f64::consts::TAU;
+
+        discrete_dispersal_target(location, habitat, radial_dispersal_offset(distance, theta))
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> SeparableDispersalSampler<AlmostInfiniteHabitat, G>
+    for AlmostInfiniteClark2DtDispersalSampler<G>
+{
+    #[must_use]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &AlmostInfiniteHabitat,
+        rng: &mut G,
+    ) -> Location {
+        let mut target_location = self.sample_dispersal_from_location(location, habitat, rng);
+
+        // For now, we just use rejection sampling here
+        while &target_location == location {
+            target_location = self.sample_dispersal_from_location(location, habitat, rng);
+        }
+
+        target_location
+    }
+
+    #[must_use]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        _location: &Location,
+        _habitat: &AlmostInfiniteHabitat,
+    ) -> ClosedUnitF64 {
+        self.self_dispersal
+    }
+}
@@ -0,0 +1,210 @@
+use core::marker::PhantomData;
+
+use necsim_core::{
+    cogs::{Backup, DispersalSampler, RngCore, SeparableDispersalSampler},
+    intrinsics::{exp, ln, sqrt},
+    landscape::Location,
+};
+use necsim_core_bond::{ClosedUnitF64, PositiveF64};
+
+use crate::cogs::habitat::almost_infinite::AlmostInfiniteHabitat;
+
+use super::{discrete_dispersal_target, radial_dispersal_offset, radial_self_dispersal};
+
+/// Exponential-power dispersal kernel
+///  `f(r) = b / (2 * pi * a^2 * Gamma(2 / b)) * exp(-(r / a)^b)`
+///  with scale `a` and shape `b`, which is fat-tailed for `b < 1`.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
+pub struct AlmostInfiniteExpPowerDispersalSampler<G: RngCore> {
+    scale_a: PositiveF64,
+    shape_b: PositiveF64,
+    self_dispersal: ClosedUnitF64,
+    marker: PhantomData<G>,
+}
+
+impl<G: RngCore> AlmostInfiniteExpPowerDispersalSampler<G> {
+    #[must_use]
+    pub fn new(scale_a: PositiveF64, shape_b: PositiveF64) -> Self {
+        let self_dispersal = radial_self_dispersal(|distance| {
+            regularised_lower_incomplete_gamma(
+                2.0_f64 / shape_b.get(),
+                libm::pow(distance / scale_a.get(), shape_b.get()),
+            )
+        });
+
+        Self {
+            scale_a,
+            shape_b,
+            self_dispersal,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> Backup for AlmostInfiniteExpPowerDispersalSampler<G> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            scale_a: self.scale_a,
+            shape_b: self.shape_b,
+            self_dispersal: self.self_dispersal,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> DispersalSampler<AlmostInfiniteHabitat, G>
+    for AlmostInfiniteExpPowerDispersalSampler<G>
+{
+    #[must_use]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &AlmostInfiniteHabitat,
+        rng: &mut G,
+    ) -> Location {
+        use necsim_core::cogs::RngSampler;
+
+        // The transformed dispersal distance (r / a)^b ~ Gamma(2 / b, 1)
+        let gamma = sample_standard_gamma(2.0_f64 / self.shape_b.get(), rng);
+        let distance = self.scale_a.get() * libm::pow(gamma, 1.0_f64 / self.shape_b.get());
+
+        let theta = rng.sample_uniform().get() * core::f64::consts::TAU;
+
+        discrete_dispersal_target(location, habitat, radial_dispersal_offset(distance, theta))
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> SeparableDispersalSampler<AlmostInfiniteHabitat, G>
+    for AlmostInfiniteExpPowerDispersalSampler<G>
+{
+    #[must_use]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &AlmostInfiniteHabitat,
+        rng: &mut G,
+    ) -> Location {
+        let mut target_location = self.sample_dispersal_from_location(location, habitat, rng);
+
+        // For now, we just use rejection sampling here
+        while &target_location == location {
+            target_location = self.sample_dispersal_from_location(location, habitat, rng);
+        }
+
+        target_location
+    }
+
+    #[must_use]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        _location: &Location,
+        _habitat: &AlmostInfiniteHabitat,
+    ) -> ClosedUnitF64 {
+        self.self_dispersal
+    }
+}
+
+/// Samples from the Gamma(k, 1) distribution using the Marsaglia-Tsang method
+#[allow(clippy::many_single_char_names)]
+fn sample_standard_gamma<G: RngCore>(shape_k: f64, rng: &mut G) -> f64 {
+    use necsim_core::cogs::RngSampler;
+
+    // Gamma(k) = Gamma(k + 1) * U^(1 / k) boosts shapes k < 1
+    let (shape_k, boost) = if shape_k < 1.0_f64 {
+        (
+            shape_k + 1.0_f64,
+            libm::pow(rng.sample_uniform().get(), 1.0_f64 / shape_k),
+        )
+    } else {
+        (shape_k, 1.0_f64)
+    };
+
+    let d = shape_k - 1.0_f64 / 3.0_f64;
+    let c = 1.0_f64 / sqrt(9.0_f64 * d);
+
+    loop {
+        let (x, _) = rng.sample_2d_standard_normal();
+
+        let v = 1.0_f64 + c * x;
+
+        if v <= 0.0_f64 {
+            continue;
+        }
+
+        let v = v * v * v;
+
+        let u = rng.sample_uniform().get();
+
+        if u > 0.0_f64 && ln(u) < 0.5_f64 * x * x + d - d * v + d * ln(v) {
+            return d * v * boost;
+        }
+    }
+}
+
+/// Computes the regularised lower incomplete gamma function `P(s, x)`
+///  using its series expansion for `x < s + 1` and its continued fraction
+///  otherwise
+#[allow(clippy::many_single_char_names)]
+fn regularised_lower_incomplete_gamma(s: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1.0e-15_f64;
+    const TINY: f64 = 1.0e-300_f64;
+    const MAX_ITERATIONS: u32 = 1000;
+
+    if x <= 0.0_f64 {
+        return 0.0_f64;
+    }
+
+    let prefactor = exp(s * ln(x) - x - libm::lgamma(s));
+
+    if x < (s + 1.0_f64) {
+        let mut term = 1.0_f64 / s;
+        let mut sum = term;
+
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (s + f64::from(n));
+            sum += term;
+
+            if libm::fabs(term) < libm::fabs(sum) * EPSILON {
+                break;
+            }
+        }
+
+        (sum * prefactor).min(1.0_f64)
+    } else {
+        // Modified Lentz's method for the upper incomplete gamma function
+        let mut b = x + 1.0_f64 - s;
+        let mut c = 1.0_f64 / TINY;
+        let mut d = 1.0_f64 / b;
+        let mut h = d;
+
+        for i in 1..MAX_ITERATIONS {
+            let an = -f64::from(i) * (f64::from(i) - s);
+            b += 2.0_f64;
+
+            d = an * d + b;
+            if libm::fabs(d) < TINY {
+                d = TINY;
+            }
+
+            c = b + an / c;
+            if libm::fabs(c) < TINY {
+                c = TINY;
+            }
+
+            d = 1.0_f64 / d;
+            let delta = d * c;
+            h *= delta;
+
+            if libm::fabs(delta - 1.0_f64) < EPSILON {
+                break;
+            }
+        }
+
+        (1.0_f64 - prefactor * h).max(0.0_f64)
+    }
+}
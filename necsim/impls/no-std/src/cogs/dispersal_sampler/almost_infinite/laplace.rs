@@ -0,0 +1,113 @@
+use core::marker::PhantomData;
+
+use necsim_core::{
+    cogs::{Backup, DispersalSampler, RngCore, SeparableDispersalSampler},
+    intrinsics::{exp, ln},
+    landscape::Location,
+};
+use necsim_core_bond::{ClosedUnitF64, PositiveF64};
+
+use crate::cogs::habitat::almost_infinite::AlmostInfiniteHabitat;
+
+use super::discrete_dispersal_target;
+
+/// Laplace dispersal kernel, which independently samples the dispersal
+///  along each axis from `f(x) = exp(-|x| / b) / (2 * b)` with scale `b`.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
+pub struct AlmostInfiniteLaplaceDispersalSampler<G: RngCore> {
+    scale: PositiveF64,
+    self_dispersal: ClosedUnitF64,
+    marker: PhantomData<G>,
+}
+
+impl<G: RngCore> AlmostInfiniteLaplaceDispersalSampler<G> {
+    #[must_use]
+    pub fn new(scale: PositiveF64) -> Self {
+        // Dispersal by x in (-0.5; 0.5) stays at the same coordinate
+        let probability = 1.0_f64 - exp(-0.5_f64 / scale.get());
+
+        // Safety: For positive values b, 1 - exp(-0.5 / b) in [0.0; 1.0]
+        let self_dispersal_1d = unsafe { ClosedUnitF64::new_unchecked(probability) };
+
+        Self {
+            scale,
+            self_dispersal: self_dispersal_1d * self_dispersal_1d,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> Backup for AlmostInfiniteLaplaceDispersalSampler<G> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            scale: self.scale,
+            self_dispersal: self.self_dispersal,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> DispersalSampler<AlmostInfiniteHabitat, G>
+    for AlmostInfiniteLaplaceDispersalSampler<G>
+{
+    #[must_use]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &AlmostInfiniteHabitat,
+        rng: &mut G,
+    ) -> Location {
+        let dx = sample_laplace(self.scale, rng);
+        let dy = sample_laplace(self.scale, rng);
+
+        discrete_dispersal_target(location, habitat, (dx, dy))
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> SeparableDispersalSampler<AlmostInfiniteHabitat, G>
+    for AlmostInfiniteLaplaceDispersalSampler<G>
+{
+    #[must_use]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &AlmostInfiniteHabitat,
+        rng: &mut G,
+    ) -> Location {
+        let mut target_location = self.sample_dispersal_from_location(location, habitat, rng);
+
+        // For now, we just use rejection sampling here
+        while &target_location == location {
+            target_location = self.sample_dispersal_from_location(location, habitat, rng);
+        }
+
+        target_location
+    }
+
+    #[must_use]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        _location: &Location,
+        _habitat: &AlmostInfiniteHabitat,
+    ) -> ClosedUnitF64 {
+        self.self_dispersal
+    }
+}
+
+/// Inverse transform sampling of the Laplace distribution with scale `b`
+fn sample_laplace<G: RngCore>(scale: PositiveF64, rng: &mut G) -> f64 {
+    use necsim_core::cogs::RngSampler;
+
+    let u = rng.sample_uniform().get() - 0.5_f64;
+
+    if u < 0.0_f64 {
+        scale.get() * ln(1.0_f64 + 2.0_f64 * u)
+    } else {
+        -scale.get() * ln(1.0_f64 - 2.0_f64 * u)
+    }
+}
@@ -0,0 +1,81 @@
+use necsim_core::{
+    cogs::Habitat,
+    intrinsics::{cos, round, sin},
+    landscape::Location,
+};
+use necsim_core_bond::ClosedUnitF64;
+
+use crate::cogs::habitat::almost_infinite::AlmostInfiniteHabitat;
+
+pub mod cauchy;
+pub mod clark2dt;
+pub mod exp_power;
+pub mod laplace;
+pub mod normal;
+
+/// Discretises the continuous dispersal by `(dx, dy)` from the `location`
+///  and wraps the target around the edges of the almost infinite habitat.
+fn discrete_dispersal_target(
+    location: &Location,
+    habitat: &AlmostInfiniteHabitat,
+    (dx, dy): (f64, f64),
+) -> Location {
+    // Discrete dispersal assumes lineage positions are centred on (0.5, 0.5),
+    // i.e. |dispersal| >= 0.5 changes the cell
+    // (dx and dy must be rounded to nearest int away from 0.0)
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (dx, dy): (i64, i64) = (
+        (round(dx) as i64) % i64::from(habitat.get_extent().width()),
+        (round(dy) as i64) % i64::from(habitat.get_extent().height()),
+    );
+
+    let new_x = (i64::from(location.x()) + dx) % i64::from(habitat.get_extent().width());
+    let new_y = (i64::from(location.y()) + dy) % i64::from(habitat.get_extent().height());
+
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    Location::new(
+        ((new_x + i64::from(habitat.get_extent().width()))
+            % i64::from(habitat.get_extent().width())) as u32,
+        ((new_y + i64::from(habitat.get_extent().height()))
+            % i64::from(habitat.get_extent().height())) as u32,
+    )
+}
+
+/// Converts a dispersal `distance` in the direction `theta` into `(dx, dy)`.
+fn radial_dispersal_offset(distance: f64, theta: f64) -> (f64, f64) {
+    (distance * cos(theta), distance * sin(theta))
+}
+
+/// Computes the probability that a radially symmetric kernel, given by the
+///  cumulative distribution function of its dispersal distance, keeps the
+///  dispersal inside the unit square cell centred on the origin.
+///
+/// By symmetry, the probability is
+///  `4/pi * integral_0^(pi/4) radial_cdf(0.5 / cos(theta)) d(theta)`,
+///  which is integrated numerically using Simpson's rule.
+fn radial_self_dispersal<F: Fn(f64) -> f64>(radial_cdf: F) -> ClosedUnitF64 {
+    const INTERVALS: u32 = 256;
+
+    let step = core::f64::consts::FRAC_PI_4 / f64::from(INTERVALS);
+
+    let mut integral = 0.0_f64;
+
+    for i in 0..=INTERVALS {
+        let theta = f64::from(i) * step;
+
+        let weight = if i == 0 || i == INTERVALS {
+            1.0_f64
+        } else if i % 2 == 1 {
+            4.0_f64
+        } else {
+            2.0_f64
+        };
+
+        integral += weight * radial_cdf(0.5_f64 / cos(theta));
+    }
+
+    let probability = integral * step / 3.0_f64 * 4.0_f64 / core::f64::consts::PI;
+
+    // Safety: The probability is clamped to [0.0; 1.0]
+    unsafe { ClosedUnitF64::new_unchecked(probability.max(0.0_f64).min(1.0_f64)) }
+}
@@ -1,14 +1,15 @@
 use core::marker::PhantomData;
 
 use necsim_core::{
-    cogs::{Backup, DispersalSampler, Habitat, RngCore, SeparableDispersalSampler},
-    intrinsics::round,
+    cogs::{Backup, DispersalSampler, RngCore, SeparableDispersalSampler},
     landscape::Location,
 };
 use necsim_core_bond::{ClosedUnitF64, NonNegativeF64};
 
 use crate::cogs::habitat::almost_infinite::AlmostInfiniteHabitat;
 
+use super::discrete_dispersal_target;
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug)]
 #[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
@@ -65,25 +66,7 @@ impl<G: RngCore> DispersalSampler<AlmostInfiniteHabitat, G>
 
         let (dx, dy): (f64, f64) = rng.sample_2d_normal(0.0_f64, self.sigma);
 
-        // Discrete dispersal assumes lineage positions are centred on (0.5, 0.5),
-        // i.e. |dispersal| >= 0.5 changes the cell
-        // (dx and dy must be rounded to nearest int away from 0.0)
-        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        let (dx, dy): (i64, i64) = (
-            (round(dx) as i64) % i64::from(habitat.get_extent().width()),
-            (round(dy) as i64) % i64::from(habitat.get_extent().height()),
-        );
-
-        let new_x = (i64::from(location.x()) + dx) % i64::from(habitat.get_extent().width());
-        let new_y = (i64::from(location.y()) + dy) % i64::from(habitat.get_extent().height());
-
-        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-        Location::new(
-            ((new_x + i64::from(habitat.get_extent().width()))
-                % i64::from(habitat.get_extent().width())) as u32,
-            ((new_y + i64::from(habitat.get_extent().height()))
-                % i64::from(habitat.get_extent().height())) as u32,
-        )
+        discrete_dispersal_target(location, habitat, (dx, dy))
     }
 }
 
@@ -0,0 +1,99 @@
+use core::ops::Range;
+
+use necsim_core::{
+    cogs::{DispersalSampler, Habitat, RngCore, SeparableDispersalSampler},
+    landscape::Location,
+};
+use necsim_core_bond::ClosedUnitF64;
+
+use crate::alias::packed::AliasMethodSamplerAtom;
+
+use super::InMemoryPackedSeparableAliasDispersalSampler;
+
+#[contract_trait]
+impl<H: Habitat, G: RngCore> DispersalSampler<H, G>
+    for InMemoryPackedSeparableAliasDispersalSampler<H, G>
+{
+    #[must_use]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &H,
+        rng: &mut G,
+    ) -> Location {
+        use necsim_core::cogs::RngSampler;
+
+        let self_dispersal_at_location =
+            self.get_self_dispersal_probability_at_location(location, habitat);
+
+        if self_dispersal_at_location >= 1.0_f64 {
+            return location.clone();
+        }
+
+        if self_dispersal_at_location > 0.0_f64 && rng.sample_event(self_dispersal_at_location) {
+            return location.clone();
+        }
+
+        self.sample_non_self_dispersal_from_location(location, habitat, rng)
+    }
+}
+
+#[contract_trait]
+impl<H: Habitat, G: RngCore> SeparableDispersalSampler<H, G>
+    for InMemoryPackedSeparableAliasDispersalSampler<H, G>
+{
+    #[must_use]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &H,
+        rng: &mut G,
+    ) -> Location {
+        let location_row = (location.y() - habitat.get_extent().y()) as usize;
+        let location_column = (location.x() - habitat.get_extent().x()) as usize;
+
+        // Only safe as trait precondition that `location` is inside `habitat`
+        let alias_range = unsafe {
+            Range::<usize>::from(
+                self.alias_dispersal_ranges
+                    .get(location_row, location_column)
+                    .unwrap_unchecked()
+                    .clone(),
+            )
+        };
+
+        // Safe by the construction of `InMemoryPackedSeparableAliasDispersalSampler`
+        let alias_dispersals_at_location =
+            unsafe { &self.alias_dispersal_buffer.get_unchecked(alias_range) };
+
+        let dispersal_target_index: usize =
+            AliasMethodSamplerAtom::sample_event(alias_dispersals_at_location, rng);
+
+        #[allow(clippy::cast_possible_truncation)]
+        Location::new(
+            (dispersal_target_index % (habitat.get_extent().width() as usize)) as u32
+                + habitat.get_extent().x(),
+            (dispersal_target_index / (habitat.get_extent().width() as usize)) as u32
+                + habitat.get_extent().y(),
+        )
+    }
+
+    #[must_use]
+    #[debug_requires(habitat.get_extent().contains(location), "location is inside habitat extent")]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        location: &Location,
+        habitat: &H,
+    ) -> ClosedUnitF64 {
+        let location_row = (location.y() - habitat.get_extent().y()) as usize;
+        let location_column = (location.x() - habitat.get_extent().x()) as usize;
+
+        // Only safe as trait precondition that `location` is inside `habitat`
+        unsafe {
+            *self
+                .self_dispersal
+                .get(location_row, location_column)
+                .unwrap_unchecked()
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::marker::PhantomData;
+
+use array2d::Array2D;
+
+use necsim_core::{
+    cogs::{Backup, Habitat, RngCore},
+    landscape::Location,
+};
+use necsim_core_bond::ClosedUnitF64;
+
+mod dispersal;
+
+use crate::alias::packed::AliasMethodSamplerAtom;
+
+use super::{packed_alias::AliasSamplerRange, InMemoryDispersalSampler};
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
+#[cfg_attr(feature = "cuda", r2cBound(H: rust_cuda::common::RustToCuda))]
+#[cfg_attr(feature = "cuda", r2cBound(G: rust_cuda::common::RustToCuda))]
+pub struct InMemoryPackedSeparableAliasDispersalSampler<H: Habitat, G: RngCore> {
+    #[cfg_attr(feature = "cuda", r2cEmbed)]
+    alias_dispersal_ranges: Array2D<AliasSamplerRange>,
+    #[cfg_attr(feature = "cuda", r2cEmbed)]
+    alias_dispersal_buffer: Box<[AliasMethodSamplerAtom<usize>]>,
+    #[cfg_attr(feature = "cuda", r2cEmbed)]
+    self_dispersal: Array2D<ClosedUnitF64>,
+    marker: PhantomData<(H, G)>,
+}
+
+#[contract_trait]
+impl<H: Habitat, G: RngCore> InMemoryDispersalSampler<H, G>
+    for InMemoryPackedSeparableAliasDispersalSampler<H, G>
+{
+    /// Creates a new `InMemoryPackedSeparableAliasDispersalSampler` from the
+    /// `dispersal` map and extent of the habitat map.
+    fn unchecked_new(dispersal: &Array2D<f64>, habitat: &H) -> Self {
+        let habitat_extent = habitat.get_extent();
+
+        let mut event_weights: Vec<(usize, f64)> = Vec::with_capacity(dispersal.row_len());
+
+        let mut alias_dispersal_buffer = Vec::new();
+
+        let mut self_dispersal = Array2D::filled_with(
+            ClosedUnitF64::zero(),
+            habitat_extent.height() as usize,
+            habitat_extent.width() as usize,
+        );
+
+        let alias_dispersal_ranges = Array2D::from_iter_row_major(
+            dispersal.rows_iter().enumerate().map(|(row_index, row)| {
+                event_weights.clear();
+
+                let mut self_dispersal_at_location = 0.0_f64;
+
+                for (col_index, dispersal_probability) in row.enumerate() {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let location = Location::new(
+                        (col_index % (habitat_extent.width() as usize)) as u32 + habitat_extent.x(),
+                        (col_index / (habitat_extent.width() as usize)) as u32 + habitat_extent.y(),
+                    );
+
+                    // Multiply all dispersal probabilities by the habitat of their target
+                    let weight = dispersal_probability
+                        * f64::from(habitat.get_habitat_at_location(&location));
+
+                    if weight > 0.0_f64 {
+                        // Separate self-dispersal from out-dispersal
+                        if col_index == row_index {
+                            self_dispersal_at_location = weight;
+                        } else {
+                            event_weights.push((col_index, weight));
+                        }
+                    }
+                }
+
+                let total_weight = event_weights.iter().map(|(_e, w)| *w).sum::<f64>()
+                    + self_dispersal_at_location;
+
+                if total_weight > 0.0_f64 {
+                    // Safety: Normalisation limits the result to [0.0; 1.0]
+                    let dispersal_probability = unsafe {
+                        ClosedUnitF64::new_unchecked(self_dispersal_at_location / total_weight)
+                    };
+
+                    self_dispersal[(
+                        row_index / (habitat_extent.width() as usize),
+                        row_index % (habitat_extent.width() as usize),
+                    )] = dispersal_probability;
+                }
+
+                let range_from = alias_dispersal_buffer.len();
+
+                if event_weights.is_empty() {
+                    AliasSamplerRange::from(range_from..range_from)
+                } else {
+                    alias_dispersal_buffer
+                        .append(&mut AliasMethodSamplerAtom::create(&event_weights));
+
+                    AliasSamplerRange::from(range_from..alias_dispersal_buffer.len())
+                }
+            }),
+            habitat_extent.height() as usize,
+            habitat_extent.width() as usize,
+        )
+        .unwrap(); // infallible by PRE;
+
+        Self {
+            alias_dispersal_ranges,
+            alias_dispersal_buffer: alias_dispersal_buffer.into_boxed_slice(),
+            self_dispersal,
+            marker: PhantomData::<(H, G)>,
+        }
+    }
+}
+
+impl<H: Habitat, G: RngCore> core::fmt::Debug
+    for InMemoryPackedSeparableAliasDispersalSampler<H, G>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("InMemoryPackedSeparableAliasDispersalSampler")
+            .field("alias_dispersal_ranges", &self.alias_dispersal_ranges)
+            .field(
+                "alias_dispersal_buffer",
+                &format_args!(
+                    "Box [ {:p}; {} ]",
+                    &self.alias_dispersal_buffer,
+                    self.alias_dispersal_buffer.len()
+                ),
+            )
+            .field("self_dispersal", &self.self_dispersal)
+            .finish()
+    }
+}
+
+#[contract_trait]
+impl<H: Habitat, G: RngCore> Backup for InMemoryPackedSeparableAliasDispersalSampler<H, G> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            alias_dispersal_ranges: self.alias_dispersal_ranges.clone(),
+            alias_dispersal_buffer: self.alias_dispersal_buffer.clone(),
+            self_dispersal: self.self_dispersal.clone(),
+            marker: PhantomData::<(H, G)>,
+        }
+    }
+}
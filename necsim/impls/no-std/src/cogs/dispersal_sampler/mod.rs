@@ -1,4 +1,5 @@
-pub mod almost_infinite_normal;
+pub mod almost_infinite;
 pub mod in_memory;
 pub mod non_spatial;
 pub mod spatially_implicit;
+pub mod torus;
@@ -0,0 +1,211 @@
+use core::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{
+    cogs::{Backup, DispersalSampler, Habitat, RngCore, SeparableDispersalSampler},
+    intrinsics::round,
+    landscape::Location,
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64};
+
+use crate::cogs::habitat::torus::TorusHabitat;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::rustacuda_core::DeviceCopy))]
+#[cfg_attr(feature = "cuda", rustacuda(core = "rust_cuda::rustacuda_core"))]
+pub enum TorusDispersalKernel {
+    /// Gaussian dispersal N(0, sigma^2) which wraps around the torus
+    Normal { sigma: NonNegativeF64 },
+    /// Uniform dispersal to the location itself or one of its eight
+    ///  (Moore) neighbours
+    NearestNeighbour,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
+pub struct TorusDispersalSampler<G: RngCore> {
+    kernel: TorusDispersalKernel,
+    self_dispersal: ClosedUnitF64,
+    marker: PhantomData<G>,
+}
+
+impl<G: RngCore> TorusDispersalSampler<G> {
+    #[must_use]
+    pub fn new(habitat: &TorusHabitat, kernel: TorusDispersalKernel) -> Self {
+        let self_dispersal_x = self_dispersal_1d(kernel, habitat.get_extent().width());
+        let self_dispersal_y = self_dispersal_1d(kernel, habitat.get_extent().height());
+
+        Self {
+            kernel,
+            self_dispersal: self_dispersal_x * self_dispersal_y,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> Backup for TorusDispersalSampler<G> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            kernel: self.kernel,
+            self_dispersal: self.self_dispersal,
+            marker: PhantomData::<G>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> DispersalSampler<TorusHabitat, G> for TorusDispersalSampler<G> {
+    #[must_use]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &TorusHabitat,
+        rng: &mut G,
+    ) -> Location {
+        use necsim_core::cogs::RngSampler;
+
+        let (dx, dy): (i64, i64) = match self.kernel {
+            TorusDispersalKernel::Normal { sigma } => {
+                let (dx, dy): (f64, f64) = rng.sample_2d_normal(0.0_f64, sigma);
+
+                // Discrete dispersal assumes lineage positions are centred on (0.5, 0.5),
+                // i.e. |dispersal| >= 0.5 changes the cell
+                // (dx and dy must be rounded to nearest int away from 0.0)
+                #[allow(clippy::cast_possible_truncation)]
+                (round(dx) as i64, round(dy) as i64)
+            },
+            TorusDispersalKernel::NearestNeighbour => {
+                let neighbour = rng.sample_index(9);
+
+                #[allow(clippy::cast_possible_wrap)]
+                ((neighbour % 3) as i64 - 1, (neighbour / 3) as i64 - 1)
+            },
+        };
+
+        let width = i64::from(habitat.get_extent().width());
+        let height = i64::from(habitat.get_extent().height());
+
+        // The periodic boundaries wrap the dispersal around the torus
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        Location::new(
+            ((i64::from(location.x()) + (dx % width) + width) % width) as u32,
+            ((i64::from(location.y()) + (dy % height) + height) % height) as u32,
+        )
+    }
+}
+
+#[contract_trait]
+impl<G: RngCore> SeparableDispersalSampler<TorusHabitat, G> for TorusDispersalSampler<G> {
+    #[must_use]
+    #[debug_requires(
+        self.self_dispersal < 1.0_f64,
+        "a different, non-self dispersal, target location exists"
+    )]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &TorusHabitat,
+        rng: &mut G,
+    ) -> Location {
+        let mut target_location = self.sample_dispersal_from_location(location, habitat, rng);
+
+        // For now, we just use rejection sampling here
+        while &target_location == location {
+            target_location = self.sample_dispersal_from_location(location, habitat, rng);
+        }
+
+        target_location
+    }
+
+    #[must_use]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        _location: &Location,
+        _habitat: &TorusHabitat,
+    ) -> ClosedUnitF64 {
+        self.self_dispersal
+    }
+}
+
+/// Probability that the dispersal along one axis of the given `length`
+///  returns to the same coordinate after wrapping around the torus
+fn self_dispersal_1d(kernel: TorusDispersalKernel, length: u32) -> ClosedUnitF64 {
+    // On a torus of length 1, all dispersal wraps around to the location itself
+    if length == 1 {
+        return ClosedUnitF64::one();
+    }
+
+    match kernel {
+        TorusDispersalKernel::Normal { sigma } if sigma > 0.0_f64 => {
+            let probability = if sigma.get() <= f64::from(length) {
+                self_dispersal_1d_normal_direct(sigma.get(), f64::from(length))
+            } else {
+                self_dispersal_1d_normal_fourier(sigma.get(), f64::from(length))
+            };
+
+            // Safety: The probability is clamped to [0.0; 1.0]
+            unsafe { ClosedUnitF64::new_unchecked(probability.max(0.0_f64).min(1.0_f64)) }
+        },
+        TorusDispersalKernel::Normal { .. } => ClosedUnitF64::one(),
+        // Safety: 1/3 is in [0.0; 1.0]
+        TorusDispersalKernel::NearestNeighbour => unsafe {
+            ClosedUnitF64::new_unchecked(1.0_f64 / 3.0_f64)
+        },
+    }
+}
+
+/// Sums the probability of dispersal by `k * length` in `(-0.5; 0.5)`, which
+///  only needs a few terms iff `sigma <= length`
+fn self_dispersal_1d_normal_direct(sigma: f64, length: f64) -> f64 {
+    let erf_scale = 1.0_f64 / (sigma * core::f64::consts::SQRT_2);
+
+    let mut probability = libm::erf(0.5_f64 * erf_scale);
+
+    let mut offset = length;
+
+    // Beyond 10 sigma, the remaining probability mass is negligible
+    //  (at most 11 iterations as sigma <= length)
+    while (offset - 0.5_f64) < (10.0_f64 * sigma) {
+        // Both the positive and the negative offsets contribute
+        probability +=
+            libm::erf((offset + 0.5_f64) * erf_scale) - libm::erf((offset - 0.5_f64) * erf_scale);
+
+        offset += length;
+    }
+
+    probability
+}
+
+/// Sums the Fourier series of the wrapped and discretised normal distribution
+///  (Poisson summation), which only needs a few terms iff `sigma > length`
+fn self_dispersal_1d_normal_fourier(sigma: f64, length: f64) -> f64 {
+    let mut probability = 1.0_f64;
+
+    let mut frequency = 1.0_f64 / length;
+
+    // Beyond exp(-40), the remaining terms are negligible
+    //  (at most 1 iteration as sigma > length)
+    while 2.0_f64
+        * core::f64::consts::PI
+        * core::f64::consts::PI
+        * sigma
+        * sigma
+        * frequency
+        * frequency
+        < 40.0_f64
+    {
+        let phase = core::f64::consts::PI * frequency;
+
+        probability += 2.0_f64
+            * (libm::sin(phase) / phase)
+            * libm::exp(-2.0_f64 * phase * phase * sigma * sigma);
+
+        frequency += 1.0_f64 / length;
+    }
+
+    probability / length
+}
@@ -7,7 +7,7 @@ use necsim_core::{
         SpeciationSample, TurnoverRate,
     },
     event::{DispersalEvent, PackedEvent, SpeciationEvent},
-    landscape::IndexedLocation,
+    landscape::{IndexedLocation, Location},
     lineage::GlobalLineageReference,
     simulation::partial::event_sampler::PartialSimulation,
 };
@@ -18,6 +18,55 @@ use crate::cogs::{
     lineage_store::independent::IndependentLineageStore,
 };
 
+pub mod skipping;
+
+/// An event sampler for the independent algorithm which also defines the
+///  rate at which it samples events at every location.
+#[allow(clippy::inline_always, clippy::inline_fn_without_body)]
+#[allow(clippy::module_name_repetitions, clippy::type_complexity)]
+#[contract_trait]
+pub trait IndependentEventRate<
+    H: Habitat,
+    G: RngCore,
+    X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+    D: DispersalSampler<H, G>,
+    T: TurnoverRate<H>,
+    N: SpeciationProbability<H>,
+>:
+    MinSpeciationTrackingEventSampler<
+    H,
+    G,
+    GlobalLineageReference,
+    IndependentLineageStore<H>,
+    X,
+    D,
+    IndependentCoalescenceSampler<H>,
+    T,
+    N,
+>
+{
+    #[must_use]
+    #[debug_requires(
+        simulation.habitat.get_habitat_at_location(location) > 0,
+        "location is habitable"
+    )]
+    fn get_event_rate_at_location(
+        &self,
+        location: &Location,
+        simulation: &PartialSimulation<
+            H,
+            G,
+            GlobalLineageReference,
+            IndependentLineageStore<H>,
+            X,
+            D,
+            IndependentCoalescenceSampler<H>,
+            T,
+            N,
+        >,
+    ) -> PositiveF64;
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
 #[cfg_attr(feature = "cuda", r2cBound(H: rust_cuda::common::RustToCuda))]
@@ -226,3 +275,46 @@ impl<
         old_value
     }
 }
+
+#[contract_trait]
+impl<
+        H: Habitat,
+        G: RngCore,
+        X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+        D: DispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+    > IndependentEventRate<H, G, X, D, T, N> for IndependentEventSampler<H, G, X, D, T, N>
+{
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn get_event_rate_at_location(
+        &self,
+        location: &Location,
+        simulation: &PartialSimulation<
+            H,
+            G,
+            GlobalLineageReference,
+            IndependentLineageStore<H>,
+            X,
+            D,
+            IndependentCoalescenceSampler<H>,
+            T,
+            N,
+        >,
+    ) -> PositiveF64 {
+        // Safety: The turnover rate is >= 0.0 and must actually be > 0.0 because
+        //  * `location` is habitable by this method's precondition
+        //  * Therefore, `turnover_rate` must return a positive value by its
+        //    postcondition
+        unsafe {
+            PositiveF64::new_unchecked(
+                simulation
+                    .turnover_rate
+                    .get_turnover_rate_at_location(location, &simulation.habitat)
+                    .get(),
+            )
+        }
+    }
+}
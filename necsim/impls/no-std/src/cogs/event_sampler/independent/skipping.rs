@@ -0,0 +1,408 @@
+use core::marker::PhantomData;
+
+use necsim_core::{
+    cogs::{
+        Backup, CoalescenceRngSample, CoalescenceSampler, EmigrationExit, EventSampler, Habitat,
+        MinSpeciationTrackingEventSampler, RngCore, SeparableDispersalSampler,
+        SpeciationProbability, SpeciationSample, TurnoverRate,
+    },
+    event::{DispersalEvent, LineageInteraction, PackedEvent, SpeciationEvent},
+    landscape::{IndexedLocation, Location},
+    lineage::GlobalLineageReference,
+    simulation::partial::event_sampler::PartialSimulation,
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+
+use crate::cogs::{
+    coalescence_sampler::independent::IndependentCoalescenceSampler,
+    lineage_store::independent::IndependentLineageStore,
+};
+
+use super::IndependentEventRate;
+
+/// The `SkippingIndependentEventSampler` never samples the no-op events in
+///  which a lineage disperses back to its own indexed location. Instead, the
+///  turnover rate is scaled down by the probability of these no-op events.
+///
+/// Self-dispersal to a different index within the same location is still
+///  sampled, as the independent algorithm relies on it to detect coalescence.
+///  At locations with a habitat of one, all self-dispersal is skipped.
+///
+/// At a location with a habitat of one, no speciation, and only
+///  self-dispersal, every event would be skipped. Instead, no events are
+///  skipped at such a location, where a lineage can never move, just like in
+///  the `IndependentEventSampler`.
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
+#[cfg_attr(feature = "cuda", r2cBound(H: rust_cuda::common::RustToCuda))]
+#[cfg_attr(feature = "cuda", r2cBound(G: rust_cuda::common::RustToCuda))]
+#[cfg_attr(feature = "cuda", r2cBound(X: rust_cuda::common::RustToCuda))]
+#[cfg_attr(feature = "cuda", r2cBound(D: rust_cuda::common::RustToCuda))]
+#[cfg_attr(feature = "cuda", r2cBound(T: rust_cuda::common::RustToCuda))]
+#[cfg_attr(feature = "cuda", r2cBound(N: rust_cuda::common::RustToCuda))]
+#[derive(Debug)]
+pub struct SkippingIndependentEventSampler<
+    H: Habitat,
+    G: RngCore,
+    X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+    D: SeparableDispersalSampler<H, G>,
+    T: TurnoverRate<H>,
+    N: SpeciationProbability<H>,
+> {
+    min_spec_sample: Option<SpeciationSample>,
+    marker: PhantomData<(H, G, X, D, T, N)>,
+}
+
+impl<
+        H: Habitat,
+        G: RngCore,
+        X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+        D: SeparableDispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+    > Default for SkippingIndependentEventSampler<H, G, X, D, T, N>
+{
+    fn default() -> Self {
+        Self {
+            min_spec_sample: None,
+            marker: PhantomData::<(H, G, X, D, T, N)>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<
+        H: Habitat,
+        G: RngCore,
+        X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+        D: SeparableDispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+    > Backup for SkippingIndependentEventSampler<H, G, X, D, T, N>
+{
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            min_spec_sample: self.min_spec_sample.clone(),
+            marker: PhantomData::<(H, G, X, D, T, N)>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<
+        H: Habitat,
+        G: RngCore,
+        X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+        D: SeparableDispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+    >
+    EventSampler<
+        H,
+        G,
+        GlobalLineageReference,
+        IndependentLineageStore<H>,
+        X,
+        D,
+        IndependentCoalescenceSampler<H>,
+        T,
+        N,
+    > for SkippingIndependentEventSampler<H, G, X, D, T, N>
+{
+    #[must_use]
+    #[allow(clippy::type_complexity, clippy::shadow_unrelated)]
+    #[allow(clippy::too_many_lines)]
+    #[inline]
+    fn sample_event_for_lineage_at_indexed_location_time_or_emigrate(
+        &mut self,
+        lineage_reference: GlobalLineageReference,
+        indexed_location: IndexedLocation,
+        prior_time: NonNegativeF64,
+        event_time: PositiveF64,
+        simulation: &mut PartialSimulation<
+            H,
+            G,
+            GlobalLineageReference,
+            IndependentLineageStore<H>,
+            X,
+            D,
+            IndependentCoalescenceSampler<H>,
+            T,
+            N,
+        >,
+        rng: &mut G,
+    ) -> Option<PackedEvent> {
+        use necsim_core::cogs::RngSampler;
+
+        let speciation_sample = rng.sample_uniform();
+
+        let min_speciation_sample =
+            SpeciationSample::new(indexed_location.clone(), event_time, speciation_sample);
+
+        match &self.min_spec_sample {
+            Some(spec_sample) if spec_sample <= &min_speciation_sample => (),
+            _ => self.min_spec_sample = Some(min_speciation_sample),
+        }
+
+        let dispersal_origin = indexed_location;
+
+        let habitat_at_location = simulation
+            .habitat
+            .get_habitat_at_location(dispersal_origin.location());
+        let speciation_probability = simulation
+            .speciation_probability
+            .get_speciation_probability_at_location(
+                dispersal_origin.location(),
+                &simulation.habitat,
+            );
+        let self_dispersal_probability = simulation
+            .dispersal_sampler
+            .get_self_dispersal_probability_at_location(
+                dispersal_origin.location(),
+                &simulation.habitat,
+            );
+
+        let non_skipped_event_probability = Self::non_skipped_event_probability(
+            habitat_at_location,
+            speciation_probability,
+            self_dispersal_probability,
+        );
+        let skipping = Self::non_skipped_event_rate(
+            simulation
+                .turnover_rate
+                .get_turnover_rate_at_location(dispersal_origin.location(), &simulation.habitat),
+            non_skipped_event_probability,
+        )
+        .is_some();
+
+        // The speciation probability is conditioned on the event not being skipped
+        if speciation_sample.get()
+            * (if skipping {
+                non_skipped_event_probability
+            } else {
+                1.0_f64
+            })
+            < speciation_probability.get()
+        {
+            return Some(
+                SpeciationEvent {
+                    origin: dispersal_origin,
+                    prior_time,
+                    event_time,
+                    global_lineage_reference: lineage_reference,
+                }
+                .into(),
+            );
+        }
+
+        let self_dispersal_to_other_index = skipping
+            && habitat_at_location > 1
+            && self_dispersal_probability > 0.0_f64
+            && rng.sample_event(Self::self_dispersal_to_other_index_probability(
+                habitat_at_location,
+                self_dispersal_probability,
+            ));
+
+        let dispersal_target = if self_dispersal_to_other_index {
+            dispersal_origin.location().clone()
+        } else if skipping {
+            simulation
+                .dispersal_sampler
+                .sample_non_self_dispersal_from_location(
+                    dispersal_origin.location(),
+                    &simulation.habitat,
+                    rng,
+                )
+        } else {
+            simulation.dispersal_sampler.sample_dispersal_from_location(
+                dispersal_origin.location(),
+                &simulation.habitat,
+                rng,
+            )
+        };
+
+        // Check for emigration and return None iff lineage emigrated
+        let (lineage_reference, dispersal_origin, dispersal_target, prior_time, event_time) =
+            simulation.with_mut_split_emigration_exit(|emigration_exit, simulation| {
+                emigration_exit.optionally_emigrate(
+                    lineage_reference,
+                    dispersal_origin,
+                    dispersal_target,
+                    prior_time,
+                    event_time,
+                    simulation,
+                    rng,
+                )
+            })?;
+
+        let (dispersal_target, interaction) = if self_dispersal_to_other_index {
+            // Sample uniformly from all indices but the origin's own index
+            let other_index =
+                CoalescenceRngSample::new(rng).sample_coalescence_index(habitat_at_location - 1);
+
+            let target_index = if other_index >= dispersal_origin.index() {
+                other_index + 1
+            } else {
+                other_index
+            };
+
+            (
+                IndexedLocation::new(dispersal_target, target_index),
+                LineageInteraction::Maybe,
+            )
+        } else {
+            simulation
+                .coalescence_sampler
+                .sample_interaction_at_location(
+                    dispersal_target,
+                    &simulation.habitat,
+                    &simulation.lineage_store,
+                    CoalescenceRngSample::new(rng),
+                )
+        };
+
+        Some(
+            DispersalEvent {
+                origin: dispersal_origin,
+                prior_time,
+                event_time,
+                global_lineage_reference: lineage_reference,
+                target: dispersal_target,
+                interaction,
+            }
+            .into(),
+        )
+    }
+}
+
+impl<
+        H: Habitat,
+        G: RngCore,
+        X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+        D: SeparableDispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+    >
+    MinSpeciationTrackingEventSampler<
+        H,
+        G,
+        GlobalLineageReference,
+        IndependentLineageStore<H>,
+        X,
+        D,
+        IndependentCoalescenceSampler<H>,
+        T,
+        N,
+    > for SkippingIndependentEventSampler<H, G, X, D, T, N>
+{
+    fn replace_min_speciation(
+        &mut self,
+        new: Option<SpeciationSample>,
+    ) -> Option<SpeciationSample> {
+        // `core::mem::replace()` would be semantically better
+        //  - but `clone()` does not spill to local memory
+        let old_value = self.min_spec_sample.clone();
+
+        self.min_spec_sample = new;
+
+        old_value
+    }
+}
+
+#[contract_trait]
+impl<
+        H: Habitat,
+        G: RngCore,
+        X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+        D: SeparableDispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+    > IndependentEventRate<H, G, X, D, T, N> for SkippingIndependentEventSampler<H, G, X, D, T, N>
+{
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn get_event_rate_at_location(
+        &self,
+        location: &Location,
+        simulation: &PartialSimulation<
+            H,
+            G,
+            GlobalLineageReference,
+            IndependentLineageStore<H>,
+            X,
+            D,
+            IndependentCoalescenceSampler<H>,
+            T,
+            N,
+        >,
+    ) -> PositiveF64 {
+        let turnover_rate = simulation
+            .turnover_rate
+            .get_turnover_rate_at_location(location, &simulation.habitat);
+
+        let non_skipped_event_probability = Self::non_skipped_event_probability(
+            simulation.habitat.get_habitat_at_location(location),
+            simulation
+                .speciation_probability
+                .get_speciation_probability_at_location(location, &simulation.habitat),
+            simulation
+                .dispersal_sampler
+                .get_self_dispersal_probability_at_location(location, &simulation.habitat),
+        );
+
+        Self::non_skipped_event_rate(turnover_rate, non_skipped_event_probability).unwrap_or_else(
+            // Safety: The turnover rate is > 0.0 as `location` is habitable
+            || unsafe { PositiveF64::new_unchecked(turnover_rate.get()) },
+        )
+    }
+}
+
+impl<
+        H: Habitat,
+        G: RngCore,
+        X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+        D: SeparableDispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+    > SkippingIndependentEventSampler<H, G, X, D, T, N>
+{
+    /// Probability that an event does not return the lineage to its own
+    ///  indexed location, i.e. `1 - (1 - nu) * s / habitat`
+    fn non_skipped_event_probability(
+        habitat_at_location: u32,
+        speciation_probability: ClosedUnitF64,
+        self_dispersal_probability: ClosedUnitF64,
+    ) -> f64 {
+        1.0_f64
+            - speciation_probability.one_minus().get() * self_dispersal_probability.get()
+                / f64::from(habitat_at_location)
+    }
+
+    /// Rate of the non-skipped events, or `None` iff every event would be
+    ///  skipped, in which case no events are skipped at all
+    fn non_skipped_event_rate(
+        turnover_rate: NonNegativeF64,
+        non_skipped_event_probability: f64,
+    ) -> Option<PositiveF64> {
+        PositiveF64::new(turnover_rate.get() * non_skipped_event_probability).ok()
+    }
+
+    /// Probability that a non-speciation, non-skipped event is a dispersal to
+    ///  a different index at the same location, i.e. `s * (habitat - 1) /
+    ///  (habitat - s)`
+    fn self_dispersal_to_other_index_probability(
+        habitat_at_location: u32,
+        self_dispersal_probability: ClosedUnitF64,
+    ) -> ClosedUnitF64 {
+        let habitat_at_location = f64::from(habitat_at_location);
+
+        // Safety: habitat >= 2 > 1 >= s, and s * (habitat - 1) <= habitat - s
+        unsafe {
+            ClosedUnitF64::new_unchecked(
+                self_dispersal_probability.get() * (habitat_at_location - 1.0_f64)
+                    / (habitat_at_location - self_dispersal_probability.get()),
+            )
+        }
+    }
+}
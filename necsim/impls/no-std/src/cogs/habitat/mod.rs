@@ -2,3 +2,4 @@ pub mod almost_infinite;
 pub mod in_memory;
 pub mod non_spatial;
 pub mod spatially_implicit;
+pub mod torus;
@@ -0,0 +1,72 @@
+use necsim_core::{
+    cogs::{Backup, Habitat},
+    landscape::{IndexedLocation, LandscapeExtent, Location},
+};
+
+use crate::cogs::habitat::non_spatial::NonSpatialHabitat;
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::common::RustToCuda))]
+#[derive(Debug)]
+pub struct TorusHabitat {
+    // The torus has the same uniform habitat as a non-spatial landscape,
+    //  only its dispersal wraps around the periodic boundaries
+    #[cfg_attr(feature = "cuda", r2cEmbed)]
+    landscape: NonSpatialHabitat,
+}
+
+impl TorusHabitat {
+    #[must_use]
+    #[debug_ensures(
+        ret.get_total_habitat() == old(u64::from(width) * u64::from(height) * u64::from(deme)),
+        "creates a habitat with community size width * height * deme"
+    )]
+    pub fn new(width: u32, height: u32, deme: u32) -> Self {
+        Self {
+            landscape: NonSpatialHabitat::new((width, height), deme),
+        }
+    }
+
+    #[must_use]
+    pub fn get_deme(&self) -> u32 {
+        self.landscape.get_deme()
+    }
+
+    #[must_use]
+    pub fn landscape(&self) -> &NonSpatialHabitat {
+        &self.landscape
+    }
+}
+
+#[contract_trait]
+impl Backup for TorusHabitat {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            landscape: self.landscape.backup_unchecked(),
+        }
+    }
+}
+
+#[contract_trait]
+impl Habitat for TorusHabitat {
+    #[must_use]
+    fn get_extent(&self) -> &LandscapeExtent {
+        self.landscape.get_extent()
+    }
+
+    #[must_use]
+    fn get_total_habitat(&self) -> u64 {
+        self.landscape.get_total_habitat()
+    }
+
+    #[must_use]
+    fn get_habitat_at_location(&self, location: &Location) -> u32 {
+        self.landscape.get_habitat_at_location(location)
+    }
+
+    #[must_use]
+    fn map_indexed_location_to_u64_injective(&self, indexed_location: &IndexedLocation) -> u64 {
+        self.landscape
+            .map_indexed_location_to_u64_injective(indexed_location)
+    }
+}
@@ -1,9 +1,12 @@
+use alloc::vec::Vec;
 use core::{fmt, iter::Iterator};
 
+use array2d::Array2D;
+
 use necsim_core::{
     cogs::OriginSampler,
-    intrinsics::ceil,
-    landscape::{IndexedLocation, LandscapeExtent, LocationIterator},
+    intrinsics::{ceil, sqrt},
+    landscape::{IndexedLocation, Location},
 };
 
 use crate::cogs::{
@@ -12,12 +15,146 @@ use crate::cogs::{
 
 const HABITAT_CENTRE: u32 = u32::MAX / 2;
 
+/// A shape from which individuals are sampled, which is positioned
+///  relative to the centre of the almost infinite habitat
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub enum AlmostInfiniteSampleShape {
+    /// Filled circle with the given `radius` around the `centre`
+    Circle { centre: (i32, i32), radius: u32 },
+    /// Rectangle of the given `size` whose top-left corner is at the `origin`
+    Rectangle {
+        origin: (i32, i32),
+        size: (u32, u32),
+    },
+    /// Mask whose top-left corner is at the `origin`, in which all `true`
+    ///  cells are sampled
+    Mask {
+        origin: (i32, i32),
+        mask: Array2D<bool>,
+    },
+}
+
+impl AlmostInfiniteSampleShape {
+    /// Checks that all locations in this shape are inside the almost
+    ///  infinite habitat
+    #[must_use]
+    pub fn fits_into_habitat(&self) -> bool {
+        let ((min_column, max_column), (min_row, max_row)) = self.bounds();
+
+        // Empty shapes always fit
+        if min_column > max_column || min_row > max_row {
+            return true;
+        }
+
+        let centre = i64::from(HABITAT_CENTRE);
+        let limit = i64::from(u32::MAX);
+
+        (centre + min_column) >= 0
+            && (centre + max_column) < limit
+            && (centre + min_row) >= 0
+            && (centre + max_row) < limit
+    }
+
+    /// Inclusive column and row bounds of the shape relative to the centre
+    fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+        match self {
+            Self::Circle {
+                centre: (x, y),
+                radius,
+            } => (
+                (
+                    i64::from(*x) - i64::from(*radius),
+                    i64::from(*x) + i64::from(*radius),
+                ),
+                (
+                    i64::from(*y) - i64::from(*radius),
+                    i64::from(*y) + i64::from(*radius),
+                ),
+            ),
+            Self::Rectangle {
+                origin: (x, y),
+                size: (width, height),
+            } => (
+                (i64::from(*x), i64::from(*x) + i64::from(*width) - 1),
+                (i64::from(*y), i64::from(*y) + i64::from(*height) - 1),
+            ),
+            #[allow(clippy::cast_possible_wrap)]
+            Self::Mask {
+                origin: (x, y),
+                mask,
+            } => (
+                (i64::from(*x), i64::from(*x) + mask.num_columns() as i64 - 1),
+                (i64::from(*y), i64::from(*y) + mask.num_rows() as i64 - 1),
+            ),
+        }
+    }
+
+    /// Appends the inclusive column spans of the shape in the given `row`
+    fn push_row_spans(&self, row: i64, spans: &mut Vec<(i64, i64)>) {
+        let ((min_column, max_column), (min_row, max_row)) = self.bounds();
+
+        if row < min_row || row > max_row || min_column > max_column {
+            return;
+        }
+
+        match self {
+            Self::Circle {
+                centre: (x, y),
+                radius,
+            } => {
+                let dy = row - i64::from(*y);
+
+                #[allow(clippy::cast_sign_loss)]
+                let half_width =
+                    integer_sqrt(u64::from(*radius) * u64::from(*radius) - (dy * dy) as u64);
+
+                #[allow(clippy::cast_possible_wrap)]
+                spans.push((
+                    i64::from(*x) - half_width as i64,
+                    i64::from(*x) + half_width as i64,
+                ));
+            },
+            Self::Rectangle { .. } => spans.push((min_column, max_column)),
+            Self::Mask { mask, .. } => {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let mask_row = (row - min_row) as usize;
+
+                let mut span_start = None;
+
+                #[allow(clippy::cast_possible_wrap)]
+                for (mask_column, sampled) in
+                    mask.row_iter(mask_row).into_iter().flatten().enumerate()
+                {
+                    let column = min_column + mask_column as i64;
+
+                    match (span_start, *sampled) {
+                        (None, true) => span_start = Some(column),
+                        (Some(start), false) => {
+                            spans.push((start, column - 1));
+                            span_start = None;
+                        },
+                        _ => (),
+                    }
+                }
+
+                if let Some(start) = span_start {
+                    spans.push((start, max_column));
+                }
+            },
+        }
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 pub struct AlmostInfiniteOriginSampler<'h, I: Iterator<Item = u64>> {
     pre_sampler: OriginPreSampler<I>,
     last_index: u64,
-    location_iterator: LocationIterator,
-    radius_squared: u64,
+    sample: &'h [AlmostInfiniteSampleShape],
+    row: Option<i64>,
+    spans: Vec<(i64, i64)>,
+    span_index: usize,
+    column: i64,
     upper_bound_size_hint: u64,
     habitat: &'h AlmostInfiniteHabitat,
 }
@@ -27,8 +164,11 @@ impl<'h, I: Iterator<Item = u64>> fmt::Debug for AlmostInfiniteOriginSampler<'h,
         f.debug_struct("AlmostInfiniteOriginSampler")
             .field("pre_sampler", &self.pre_sampler)
             .field("last_index", &self.last_index)
-            .field("location_iterator", &self.location_iterator)
-            .field("radius_squared", &self.radius_squared)
+            .field("sample", &self.sample)
+            .field("row", &self.row)
+            .field("spans", &self.spans)
+            .field("span_index", &self.span_index)
+            .field("column", &self.column)
             .field("upper_bound_size_hint", &self.upper_bound_size_hint)
             .field("habitat", &self.habitat)
             .finish()
@@ -36,37 +176,67 @@ impl<'h, I: Iterator<Item = u64>> fmt::Debug for AlmostInfiniteOriginSampler<'h,
 }
 
 impl<'h, I: Iterator<Item = u64>> AlmostInfiniteOriginSampler<'h, I> {
-    #[debug_requires(radius < (u32::MAX / 2), "sample circle fits into almost infinite habitat")]
+    #[debug_requires(
+        sample.iter().all(AlmostInfiniteSampleShape::fits_into_habitat),
+        "sample shapes fit into almost infinite habitat"
+    )]
     #[must_use]
     pub fn new(
         pre_sampler: OriginPreSampler<I>,
         habitat: &'h AlmostInfiniteHabitat,
-        radius: u32,
+        sample: &'h [AlmostInfiniteSampleShape],
     ) -> Self {
-        let sample_extent = LandscapeExtent::new(
-            HABITAT_CENTRE - radius,
-            HABITAT_CENTRE - radius,
-            radius * 2 + 1,
-            radius * 2 + 1,
-        );
-
-        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        let upper_bound_size_hint = ceil(
-            f64::from(radius)
-                * f64::from(radius)
-                * core::f64::consts::PI
-                * pre_sampler.get_sample_proportion(),
-        ) as u64;
+        let mut spans = Vec::new();
+
+        // The union of all sample shapes is counted row by row
+        let mut sample_size = 0_u64;
+        let mut row = next_sample_row(sample, i64::MIN);
+
+        while let Some(current_row) = row {
+            sample_row_spans(sample, current_row, &mut spans);
+
+            #[allow(clippy::cast_sign_loss)]
+            for (start, end) in &spans {
+                sample_size += (end - start + 1) as u64;
+            }
+
+            row = next_sample_row(sample, current_row + 1);
+        }
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let upper_bound_size_hint =
+            ceil((sample_size as f64) * pre_sampler.get_sample_proportion()) as u64;
+
+        let row = next_sample_row(sample, i64::MIN);
+
+        if let Some(row) = row {
+            sample_row_spans(sample, row, &mut spans);
+        }
 
         Self {
             pre_sampler,
             last_index: 0_u64,
-            location_iterator: sample_extent.iter(),
-            radius_squared: u64::from(radius) * u64::from(radius),
+            sample,
+            row,
+            column: spans.first().map_or(0_i64, |(start, _)| *start),
+            spans,
+            span_index: 0_usize,
             upper_bound_size_hint,
             habitat,
         }
     }
+
+    fn next_span(&mut self) {
+        self.span_index += 1;
+
+        if let Some((start, _)) = self.spans.get(self.span_index) {
+            self.column = *start;
+        }
+    }
 }
 
 #[contract_trait]
@@ -90,22 +260,108 @@ impl<'h, I: Iterator<Item = u64>> Iterator for AlmostInfiniteOriginSampler<'h, I
         let mut index_difference = next_index - self.last_index;
         self.last_index = next_index + 1;
 
-        for next_location in &mut self.location_iterator {
-            let dx = i64::from(next_location.x()) - i64::from(HABITAT_CENTRE);
-            let dy = i64::from(next_location.y()) - i64::from(HABITAT_CENTRE);
+        loop {
+            let row = self.row?;
 
-            #[allow(clippy::cast_sign_loss)]
-            let distance_squared = (dx * dx) as u64 + (dy * dy) as u64;
+            if let Some((_, end)) = self.spans.get(self.span_index).copied() {
+                #[allow(clippy::cast_sign_loss)]
+                let remaining = (end - self.column + 1) as u64;
 
-            if distance_squared <= self.radius_squared {
-                if index_difference == 0 {
-                    return Some(IndexedLocation::new(next_location, 0));
+                // Entire spans of unsampled locations are skipped at once
+                if index_difference < remaining {
+                    #[allow(clippy::cast_possible_wrap)]
+                    let column = self.column + index_difference as i64;
+
+                    if column < end {
+                        self.column = column + 1;
+                    } else {
+                        self.next_span();
+                    }
+
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    return Some(IndexedLocation::new(
+                        Location::new(
+                            (i64::from(HABITAT_CENTRE) + column) as u32,
+                            (i64::from(HABITAT_CENTRE) + row) as u32,
+                        ),
+                        0,
+                    ));
                 }
 
-                index_difference -= 1;
+                index_difference -= remaining;
+
+                self.next_span();
+            } else {
+                self.row = next_sample_row(self.sample, row + 1);
+
+                if let Some(row) = self.row {
+                    sample_row_spans(self.sample, row, &mut self.spans);
+                }
+
+                self.span_index = 0;
+                self.column = self.spans.first().map_or(0_i64, |(start, _)| *start);
             }
         }
+    }
+}
+
+/// Finds the first row at or after `from_row` which any sample shape covers
+fn next_sample_row(sample: &[AlmostInfiniteSampleShape], from_row: i64) -> Option<i64> {
+    sample
+        .iter()
+        .filter_map(|shape| {
+            let ((min_column, max_column), (min_row, max_row)) = shape.bounds();
 
-        None
+            if min_column > max_column || min_row > max_row || max_row < from_row {
+                None
+            } else {
+                Some(min_row.max(from_row))
+            }
+        })
+        .min()
+}
+
+/// Computes the sorted and disjoint column spans of the union of all sample
+///  shapes in the given `row`
+fn sample_row_spans(sample: &[AlmostInfiniteSampleShape], row: i64, spans: &mut Vec<(i64, i64)>) {
+    spans.clear();
+
+    for shape in sample {
+        shape.push_row_spans(row, spans);
+    }
+
+    spans.sort_unstable();
+
+    let mut merged = 0;
+
+    for i in 0..spans.len() {
+        if merged > 0 && spans[i].0 <= (spans[merged - 1].1 + 1) {
+            spans[merged - 1].1 = spans[merged - 1].1.max(spans[i].1);
+        } else {
+            spans[merged] = spans[i];
+            merged += 1;
+        }
     }
+
+    spans.truncate(merged);
+}
+
+/// Computes `floor(sqrt(value))` exactly
+fn integer_sqrt(value: u64) -> u64 {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let mut root = sqrt(value as f64) as u64;
+
+    while root * root > value {
+        root -= 1;
+    }
+
+    while (root + 1) * (root + 1) <= value {
+        root += 1;
+    }
+
+    root
 }
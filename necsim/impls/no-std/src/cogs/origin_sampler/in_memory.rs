@@ -4,8 +4,11 @@ use core::{
     iter::{Iterator, Peekable},
 };
 
+use array2d::Array2D;
+
 use necsim_core::{
     cogs::{Habitat, OriginSampler},
+    intrinsics::floor,
     landscape::{IndexedLocation, LocationIterator},
 };
 
@@ -19,6 +22,8 @@ pub struct InMemoryOriginSampler<'h, I: Iterator<Item = u64>> {
     last_index: u64,
     location_iterator: Peekable<LocationIterator>,
     next_location_index: u32,
+    sample_map: Option<&'h Array2D<f64>>,
+    sample_size: u64,
     habitat: &'h InMemoryHabitat,
 }
 
@@ -29,6 +34,8 @@ impl<'h, I: Iterator<Item = u64>> fmt::Debug for InMemoryOriginSampler<'h, I> {
             .field("last_index", &self.last_index)
             .field("location_iterator", &self.location_iterator)
             .field("next_location_index", &self.next_location_index)
+            .field("sample_map", &self.sample_map)
+            .field("sample_size", &self.sample_size)
             .field("habitat", &self.habitat)
             .finish()
     }
@@ -42,36 +49,56 @@ impl<'h, I: Iterator<Item = u64>> InMemoryOriginSampler<'h, I> {
             last_index: 0_u64,
             location_iterator: habitat.get_extent().iter().peekable(),
             next_location_index: 0_u32,
+            sample_map: None,
+            sample_size: habitat.get_total_habitat(),
             habitat,
         }
     }
-}
-
-#[contract_trait]
-impl<'h, I: Iterator<Item = u64>> OriginSampler<'h> for InMemoryOriginSampler<'h, I> {
-    type Habitat = InMemoryHabitat;
 
-    fn habitat(&self) -> &'h Self::Habitat {
-        self.habitat
-    }
+    /// Creates an origin sampler which only samples the fraction of the
+    ///  individuals at each location that is given by the `sample_map`.
+    ///
+    /// The per-location sampling is deterministic and only depends on each
+    ///  individual's index, such that it stays consistent with the
+    ///  `pre_sampler`, e.g. when it is partitioned.
+    #[debug_requires(
+        sample_map.num_rows() == habitat.get_extent().height() as usize &&
+        sample_map.num_columns() == habitat.get_extent().width() as usize,
+        "sample map has the same size as the habitat"
+    )]
+    #[debug_requires(
+        sample_map.elements_row_major_iter().all(|f| (0.0_f64..=1.0_f64).contains(f)),
+        "sample map only contains fractions in [0, 1]"
+    )]
+    #[must_use]
+    pub fn with_sample_map(
+        pre_sampler: OriginPreSampler<I>,
+        habitat: &'h InMemoryHabitat,
+        sample_map: &'h Array2D<f64>,
+    ) -> Self {
+        let sample_size = habitat
+            .get_extent()
+            .iter()
+            .map(|location| {
+                sampled_individuals(
+                    sample_map[(location.y() as usize, location.x() as usize)],
+                    habitat.get_habitat_at_location(&location),
+                )
+            })
+            .sum();
 
-    fn full_upper_bound_size_hint(&self) -> u64 {
-        #[allow(
-            clippy::cast_possible_truncation,
-            clippy::cast_sign_loss,
-            clippy::cast_precision_loss
-        )]
-        {
-            ((self.habitat.get_total_habitat() as f64) * self.pre_sampler.get_sample_proportion())
-                as u64
+        Self {
+            pre_sampler,
+            last_index: 0_u64,
+            location_iterator: habitat.get_extent().iter().peekable(),
+            next_location_index: 0_u32,
+            sample_map: Some(sample_map),
+            sample_size,
+            habitat,
         }
     }
-}
-
-impl<'h, I: Iterator<Item = u64>> Iterator for InMemoryOriginSampler<'h, I> {
-    type Item = IndexedLocation;
 
-    fn next(&mut self) -> Option<Self::Item> {
+    fn next_pre_sampled(&mut self) -> Option<IndexedLocation> {
         let next_index = self.pre_sampler.next()?;
         let mut index_difference = next_index - self.last_index;
         self.last_index = next_index;
@@ -103,3 +130,63 @@ impl<'h, I: Iterator<Item = u64>> Iterator for InMemoryOriginSampler<'h, I> {
         ))
     }
 }
+
+#[contract_trait]
+impl<'h, I: Iterator<Item = u64>> OriginSampler<'h> for InMemoryOriginSampler<'h, I> {
+    type Habitat = InMemoryHabitat;
+
+    fn habitat(&self) -> &'h Self::Habitat {
+        self.habitat
+    }
+
+    fn full_upper_bound_size_hint(&self) -> u64 {
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        {
+            ((self.sample_size as f64) * self.pre_sampler.get_sample_proportion()) as u64
+        }
+    }
+}
+
+impl<'h, I: Iterator<Item = u64>> Iterator for InMemoryOriginSampler<'h, I> {
+    type Item = IndexedLocation;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let indexed_location = self.next_pre_sampled()?;
+
+            let sample_map = match self.sample_map {
+                Some(sample_map) => sample_map,
+                None => return Some(indexed_location),
+            };
+
+            let location = indexed_location.location();
+
+            if is_individual_sampled(
+                sample_map[(location.y() as usize, location.x() as usize)],
+                indexed_location.index(),
+            ) {
+                return Some(indexed_location);
+            }
+        }
+    }
+}
+
+/// Deterministically thins out the individuals at a location such that
+///  `round(deme * fraction)` of them, spread evenly across their indices,
+///  are sampled
+fn is_individual_sampled(fraction: f64, index: u32) -> bool {
+    floor(f64::from(index + 1) * fraction + 0.5_f64) > floor(f64::from(index) * fraction + 0.5_f64)
+}
+
+/// Number of individuals at a location with the given `deme` which are
+///  sampled with the per-location `fraction`
+fn sampled_individuals(fraction: f64, deme: u32) -> u64 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    {
+        floor(f64::from(deme) * fraction + 0.5_f64) as u64
+    }
+}
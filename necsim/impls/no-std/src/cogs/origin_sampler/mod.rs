@@ -4,3 +4,4 @@ pub mod in_memory;
 pub mod non_spatial;
 pub mod pre_sampler;
 pub mod spatially_implicit;
+pub mod torus;
@@ -0,0 +1,54 @@
+use core::{fmt, iter::Iterator};
+
+use necsim_core::{cogs::OriginSampler, landscape::IndexedLocation};
+
+use crate::cogs::{
+    habitat::torus::TorusHabitat,
+    origin_sampler::{non_spatial::NonSpatialOriginSampler, pre_sampler::OriginPreSampler},
+};
+
+#[allow(clippy::module_name_repetitions)]
+pub struct TorusOriginSampler<'h, I: Iterator<Item = u64>> {
+    landscape_iterator: NonSpatialOriginSampler<'h, I>,
+    habitat: &'h TorusHabitat,
+}
+
+impl<'h, I: Iterator<Item = u64>> fmt::Debug for TorusOriginSampler<'h, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TorusOriginSampler")
+            .field("landscape_iterator", &self.landscape_iterator)
+            .field("habitat", &self.habitat)
+            .finish()
+    }
+}
+
+impl<'h, I: Iterator<Item = u64>> TorusOriginSampler<'h, I> {
+    #[must_use]
+    pub fn new(pre_sampler: OriginPreSampler<I>, habitat: &'h TorusHabitat) -> Self {
+        Self {
+            landscape_iterator: NonSpatialOriginSampler::new(pre_sampler, habitat.landscape()),
+            habitat,
+        }
+    }
+}
+
+#[contract_trait]
+impl<'h, I: Iterator<Item = u64>> OriginSampler<'h> for TorusOriginSampler<'h, I> {
+    type Habitat = TorusHabitat;
+
+    fn habitat(&self) -> &'h Self::Habitat {
+        self.habitat
+    }
+
+    fn full_upper_bound_size_hint(&self) -> u64 {
+        self.landscape_iterator.full_upper_bound_size_hint()
+    }
+}
+
+impl<'h, I: Iterator<Item = u64>> Iterator for TorusOriginSampler<'h, I> {
+    type Item = IndexedLocation;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.landscape_iterator.next()
+    }
+}
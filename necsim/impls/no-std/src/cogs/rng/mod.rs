@@ -1,6 +1,11 @@
 pub mod aes;
 pub mod fixedseahash;
+pub mod philox;
 pub mod seahash;
+pub mod threefry;
 pub mod wyhash;
 pub mod wyrand;
 pub mod xxhash;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,164 @@
+use necsim_core::cogs::{Backup, PrimeableRng, RngCore, SplittableRng};
+
+// Philox4x32 constants
+// https://github.com/DEShawResearch/random123/blob/main/include/Random123/philox.h
+const PHILOX_M4X32_0: u32 = 0xD251_1F53;
+const PHILOX_M4X32_1: u32 = 0xCD9E_8D57;
+const PHILOX_W32_0: u32 = 0x9E37_79B9;
+const PHILOX_W32_1: u32 = 0xBB67_AE85;
+
+const PHILOX_ROUNDS: usize = 10;
+
+// The keys of split RNGs are derived from counters in separate domains
+const SPLIT_DOMAIN: u32 = 0x7370_6C74;
+const STREAM_DOMAIN: u32 = 0x7374_726D;
+
+/// Counter-based Philox4x32-10 RNG, which is keyed by the seed and
+///  countered by the `(location, time)` index it is primed with
+///
+/// The 128 bit `(location, time)` index already fills the entire counter.
+/// The number of blocks generated since priming is therefore folded into
+///  the most significant bits of the time index in bit-reversed order.
+/// Two primed streams can thus only overlap if their time indices differ in
+///  their top bits, i.e. if more than `2^k` blocks are generated after
+///  priming with a time index of at least `2^(64-k)`.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct Philox4x32 {
+    key: [u32; 2],
+    counter: [u32; 4],
+    block: u32,
+    buffer: [u32; 4],
+    buffered: usize,
+}
+
+#[contract_trait]
+impl Backup for Philox4x32 {
+    unsafe fn backup_unchecked(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl RngCore for Philox4x32 {
+    type Seed = [u8; 8];
+
+    #[must_use]
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut key = [0_u32; 2];
+
+        for (word, bytes) in key.iter_mut().zip(seed.chunks_exact(4)) {
+            let mut le_bytes = [0_u8; 4];
+            le_bytes.copy_from_slice(bytes);
+
+            *word = u32::from_le_bytes(le_bytes);
+        }
+
+        Self::from_key(key)
+    }
+
+    #[must_use]
+    #[inline]
+    fn sample_u64(&mut self) -> u64 {
+        if self.buffered == 0 {
+            let mut counter = self.counter;
+            counter[3] ^= self.block.reverse_bits();
+
+            self.buffer = philox4x32(counter, self.key);
+            self.buffered = self.buffer.len() / 2;
+
+            self.block = self.block.wrapping_add(1);
+        }
+
+        self.buffered -= 1;
+
+        let index = self.buffer.len() - 2 - self.buffered * 2;
+
+        u64::from(self.buffer[index]) | (u64::from(self.buffer[index + 1]) << 32)
+    }
+}
+
+impl PrimeableRng for Philox4x32 {
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn prime_with(&mut self, location_index: u64, time_index: u64) {
+        self.counter = [
+            location_index as u32,
+            (location_index >> 32) as u32,
+            time_index as u32,
+            (time_index >> 32) as u32,
+        ];
+        self.block = 0;
+        self.buffered = 0;
+    }
+}
+
+impl SplittableRng for Philox4x32 {
+    // The derived keys may coincide with primed outputs of the parent,
+    //  which is consumed by splitting and never generates them
+    fn split(self) -> (Self, Self) {
+        let left = Self::from_key(self.derive_key([0, 0, 0, SPLIT_DOMAIN]));
+        let right = Self::from_key(self.derive_key([1, 0, 0, SPLIT_DOMAIN]));
+
+        (left, right)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn split_to_stream(self, stream: u64) -> Self {
+        Self::from_key(self.derive_key([stream as u32, (stream >> 32) as u32, 0, STREAM_DOMAIN]))
+    }
+}
+
+impl Philox4x32 {
+    #[must_use]
+    #[inline]
+    fn from_key(key: [u32; 2]) -> Self {
+        Self {
+            key,
+            counter: [0_u32; 4],
+            block: 0_u32,
+            buffer: [0_u32; 4],
+            buffered: 0,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn derive_key(&self, counter: [u32; 4]) -> [u32; 2] {
+        let block = philox4x32(counter, self.key);
+
+        [block[0], block[1]]
+    }
+}
+
+#[inline]
+pub(super) fn philox4x32(mut counter: [u32; 4], mut key: [u32; 2]) -> [u32; 4] {
+    for round in 0..PHILOX_ROUNDS {
+        if round > 0 {
+            // Philox key schedule
+            key[0] = key[0].wrapping_add(PHILOX_W32_0);
+            key[1] = key[1].wrapping_add(PHILOX_W32_1);
+        }
+
+        // Philox S-box
+        let (hi0, lo0) = mulhilo(PHILOX_M4X32_0, counter[0]);
+        let (hi1, lo1) = mulhilo(PHILOX_M4X32_1, counter[2]);
+
+        counter = [
+            hi1 ^ counter[1] ^ key[0],
+            lo1,
+            hi0 ^ counter[3] ^ key[1],
+            lo0,
+        ];
+    }
+
+    counter
+}
+
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+fn mulhilo(a: u32, b: u32) -> (u32, u32) {
+    let product = u64::from(a) * u64::from(b);
+
+    ((product >> 32) as u32, product as u32)
+}
@@ -0,0 +1,70 @@
+use super::{philox::philox4x32, threefry::threefry4x64};
+
+// Known answer tests from the Random123 `kat_vectors`
+// https://github.com/DEShawResearch/random123/blob/main/tests/kat_vectors
+
+#[test]
+fn test_philox4x32_10_known_answers() {
+    assert_eq!(
+        philox4x32([0, 0, 0, 0], [0, 0]),
+        [0x6627_E8D5, 0xE169_C58D, 0xBC57_AC4C, 0x9B00_DBD8]
+    );
+    assert_eq!(
+        philox4x32(
+            [0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF],
+            [0xFFFF_FFFF, 0xFFFF_FFFF]
+        ),
+        [0x408F_276D, 0x41C8_3B0E, 0xA20B_C7C6, 0x6D54_51FD]
+    );
+    assert_eq!(
+        philox4x32(
+            [0x243F_6A88, 0x85A3_08D3, 0x1319_8A2E, 0x0370_7344],
+            [0xA409_3822, 0x299F_31D0]
+        ),
+        [0xD16C_FE09, 0x94FD_CCEB, 0x5001_E420, 0x2412_6EA1]
+    );
+}
+
+#[test]
+fn test_threefry4x64_20_known_answers() {
+    assert_eq!(
+        threefry4x64([0, 0, 0, 0], [0, 0, 0, 0]),
+        [
+            0x0921_8EBD_E6C8_5537,
+            0x5594_1F52_66D8_6105,
+            0x4BD2_5E16_2824_34DC,
+            0xEE29_EC84_6BD2_E40B,
+        ]
+    );
+    assert_eq!(
+        threefry4x64([u64::MAX; 4], [u64::MAX; 4]),
+        [
+            0x29C2_4097_942B_BA1B,
+            0x0371_BBFB_0F6F_4E11,
+            0x3C23_1FFA_33F8_3A1C,
+            0xCD29_113F_DE32_D168,
+        ]
+    );
+    assert_eq!(
+        threefry4x64(
+            [
+                0x243F_6A88_85A3_08D3,
+                0x1319_8A2E_0370_7344,
+                0xA409_3822_299F_31D0,
+                0x082E_FA98_EC4E_6C89,
+            ],
+            [
+                0x4528_21E6_38D0_1377,
+                0xBE54_66CF_34E9_0C6C,
+                0xBE54_66CF_34E9_0C6C,
+                0xC0AC_29B7_C97C_50DD,
+            ]
+        ),
+        [
+            0xA7E8_FDE5_9165_1BD9,
+            0xBAAF_D0C3_0138_319B,
+            0x84A5_C1A7_29E6_85B9,
+            0x901D_406C_CEBC_1BA4,
+        ]
+    );
+}
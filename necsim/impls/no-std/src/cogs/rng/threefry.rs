@@ -0,0 +1,155 @@
+use necsim_core::cogs::{Backup, PrimeableRng, RngCore, SplittableRng};
+
+// Threefry4x64 constants
+// https://github.com/DEShawResearch/random123/blob/main/include/Random123/threefry.h
+const SKEIN_KS_PARITY64: u64 = 0x1BD1_1BDA_A9FC_1A22;
+const ROTATIONS_4X64: [[u32; 2]; 8] = [
+    [14, 16],
+    [52, 57],
+    [23, 40],
+    [5, 37],
+    [25, 33],
+    [46, 12],
+    [58, 22],
+    [32, 32],
+];
+
+const THREEFRY_ROUNDS: usize = 20;
+
+// Random outputs always use a zero last counter word, while the keys of split
+//  RNGs are derived from counters in separate domains
+const SPLIT_DOMAIN: u64 = 0x7370_6C74;
+const STREAM_DOMAIN: u64 = 0x7374_726D;
+
+/// Counter-based Threefry4x64-20 RNG, which is keyed by the seed and
+///  countered by the `(location, time)` index it is primed with
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct Threefry4x64 {
+    key: [u64; 4],
+    counter: [u64; 4],
+    buffer: [u64; 4],
+    buffered: usize,
+}
+
+#[contract_trait]
+impl Backup for Threefry4x64 {
+    unsafe fn backup_unchecked(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl RngCore for Threefry4x64 {
+    type Seed = [u8; 32];
+
+    #[must_use]
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut key = [0_u64; 4];
+
+        for (word, bytes) in key.iter_mut().zip(seed.chunks_exact(8)) {
+            let mut le_bytes = [0_u8; 8];
+            le_bytes.copy_from_slice(bytes);
+
+            *word = u64::from_le_bytes(le_bytes);
+        }
+
+        Self::from_key(key)
+    }
+
+    #[must_use]
+    #[inline]
+    fn sample_u64(&mut self) -> u64 {
+        if self.buffered == 0 {
+            // the counter's third word counts the blocks since priming
+            self.buffer = threefry4x64(self.counter, self.key);
+            self.buffered = self.buffer.len();
+
+            self.counter[2] = self.counter[2].wrapping_add(1);
+        }
+
+        self.buffered -= 1;
+
+        self.buffer[self.buffer.len() - 1 - self.buffered]
+    }
+}
+
+impl PrimeableRng for Threefry4x64 {
+    #[inline]
+    fn prime_with(&mut self, location_index: u64, time_index: u64) {
+        self.counter = [location_index, time_index, 0, 0];
+        self.buffered = 0;
+    }
+}
+
+impl SplittableRng for Threefry4x64 {
+    fn split(self) -> (Self, Self) {
+        let left = Self::from_key(threefry4x64([0, 0, 0, SPLIT_DOMAIN], self.key));
+        let right = Self::from_key(threefry4x64([1, 0, 0, SPLIT_DOMAIN], self.key));
+
+        (left, right)
+    }
+
+    fn split_to_stream(self, stream: u64) -> Self {
+        Self::from_key(threefry4x64([stream, 0, 0, STREAM_DOMAIN], self.key))
+    }
+}
+
+impl Threefry4x64 {
+    #[must_use]
+    #[inline]
+    fn from_key(key: [u64; 4]) -> Self {
+        Self {
+            key,
+            counter: [0_u64; 4],
+            buffer: [0_u64; 4],
+            buffered: 0,
+        }
+    }
+}
+
+#[inline]
+pub(super) fn threefry4x64(counter: [u64; 4], key: [u64; 4]) -> [u64; 4] {
+    let schedule = [
+        key[0],
+        key[1],
+        key[2],
+        key[3],
+        SKEIN_KS_PARITY64 ^ key[0] ^ key[1] ^ key[2] ^ key[3],
+    ];
+
+    let mut x = counter;
+    inject_key(&mut x, &schedule, 0);
+
+    for round in 0..THREEFRY_ROUNDS {
+        let [r0, r1] = ROTATIONS_4X64[round % ROTATIONS_4X64.len()];
+
+        // Threefish MIX functions with alternating word permutations
+        if round % 2 == 0 {
+            x[0] = x[0].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(r0) ^ x[0];
+            x[2] = x[2].wrapping_add(x[3]);
+            x[3] = x[3].rotate_left(r1) ^ x[2];
+        } else {
+            x[0] = x[0].wrapping_add(x[3]);
+            x[3] = x[3].rotate_left(r0) ^ x[0];
+            x[2] = x[2].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(r1) ^ x[2];
+        }
+
+        if round % 4 == 3 {
+            inject_key(&mut x, &schedule, (round + 1) / 4);
+        }
+    }
+
+    x
+}
+
+#[inline]
+fn inject_key(x: &mut [u64; 4], schedule: &[u64; 5], injection: usize) {
+    for (i, word) in x.iter_mut().enumerate() {
+        *word = word.wrapping_add(schedule[(injection + i) % schedule.len()]);
+    }
+
+    x[3] = x[3].wrapping_add(injection as u64);
+}
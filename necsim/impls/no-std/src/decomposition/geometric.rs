@@ -0,0 +1,137 @@
+use core::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{
+    cogs::{Backup, Habitat},
+    landscape::Location,
+};
+
+use crate::decomposition::{
+    equal::EqualDecomposition, hilbert::HilbertDecomposition, kd_tree::KdTreeDecomposition,
+    Decomposition,
+};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum GeometricDecompositionMethod {
+    /// Split the Morton curve over the habitat by area or weight
+    Equal,
+    /// Recursively bisect the habitat by area or weight along alternating
+    ///  axes
+    KdTree,
+    /// Split the Hilbert curve over the habitat by area or weight
+    Hilbert,
+}
+
+impl Default for GeometricDecompositionMethod {
+    fn default() -> Self {
+        Self::Equal
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub enum GeometricDecomposition<H: Habitat> {
+    Equal(EqualDecomposition<H>),
+    KdTree(KdTreeDecomposition<H>),
+    Hilbert(HilbertDecomposition<H>),
+}
+
+impl GeometricDecompositionMethod {
+    /// Decomposes the `habitat` by weight, i.e. by its habitat per location,
+    ///  using this method
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(_)` iff the `habitat` can be partitioned into
+    /// `partitions` by this method, otherwise returns `Err(_)`.
+    pub fn weight<H: Habitat>(
+        self,
+        habitat: &H,
+        rank: u32,
+        partitions: NonZeroU32,
+    ) -> Result<GeometricDecomposition<H>, GeometricDecomposition<H>> {
+        match self {
+            Self::Equal => EqualDecomposition::weight(habitat, rank, partitions)
+                .map(GeometricDecomposition::Equal)
+                .map_err(GeometricDecomposition::Equal),
+            Self::KdTree => KdTreeDecomposition::new(habitat, rank, partitions)
+                .map(GeometricDecomposition::KdTree)
+                .map_err(GeometricDecomposition::KdTree),
+            Self::Hilbert => HilbertDecomposition::new(habitat, rank, partitions)
+                .map(GeometricDecomposition::Hilbert)
+                .map_err(GeometricDecomposition::Hilbert),
+        }
+    }
+
+    /// Decomposes the `habitat` by area, i.e. by ignoring its habitat per
+    ///  location, using this method
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(_)` iff the `habitat` can be partitioned into
+    /// `partitions` by this method, otherwise returns `Err(_)`.
+    pub fn area<H: Habitat>(
+        self,
+        habitat: &H,
+        rank: u32,
+        partitions: NonZeroU32,
+    ) -> Result<GeometricDecomposition<H>, GeometricDecomposition<H>> {
+        match self {
+            Self::Equal => EqualDecomposition::area(habitat, rank, partitions)
+                .map(GeometricDecomposition::Equal)
+                .map_err(GeometricDecomposition::Equal),
+            Self::KdTree => KdTreeDecomposition::area(habitat, rank, partitions)
+                .map(GeometricDecomposition::KdTree)
+                .map_err(GeometricDecomposition::KdTree),
+            Self::Hilbert => HilbertDecomposition::area(habitat, rank, partitions)
+                .map(GeometricDecomposition::Hilbert)
+                .map_err(GeometricDecomposition::Hilbert),
+        }
+    }
+}
+
+#[contract_trait]
+impl<H: Habitat> Backup for GeometricDecomposition<H> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        match self {
+            Self::Equal(decomposition) => Self::Equal(decomposition.backup_unchecked()),
+            Self::KdTree(decomposition) => Self::KdTree(decomposition.backup_unchecked()),
+            Self::Hilbert(decomposition) => Self::Hilbert(decomposition.backup_unchecked()),
+        }
+    }
+}
+
+#[contract_trait]
+impl<H: Habitat> Decomposition<H> for GeometricDecomposition<H> {
+    fn get_subdomain_rank(&self) -> u32 {
+        match self {
+            Self::Equal(decomposition) => decomposition.get_subdomain_rank(),
+            Self::KdTree(decomposition) => decomposition.get_subdomain_rank(),
+            Self::Hilbert(decomposition) => decomposition.get_subdomain_rank(),
+        }
+    }
+
+    fn get_number_of_subdomains(&self) -> NonZeroU32 {
+        match self {
+            Self::Equal(decomposition) => decomposition.get_number_of_subdomains(),
+            Self::KdTree(decomposition) => decomposition.get_number_of_subdomains(),
+            Self::Hilbert(decomposition) => decomposition.get_number_of_subdomains(),
+        }
+    }
+
+    fn map_location_to_subdomain_rank(&self, location: &Location, habitat: &H) -> u32 {
+        match self {
+            Self::Equal(decomposition) => {
+                decomposition.map_location_to_subdomain_rank(location, habitat)
+            },
+            Self::KdTree(decomposition) => {
+                decomposition.map_location_to_subdomain_rank(location, habitat)
+            },
+            Self::Hilbert(decomposition) => {
+                decomposition.map_location_to_subdomain_rank(location, habitat)
+            },
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{marker::PhantomData, num::NonZeroU32};
+
+use necsim_core::{
+    cogs::{Backup, Habitat},
+    landscape::{LandscapeExtent, Location},
+};
+
+use crate::decomposition::Decomposition;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct HilbertDecomposition<H: Habitat> {
+    rank: u32,
+    partitions: NonZeroU32,
+
+    extent: LandscapeExtent,
+    order: u32,
+
+    indices: Box<[u64]>,
+
+    _marker: PhantomData<H>,
+}
+
+#[contract_trait]
+impl<H: Habitat> Backup for HilbertDecomposition<H> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            rank: self.rank,
+            partitions: self.partitions,
+            extent: self.extent.clone(),
+            order: self.order,
+            indices: self.indices.clone(),
+            _marker: PhantomData::<H>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<H: Habitat> Decomposition<H> for HilbertDecomposition<H> {
+    fn get_subdomain_rank(&self) -> u32 {
+        self.rank
+    }
+
+    fn get_number_of_subdomains(&self) -> NonZeroU32 {
+        self.partitions
+    }
+
+    #[debug_requires(
+        habitat.get_extent() == &self.extent,
+        "habitat has a matching extent"
+    )]
+    fn map_location_to_subdomain_rank(&self, location: &Location, habitat: &H) -> u32 {
+        let hilbert_index = Self::map_x_y_to_hilbert(
+            self.order,
+            location.x() - self.extent.x(),
+            location.y() - self.extent.y(),
+        );
+
+        #[allow(clippy::cast_possible_truncation)]
+        match self.indices.binary_search(&hilbert_index) {
+            Ok(index) => (index + 1) as u32,
+            Err(index) => index as u32,
+        }
+    }
+}
+
+impl<H: Habitat> HilbertDecomposition<H> {
+    /// Orders all cells along a Hilbert curve which covers the habitat and
+    ///  splits the curve into `partitions` segments of (roughly) equal
+    ///  cumulative habitat.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(Self)` iff the `habitat` can be partitioned into
+    /// `partitions` by weight, otherwise returns `Err(Self)`.
+    pub fn new(habitat: &H, rank: u32, partitions: NonZeroU32) -> Result<Self, Self> {
+        Self::with_weights(habitat, rank, partitions, |location| {
+            habitat.get_habitat_at_location(location)
+        })
+    }
+
+    /// Orders all cells along a Hilbert curve which covers the habitat and
+    ///  splits the curve into `partitions` segments of (roughly) equal area,
+    ///  i.e. of equally many locations irrespective of their habitat.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(Self)` iff the `habitat` can be partitioned into
+    /// `partitions` by area, otherwise returns `Err(Self)`.
+    pub fn area(habitat: &H, rank: u32, partitions: NonZeroU32) -> Result<Self, Self> {
+        Self::with_weights(habitat, rank, partitions, |_| 1_u32)
+    }
+
+    fn with_weights<F: Fn(&Location) -> u32>(
+        habitat: &H,
+        rank: u32,
+        partitions: NonZeroU32,
+        weight: F,
+    ) -> Result<Self, Self> {
+        let extent = habitat.get_extent().clone();
+
+        let mut total_habitat = 0;
+        let mut indices = Vec::with_capacity(partitions.get() as usize);
+
+        let side = extent.width().max(extent.height());
+        let order = if side > 1 {
+            u32::BITS - (side - 1).leading_zeros()
+        } else {
+            0
+        };
+
+        for location in extent.iter() {
+            let h = weight(&location);
+
+            if h > 0 {
+                total_habitat += u64::from(h);
+
+                indices.push((
+                    Self::map_x_y_to_hilbert(
+                        order,
+                        location.x() - extent.x(),
+                        location.y() - extent.y(),
+                    ),
+                    h,
+                ));
+            }
+        }
+
+        indices.sort_unstable();
+
+        let mut cumulative_habitat = 0;
+        let mut last_rank = 0;
+
+        let indices: Vec<u64> = indices
+            .into_iter()
+            .filter_map(|(index, habitat)| {
+                #[allow(clippy::cast_possible_truncation)]
+                let next_rank = (u128::from(cumulative_habitat) * u128::from(partitions.get())
+                    / u128::from(total_habitat)) as u32;
+
+                cumulative_habitat += u64::from(habitat);
+
+                if next_rank == last_rank {
+                    None
+                } else {
+                    last_rank = next_rank;
+
+                    Some(index)
+                }
+            })
+            .collect();
+
+        let decomposition = Self {
+            rank,
+            partitions,
+
+            extent,
+            order,
+
+            indices: indices.into_boxed_slice(),
+
+            _marker: PhantomData::<H>,
+        };
+
+        if (decomposition.indices.len() + 1) == (partitions.get() as usize) {
+            Ok(decomposition)
+        } else {
+            Err(decomposition)
+        }
+    }
+
+    /// Maps the offset (`dx`, `dy`) onto its distance along the Hilbert curve
+    ///  which fills the `2^order x 2^order` square
+    fn map_x_y_to_hilbert(order: u32, dx: u32, dy: u32) -> u64 {
+        let side = 1_u64 << order;
+
+        let mut x = u64::from(dx);
+        let mut y = u64::from(dy);
+
+        let mut hilbert_index = 0_u64;
+        let mut s = side >> 1;
+
+        while s > 0 {
+            let rx = u64::from((x & s) > 0);
+            let ry = u64::from((y & s) > 0);
+
+            hilbert_index += s * s * ((3 * rx) ^ ry);
+
+            // Rotate the quadrant such that the curve is continuous
+            if ry == 0 {
+                if rx == 1 {
+                    x = side - 1 - x;
+                    y = side - 1 - y;
+                }
+
+                core::mem::swap(&mut x, &mut y);
+            }
+
+            s >>= 1;
+        }
+
+        hilbert_index
+    }
+}
@@ -0,0 +1,335 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{marker::PhantomData, num::NonZeroU32};
+
+use necsim_core::{
+    cogs::{Backup, Habitat},
+    landscape::{LandscapeExtent, Location},
+};
+
+use crate::decomposition::Decomposition;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    X,
+    Y,
+}
+
+impl Axis {
+    fn flip(self) -> Self {
+        match self {
+            Self::X => Self::Y,
+            Self::Y => Self::X,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum KdNode {
+    Leaf {
+        rank: u32,
+    },
+    Split {
+        axis: Axis,
+        at: u32,
+        lower: usize,
+        upper: usize,
+    },
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct KdTreeDecomposition<H: Habitat> {
+    rank: u32,
+    partitions: NonZeroU32,
+
+    extent: LandscapeExtent,
+
+    nodes: Box<[KdNode]>,
+
+    _marker: PhantomData<H>,
+}
+
+#[contract_trait]
+impl<H: Habitat> Backup for KdTreeDecomposition<H> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            rank: self.rank,
+            partitions: self.partitions,
+            extent: self.extent.clone(),
+            nodes: self.nodes.clone(),
+            _marker: PhantomData::<H>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<H: Habitat> Decomposition<H> for KdTreeDecomposition<H> {
+    fn get_subdomain_rank(&self) -> u32 {
+        self.rank
+    }
+
+    fn get_number_of_subdomains(&self) -> NonZeroU32 {
+        self.partitions
+    }
+
+    #[debug_requires(
+        habitat.get_extent() == &self.extent,
+        "habitat has a matching extent"
+    )]
+    fn map_location_to_subdomain_rank(&self, location: &Location, habitat: &H) -> u32 {
+        let mut index = 0;
+
+        loop {
+            match &self.nodes[index] {
+                KdNode::Leaf { rank } => return *rank,
+                KdNode::Split {
+                    axis,
+                    at,
+                    lower,
+                    upper,
+                } => {
+                    let coordinate = match axis {
+                        Axis::X => location.x(),
+                        Axis::Y => location.y(),
+                    };
+
+                    index = if coordinate < *at { *lower } else { *upper };
+                },
+            }
+        }
+    }
+}
+
+impl<H: Habitat> KdTreeDecomposition<H> {
+    /// Recursively bisects the habitat along alternating axes such that
+    ///  each half receives a share of the habitat that is proportional to
+    ///  the number of partitions assigned to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(Self)` iff the `habitat` can be partitioned into
+    /// `partitions` non-empty subdomains, otherwise returns `Err(Self)`.
+    pub fn new(habitat: &H, rank: u32, partitions: NonZeroU32) -> Result<Self, Self> {
+        let weights = PrefixSums::new(habitat.get_extent(), |location| {
+            u64::from(habitat.get_habitat_at_location(location))
+        });
+
+        Self::with_weights(habitat, rank, partitions, &weights)
+    }
+
+    /// Recursively bisects the habitat along alternating axes such that
+    ///  each half receives a share of the area, i.e. of the locations
+    ///  irrespective of their habitat, that is proportional to the number of
+    ///  partitions assigned to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(Self)` iff the `habitat` can be partitioned into
+    /// `partitions` non-empty subdomains, otherwise returns `Err(Self)`.
+    pub fn area(habitat: &H, rank: u32, partitions: NonZeroU32) -> Result<Self, Self> {
+        let weights = PrefixSums::new(habitat.get_extent(), |_| 1_u64);
+
+        Self::with_weights(habitat, rank, partitions, &weights)
+    }
+
+    fn with_weights(
+        habitat: &H,
+        rank: u32,
+        partitions: NonZeroU32,
+        weights: &PrefixSums,
+    ) -> Result<Self, Self> {
+        let extent = habitat.get_extent().clone();
+
+        let mut nodes = Vec::with_capacity(partitions.get() as usize * 2 - 1);
+
+        let axis = if extent.width() >= extent.height() {
+            Axis::X
+        } else {
+            Axis::Y
+        };
+
+        let successful = Self::bisect(weights, &extent, (0, partitions.get()), axis, &mut nodes);
+
+        let decomposition = Self {
+            rank,
+            partitions,
+
+            extent,
+
+            nodes: nodes.into_boxed_slice(),
+
+            _marker: PhantomData::<H>,
+        };
+
+        if successful {
+            Ok(decomposition)
+        } else {
+            Err(decomposition)
+        }
+    }
+
+    /// Appends the subtree for the `ranks` (first, count) in `region` to
+    ///  `nodes` and returns whether all its subdomains contain any weight
+    fn bisect(
+        weights: &PrefixSums,
+        region: &LandscapeExtent,
+        ranks: (u32, u32),
+        axis: Axis,
+        nodes: &mut Vec<KdNode>,
+    ) -> bool {
+        let (first_rank, num_ranks) = ranks;
+
+        let axis = match axis {
+            Axis::X if region.width() <= 1 => Axis::Y,
+            Axis::Y if region.height() <= 1 => Axis::X,
+            axis => axis,
+        };
+
+        let (origin, length) = match axis {
+            Axis::X => (region.x(), region.width()),
+            Axis::Y => (region.y(), region.height()),
+        };
+
+        let total_weight = weights.sum(region);
+
+        // The region cannot be split any further
+        if num_ranks == 1 || length <= 1 {
+            nodes.push(KdNode::Leaf { rank: first_rank });
+
+            return num_ranks == 1 && total_weight > 0;
+        }
+
+        let lower_ranks = num_ranks / 2;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let target_weight =
+            (u128::from(total_weight) * u128::from(lower_ranks) / u128::from(num_ranks)) as u64;
+
+        // Pick the split line (in [1, length)) whose lower cumulative weight
+        //  is closest to the target weight
+        let mut split = 1;
+        let mut best_distance = u64::MAX;
+
+        for line in 1..length {
+            let (lower_region, _) = Self::split_region(region, axis, line);
+            let cumulative_weight = weights.sum(&lower_region);
+
+            let distance =
+                cumulative_weight.max(target_weight) - cumulative_weight.min(target_weight);
+
+            if distance < best_distance {
+                split = line;
+                best_distance = distance;
+            }
+        }
+
+        let (lower_region, upper_region) = Self::split_region(region, axis, split);
+
+        let index = nodes.len();
+        nodes.push(KdNode::Leaf { rank: first_rank });
+
+        let lower = nodes.len();
+        let lower_successful = Self::bisect(
+            weights,
+            &lower_region,
+            (first_rank, lower_ranks),
+            axis.flip(),
+            nodes,
+        );
+
+        let upper = nodes.len();
+        let upper_successful = Self::bisect(
+            weights,
+            &upper_region,
+            (first_rank + lower_ranks, num_ranks - lower_ranks),
+            axis.flip(),
+            nodes,
+        );
+
+        nodes[index] = KdNode::Split {
+            axis,
+            at: origin + split,
+            lower,
+            upper,
+        };
+
+        lower_successful && upper_successful
+    }
+
+    /// Splits the `region` into the lower part before the `split` line
+    ///  along the `axis` and the upper part from it onwards
+    fn split_region(
+        region: &LandscapeExtent,
+        axis: Axis,
+        split: u32,
+    ) -> (LandscapeExtent, LandscapeExtent) {
+        match axis {
+            Axis::X => (
+                LandscapeExtent::new(region.x(), region.y(), split, region.height()),
+                LandscapeExtent::new(
+                    region.x() + split,
+                    region.y(),
+                    region.width() - split,
+                    region.height(),
+                ),
+            ),
+            Axis::Y => (
+                LandscapeExtent::new(region.x(), region.y(), region.width(), split),
+                LandscapeExtent::new(
+                    region.x(),
+                    region.y() + split,
+                    region.width(),
+                    region.height() - split,
+                ),
+            ),
+        }
+    }
+}
+
+/// Summed-area table of the weights of all locations inside an extent, which
+///  is built once and then sums up the weight of any rectangular region in
+///  constant time
+struct PrefixSums {
+    x: u32,
+    y: u32,
+    stride: usize,
+    sums: Vec<u64>,
+}
+
+impl PrefixSums {
+    fn new<F: Fn(&Location) -> u64>(extent: &LandscapeExtent, weight: F) -> Self {
+        let stride = extent.width() as usize + 1;
+
+        let mut sums = vec![0_u64; stride * (extent.height() as usize + 1)];
+
+        for dy in 0..extent.height() {
+            let mut row_sum = 0_u64;
+
+            for dx in 0..extent.width() {
+                row_sum += weight(&Location::new(extent.x() + dx, extent.y() + dy));
+
+                let index = (dy as usize + 1) * stride + (dx as usize + 1);
+
+                sums[index] = sums[index - stride] + row_sum;
+            }
+        }
+
+        Self {
+            x: extent.x(),
+            y: extent.y(),
+            stride,
+            sums,
+        }
+    }
+
+    fn sum(&self, region: &LandscapeExtent) -> u64 {
+        let left = (region.x() - self.x) as usize;
+        let top = (region.y() - self.y) as usize;
+        let right = left + region.width() as usize;
+        let bottom = top + region.height() as usize;
+
+        self.sums[bottom * self.stride + right] + self.sums[top * self.stride + left]
+            - self.sums[top * self.stride + right]
+            - self.sums[bottom * self.stride + left]
+    }
+}
@@ -0,0 +1,532 @@
+use alloc::{boxed::Box, collections::BinaryHeap, vec, vec::Vec};
+use core::{cmp::Reverse, marker::PhantomData, num::NonZeroU32};
+
+use array2d::Array2D;
+
+use necsim_core::{
+    cogs::{Backup, Habitat, TurnoverRate},
+    landscape::{LandscapeExtent, Location},
+};
+use necsim_core_bond::NonNegativeF64;
+
+use crate::decomposition::Decomposition;
+
+/// Maximum relative excess weight of a subdomain over the average weight
+const MAX_IMBALANCE: f64 = 0.05;
+/// Coarsening stops once the graph has at most this many nodes per subdomain
+const COARSEST_NODES_PER_SUBDOMAIN: usize = 16;
+/// Maximum number of refinement passes per coarsening level
+const MAX_REFINEMENT_PASSES: usize = 8;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct MinCutDecomposition<H: Habitat> {
+    rank: u32,
+    partitions: NonZeroU32,
+
+    extent: LandscapeExtent,
+
+    subdomains: Box<[u32]>,
+
+    _marker: PhantomData<H>,
+}
+
+#[contract_trait]
+impl<H: Habitat> Backup for MinCutDecomposition<H> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        Self {
+            rank: self.rank,
+            partitions: self.partitions,
+            extent: self.extent.clone(),
+            subdomains: self.subdomains.clone(),
+            _marker: PhantomData::<H>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<H: Habitat> Decomposition<H> for MinCutDecomposition<H> {
+    fn get_subdomain_rank(&self) -> u32 {
+        self.rank
+    }
+
+    fn get_number_of_subdomains(&self) -> NonZeroU32 {
+        self.partitions
+    }
+
+    #[debug_requires(
+        habitat.get_extent() == &self.extent,
+        "habitat has a matching extent"
+    )]
+    fn map_location_to_subdomain_rank(&self, location: &Location, habitat: &H) -> u32 {
+        let dx = (location.x() - self.extent.x()) as usize;
+        let dy = (location.y() - self.extent.y()) as usize;
+
+        self.subdomains[dy * (self.extent.width() as usize) + dx]
+    }
+}
+
+impl<H: Habitat> MinCutDecomposition<H> {
+    /// Partitions the graph whose nodes are the habitat cells, weighted by
+    ///  their habitat and turnover rate, and whose edges are the expected
+    ///  dispersal between them, such that the subdomains have roughly equal
+    ///  weight and the expected dispersal mass between them is minimised.
+    ///
+    /// The `dispersal` map must use the same row-major layout as the
+    ///  in-memory dispersal samplers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(Self)` iff the `habitat` can be partitioned into
+    /// `partitions` non-empty subdomains, otherwise returns `Err(Self)`.
+    #[debug_requires(
+        dispersal.num_rows() == dispersal.num_columns(),
+        "dispersal map is square"
+    )]
+    #[debug_requires(
+        dispersal.num_rows() == (
+            (habitat.get_extent().width() as usize) * (habitat.get_extent().height() as usize)
+        ),
+        "dispersal map has one row per habitat cell"
+    )]
+    pub fn new<T: TurnoverRate<H>>(
+        habitat: &H,
+        dispersal: &Array2D<f64>,
+        turnover_rate: &T,
+        rank: u32,
+        partitions: NonZeroU32,
+    ) -> Result<Self, Self> {
+        let extent = habitat.get_extent().clone();
+
+        let (graph, cells) = DispersalGraph::new(habitat, dispersal, turnover_rate);
+
+        let node_subdomains = graph.partition(partitions.get() as usize);
+
+        let mut subdomains = vec![0_u32; (extent.width() as usize) * (extent.height() as usize)];
+        let mut subdomain_sizes = vec![0_usize; partitions.get() as usize];
+
+        for (cell, subdomain) in cells.into_iter().zip(node_subdomains) {
+            subdomains[cell] = subdomain;
+            subdomain_sizes[subdomain as usize] += 1;
+        }
+
+        let decomposition = Self {
+            rank,
+            partitions,
+
+            extent,
+
+            subdomains: subdomains.into_boxed_slice(),
+
+            _marker: PhantomData::<H>,
+        };
+
+        if subdomain_sizes.iter().all(|size| *size > 0) {
+            Ok(decomposition)
+        } else {
+            Err(decomposition)
+        }
+    }
+}
+
+struct DispersalGraph {
+    weights: Vec<f64>,
+    edges: Vec<Vec<(usize, f64)>>,
+}
+
+impl DispersalGraph {
+    /// Returns the graph over all habitat cells and the row-major cell index
+    ///  of each node
+    fn new<H: Habitat, T: TurnoverRate<H>>(
+        habitat: &H,
+        dispersal: &Array2D<f64>,
+        turnover_rate: &T,
+    ) -> (Self, Vec<usize>) {
+        let extent = habitat.get_extent();
+
+        let mut cells = Vec::new();
+        let mut cell_habitat = Vec::with_capacity(dispersal.num_rows());
+        let mut nodes = Vec::with_capacity(dispersal.num_rows());
+        let mut weights = Vec::new();
+
+        for (cell, location) in extent.iter().enumerate() {
+            let h = habitat.get_habitat_at_location(&location);
+
+            cell_habitat.push(f64::from(h));
+
+            if h > 0 {
+                nodes.push(Some(cells.len()));
+                cells.push(cell);
+                weights.push(
+                    f64::from(h)
+                        * turnover_rate
+                            .get_turnover_rate_at_location(&location, habitat)
+                            .get(),
+                );
+            } else {
+                nodes.push(None);
+            }
+        }
+
+        let mut edges = vec![Vec::new(); cells.len()];
+
+        for (node, cell) in cells.iter().enumerate() {
+            let row = || {
+                dispersal
+                    .row_iter(*cell)
+                    .into_iter()
+                    .flatten()
+                    .zip(cell_habitat.iter())
+                    .map(|(dispersal, habitat)| dispersal * habitat)
+                    .enumerate()
+            };
+
+            let total_dispersal: f64 = row().map(|(_, dispersal)| dispersal).sum();
+
+            if total_dispersal <= 0.0_f64 {
+                continue;
+            }
+
+            // Expected rate of dispersal from this node to each other node
+            for (target_cell, dispersal) in row() {
+                if let Some(target) = nodes[target_cell] {
+                    if target != node && dispersal > 0.0_f64 {
+                        let mass = weights[node] * dispersal / total_dispersal;
+
+                        edges[node].push((target, mass));
+                        edges[target].push((node, mass));
+                    }
+                }
+            }
+        }
+
+        // Merge the dispersal in both directions into one undirected edge
+        for node_edges in &mut edges {
+            node_edges.sort_unstable_by_key(|(target, _)| *target);
+            node_edges.dedup_by(|(target, mass), (previous_target, previous_mass)| {
+                if target == previous_target {
+                    *previous_mass += *mass;
+                    true
+                } else {
+                    false
+                }
+            });
+        }
+
+        (Self { weights, edges }, cells)
+    }
+
+    fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Multilevel partitioning: the graph is coarsened by heavy-edge
+    ///  matching, the coarsest graph is partitioned by greedy graph growing,
+    ///  and the partition is refined while it is projected back
+    fn partition(self, partitions: usize) -> Vec<u32> {
+        let total_weight: f64 = self.weights.iter().sum();
+
+        #[allow(clippy::cast_precision_loss)]
+        let max_subdomain_weight = total_weight / (partitions as f64) * (1.0_f64 + MAX_IMBALANCE);
+        #[allow(clippy::cast_precision_loss)]
+        let max_node_weight =
+            total_weight * 2.0_f64 / ((partitions * COARSEST_NODES_PER_SUBDOMAIN) as f64);
+
+        let mut levels = Vec::new();
+        let mut graph = self;
+
+        while graph.len() > partitions * COARSEST_NODES_PER_SUBDOMAIN {
+            // A graph without edges, e.g. with self-only dispersal, cannot be coarsened
+            if graph.edges.iter().all(Vec::is_empty) {
+                break;
+            }
+
+            let (coarse_graph, coarse_nodes) = graph.coarsen(max_node_weight);
+
+            // Stop once coarsening stagnates
+            if coarse_graph.len() * 20 > graph.len() * 19 {
+                break;
+            }
+
+            levels.push((graph, coarse_nodes));
+            graph = coarse_graph;
+        }
+
+        let mut subdomains = graph.grow(partitions);
+        graph.refine(&mut subdomains, partitions, max_subdomain_weight);
+
+        while let Some((fine_graph, coarse_nodes)) = levels.pop() {
+            subdomains = coarse_nodes
+                .into_iter()
+                .map(|coarse_node| subdomains[coarse_node])
+                .collect();
+
+            fine_graph.refine(&mut subdomains, partitions, max_subdomain_weight);
+        }
+
+        subdomains
+    }
+
+    /// Merges every node with its unmatched neighbour along the heaviest edge
+    ///  and returns the coarse graph and the coarse node of every node
+    fn coarsen(&self, max_node_weight: f64) -> (Self, Vec<usize>) {
+        let mut coarse_nodes = vec![usize::MAX; self.len()];
+        let mut members: Vec<Vec<usize>> = Vec::new();
+
+        for node in 0..self.len() {
+            if coarse_nodes[node] != usize::MAX {
+                continue;
+            }
+
+            let mut matching: Option<(usize, f64)> = None;
+
+            for (neighbour, mass) in &self.edges[node] {
+                if coarse_nodes[*neighbour] == usize::MAX
+                    && (self.weights[node] + self.weights[*neighbour]) <= max_node_weight
+                    && matching.map_or(true, |(_, matching_mass)| *mass > matching_mass)
+                {
+                    matching = Some((*neighbour, *mass));
+                }
+            }
+
+            coarse_nodes[node] = members.len();
+
+            match matching {
+                Some((neighbour, _)) => {
+                    coarse_nodes[neighbour] = members.len();
+                    members.push(vec![node, neighbour]);
+                },
+                None => members.push(vec![node]),
+            }
+        }
+
+        let mut weights = Vec::with_capacity(members.len());
+        let mut edges = Vec::with_capacity(members.len());
+
+        let mut coarse_masses = vec![0.0_f64; members.len()];
+        let mut coarse_neighbours = Vec::new();
+        let mut is_neighbour = vec![false; members.len()];
+
+        for (coarse_node, nodes) in members.iter().enumerate() {
+            weights.push(nodes.iter().map(|node| self.weights[*node]).sum());
+
+            for node in nodes {
+                for (neighbour, mass) in &self.edges[*node] {
+                    let coarse_neighbour = coarse_nodes[*neighbour];
+
+                    if coarse_neighbour != coarse_node {
+                        if !is_neighbour[coarse_neighbour] {
+                            is_neighbour[coarse_neighbour] = true;
+                            coarse_neighbours.push(coarse_neighbour);
+                        }
+
+                        coarse_masses[coarse_neighbour] += mass;
+                    }
+                }
+            }
+
+            coarse_neighbours.sort_unstable();
+
+            edges.push(
+                coarse_neighbours
+                    .drain(..)
+                    .map(|coarse_neighbour| {
+                        is_neighbour[coarse_neighbour] = false;
+
+                        (
+                            coarse_neighbour,
+                            core::mem::replace(&mut coarse_masses[coarse_neighbour], 0.0_f64),
+                        )
+                    })
+                    .collect(),
+            );
+        }
+
+        (Self { weights, edges }, coarse_nodes)
+    }
+
+    /// Grows the subdomains one after the other from the lowest unassigned
+    ///  node, always adding the node that is most strongly connected to the
+    ///  subdomain, until the subdomain has its share of the remaining weight
+    ///
+    /// The candidate nodes are kept in a max-heap keyed on their connection
+    ///  to the subdomain, with ties broken towards the lowest node. Entries
+    ///  are invalidated lazily, i.e. skipped when they are popped after their
+    ///  node has been assigned or their connection has grown since.
+    fn grow(&self, partitions: usize) -> Vec<u32> {
+        let mut subdomains = vec![u32::MAX; self.len()];
+        let mut connections = vec![0.0_f64; self.len()];
+        let mut candidates: BinaryHeap<(NonNegativeF64, Reverse<usize>)> = BinaryHeap::new();
+
+        // All nodes below the lowest unassigned node have been assigned
+        let mut lowest_unassigned = 0_usize;
+
+        let mut remaining_weight: f64 = self.weights.iter().sum();
+        let mut remaining_nodes = self.len();
+
+        #[allow(clippy::cast_possible_truncation)]
+        for subdomain in 0..(partitions as u32) {
+            let remaining_subdomains = partitions - (subdomain as usize);
+
+            #[allow(clippy::cast_precision_loss)]
+            let target_weight = remaining_weight / (remaining_subdomains as f64);
+            let mut subdomain_weight = 0.0_f64;
+
+            // Every node with a non-zero connection has an entry in the heap
+            for (_, Reverse(node)) in candidates.drain() {
+                connections[node] = 0.0_f64;
+            }
+
+            // Leave at least one node for each of the remaining subdomains
+            let reserved_nodes = if self.len() >= partitions {
+                remaining_subdomains - 1
+            } else {
+                0
+            };
+
+            while remaining_nodes > reserved_nodes {
+                let mut next: Option<usize> = None;
+
+                while let Some((connection, Reverse(node))) = candidates.peek() {
+                    // Connections only grow, so stale entries have a lower connection
+                    if subdomains[*node] == u32::MAX && connection.get() >= connections[*node] {
+                        next = Some(*node);
+                        break;
+                    }
+
+                    candidates.pop();
+                }
+
+                // Without any connected candidates, the lowest unassigned node
+                //  is grown next, just as when all connections are zero
+                let next = if let Some(next) = next {
+                    next
+                } else {
+                    while lowest_unassigned < self.len()
+                        && subdomains[lowest_unassigned] != u32::MAX
+                    {
+                        lowest_unassigned += 1;
+                    }
+
+                    if lowest_unassigned == self.len() {
+                        break;
+                    }
+
+                    lowest_unassigned
+                };
+
+                // The last subdomain takes all remaining nodes
+                if remaining_subdomains > 1
+                    && subdomain_weight > 0.0_f64
+                    && (subdomain_weight + self.weights[next] - target_weight)
+                        > (target_weight - subdomain_weight)
+                {
+                    break;
+                }
+
+                subdomains[next] = subdomain;
+                subdomain_weight += self.weights[next];
+                remaining_weight -= self.weights[next];
+                remaining_nodes -= 1;
+
+                for (neighbour, mass) in &self.edges[next] {
+                    if subdomains[*neighbour] == u32::MAX {
+                        connections[*neighbour] += mass;
+
+                        // Safety: The connection is a sum of positive dispersal masses
+                        candidates.push((
+                            unsafe { NonNegativeF64::new_unchecked(connections[*neighbour]) },
+                            Reverse(*neighbour),
+                        ));
+                    }
+                }
+
+                if remaining_subdomains > 1 && subdomain_weight >= target_weight {
+                    break;
+                }
+            }
+        }
+
+        subdomains
+    }
+
+    /// Greedily moves boundary nodes to the neighbouring subdomain which
+    ///  reduces the cut the most without exceeding the maximum weight, or
+    ///  away from subdomains which already exceed it
+    fn refine(&self, subdomains: &mut [u32], partitions: usize, max_subdomain_weight: f64) {
+        let mut subdomain_weights = vec![0.0_f64; partitions];
+        let mut subdomain_sizes = vec![0_usize; partitions];
+
+        for (node, subdomain) in subdomains.iter().enumerate() {
+            subdomain_weights[*subdomain as usize] += self.weights[node];
+            subdomain_sizes[*subdomain as usize] += 1;
+        }
+
+        let mut connections = vec![0.0_f64; partitions];
+        let mut neighbour_subdomains = Vec::new();
+        let mut is_neighbour = vec![false; partitions];
+
+        for _ in 0..MAX_REFINEMENT_PASSES {
+            let mut has_moved = false;
+
+            for node in 0..self.len() {
+                let subdomain = subdomains[node] as usize;
+
+                // Subdomains must not become empty
+                if subdomain_sizes[subdomain] <= 1 {
+                    continue;
+                }
+
+                for (neighbour, mass) in &self.edges[node] {
+                    let neighbour_subdomain = subdomains[*neighbour] as usize;
+
+                    if !is_neighbour[neighbour_subdomain] {
+                        is_neighbour[neighbour_subdomain] = true;
+                        neighbour_subdomains.push(neighbour_subdomain);
+                    }
+
+                    connections[neighbour_subdomain] += mass;
+                }
+
+                let is_overweight = subdomain_weights[subdomain] > max_subdomain_weight;
+                let mut best_move: Option<(usize, f64)> = None;
+
+                for neighbour_subdomain in &neighbour_subdomains {
+                    let gain = connections[*neighbour_subdomain] - connections[subdomain];
+
+                    if *neighbour_subdomain != subdomain
+                        && (gain > 0.0_f64 || is_overweight)
+                        && (subdomain_weights[*neighbour_subdomain] + self.weights[node])
+                            <= max_subdomain_weight
+                        && best_move.map_or(true, |(_, best_gain)| gain > best_gain)
+                    {
+                        best_move = Some((*neighbour_subdomain, gain));
+                    }
+                }
+
+                for neighbour_subdomain in neighbour_subdomains.drain(..) {
+                    is_neighbour[neighbour_subdomain] = false;
+                    connections[neighbour_subdomain] = 0.0_f64;
+                }
+
+                if let Some((target, _)) = best_move {
+                    subdomain_weights[subdomain] -= self.weights[node];
+                    subdomain_sizes[subdomain] -= 1;
+                    subdomain_weights[target] += self.weights[node];
+                    subdomain_sizes[target] += 1;
+
+                    #[allow(clippy::cast_possible_truncation)]
+                    {
+                        subdomains[node] = target as u32;
+                    }
+
+                    has_moved = true;
+                }
+            }
+
+            if !has_moved {
+                break;
+            }
+        }
+    }
+}
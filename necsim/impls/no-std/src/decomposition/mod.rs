@@ -6,10 +6,17 @@ use necsim_core::{
 };
 
 pub mod equal;
+pub mod geometric;
+pub mod hilbert;
+pub mod kd_tree;
+pub mod min_cut;
 pub mod modulo;
 pub mod monolithic;
 pub mod radial;
 
+#[cfg(test)]
+mod test;
+
 #[allow(clippy::inline_always, clippy::inline_fn_without_body)]
 #[contract_trait]
 pub trait Decomposition<H: Habitat>: Backup + Sized + core::fmt::Debug {
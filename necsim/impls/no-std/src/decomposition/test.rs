@@ -0,0 +1,421 @@
+use core::{convert::TryFrom, num::NonZeroU32};
+
+use array2d::Array2D;
+use hashbrown::HashMap;
+
+use necsim_core::{cogs::Habitat, landscape::Location};
+
+use crate::{
+    cogs::{
+        habitat::{in_memory::InMemoryHabitat, non_spatial::NonSpatialHabitat},
+        turnover_rate::uniform::UniformTurnoverRate,
+    },
+    decomposition::{
+        hilbert::HilbertDecomposition, kd_tree::KdTreeDecomposition, min_cut::MinCutDecomposition,
+        Decomposition,
+    },
+};
+
+#[test]
+fn test_kd_tree_decomposition() {
+    // rank => (frequency, min x, min y, max x, max y)
+    let mut indices: HashMap<u32, (u32, u32, u32, u32, u32)> = HashMap::with_capacity(64);
+
+    for width in 1..=8 {
+        for height in 1..=8 {
+            let habitat = NonSpatialHabitat::new((width, height), 1);
+
+            for partition in 1..=(width * height + 1) {
+                let (successful, decomposition) = match KdTreeDecomposition::new(
+                    &habitat,
+                    0,
+                    NonZeroU32::new(partition).unwrap(),
+                ) {
+                    Ok(decomposition) => (true, decomposition),
+                    Err(decomposition) => (false, decomposition),
+                };
+
+                indices.clear();
+
+                for location in habitat.get_extent().iter() {
+                    let index = decomposition.map_location_to_subdomain_rank(&location, &habitat);
+
+                    let entry = indices.entry(index).or_insert((
+                        0,
+                        location.x(),
+                        location.y(),
+                        location.x(),
+                        location.y(),
+                    ));
+
+                    entry.0 += 1;
+                    entry.1 = entry.1.min(location.x());
+                    entry.2 = entry.2.min(location.y());
+                    entry.3 = entry.3.max(location.x());
+                    entry.4 = entry.4.max(location.y());
+                }
+
+                let assert_message = alloc::format!(
+                    "{}x{} / {} => {:?} => {}@{:?}",
+                    width,
+                    height,
+                    partition,
+                    decomposition,
+                    indices.len(),
+                    indices,
+                );
+
+                let num_indices = u32::try_from(indices.len()).expect(&assert_message);
+
+                // Check that every partition is used iff the decomposition succeeded
+                if successful {
+                    assert_eq!(num_indices, partition, "{}", &assert_message);
+                } else {
+                    assert!(num_indices > 0, "{}", assert_message);
+                    assert!(num_indices < partition, "{}", assert_message);
+                }
+
+                // Check that all indices are in range [0, partition)
+                for i in indices.keys() {
+                    assert!(*i < partition, "{}", assert_message);
+                }
+
+                // Check that every subdomain is a filled rectangle
+                for (frequency, min_x, min_y, max_x, max_y) in indices.values() {
+                    assert_eq!(
+                        *frequency,
+                        (max_x - min_x + 1) * (max_y - min_y + 1),
+                        "{}",
+                        assert_message
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hilbert_decomposition() {
+    let mut indices: HashMap<u32, usize> = HashMap::with_capacity(64);
+
+    for width in 1..=8 {
+        for height in 1..=8 {
+            let habitat = NonSpatialHabitat::new((width, height), 1);
+
+            for partition in 1..=(width * height + 1) {
+                let (successful, decomposition) = match HilbertDecomposition::new(
+                    &habitat,
+                    0,
+                    NonZeroU32::new(partition).unwrap(),
+                ) {
+                    Ok(decomposition) => (true, decomposition),
+                    Err(decomposition) => (false, decomposition),
+                };
+
+                indices.clear();
+
+                for location in habitat.get_extent().iter() {
+                    let index = decomposition.map_location_to_subdomain_rank(&location, &habitat);
+
+                    *indices.entry(index).or_insert(0) += 1;
+                }
+
+                let assert_message = alloc::format!(
+                    "{}x{} / {} => {:?} => {}@{:?}",
+                    width,
+                    height,
+                    partition,
+                    decomposition,
+                    indices.len(),
+                    indices,
+                );
+
+                let num_indices = u32::try_from(indices.len()).expect(&assert_message);
+
+                // Check that the number of generated indices is less than
+                //  (unsuccessful) or equal (successful) to the partition
+                if successful {
+                    assert_eq!(num_indices, partition, "{}", &assert_message);
+                } else {
+                    assert!(num_indices < partition, "{}", assert_message);
+                    assert!(
+                        u64::from(num_indices) == (u64::from(width) * u64::from(height)),
+                        "{}",
+                        assert_message
+                    );
+                }
+
+                // Check that all indices in [0, num_indices) have been assigned
+                for i in 0..num_indices {
+                    assert!(indices.contains_key(&i), "{}", assert_message);
+                }
+
+                let min_index_frequency = indices.iter().map(|(_, freq)| freq).min().unwrap();
+                let max_index_frequency = indices.iter().map(|(_, freq)| freq).max().unwrap();
+
+                // Check that the indices are distributed equally
+                assert!(
+                    (max_index_frequency - min_index_frequency) <= 1,
+                    "{}",
+                    assert_message
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_area_decompositions_ignore_habitat() {
+    // The left half of the habitat holds far more habitat than the right
+    let mut habitat = Array2D::filled_with(1, 8, 8);
+    for y in 0..8 {
+        for x in 0..4 {
+            habitat[(y, x)] = 100;
+        }
+    }
+    habitat[(0, 7)] = 0;
+    let habitat = InMemoryHabitat::new(habitat);
+
+    let partitions = NonZeroU32::new(4).unwrap();
+
+    let kd_tree = subdomain_frequencies(
+        &KdTreeDecomposition::area(&habitat, 0, partitions).unwrap(),
+        &habitat,
+    );
+    let hilbert = subdomain_frequencies(
+        &HilbertDecomposition::area(&habitat, 0, partitions).unwrap(),
+        &habitat,
+    );
+
+    for frequencies in &[kd_tree, hilbert] {
+        assert_eq!(frequencies.len(), 4, "{:?}", frequencies);
+        assert!(
+            frequencies.values().all(|frequency| *frequency == 16),
+            "{:?}",
+            frequencies
+        );
+    }
+}
+
+fn subdomain_frequencies<H: Habitat, D: Decomposition<H>>(
+    decomposition: &D,
+    habitat: &H,
+) -> HashMap<u32, usize> {
+    let mut frequencies = HashMap::with_capacity(64);
+
+    for location in habitat.get_extent().iter() {
+        *frequencies
+            .entry(decomposition.map_location_to_subdomain_rank(&location, habitat))
+            .or_insert(0) += 1;
+    }
+
+    frequencies
+}
+
+/// Dispersal map in which each cell disperses uniformly to itself and its
+///  (up to) four direct neighbours
+fn nearest_neighbour_dispersal(width: usize, height: usize) -> Array2D<f64> {
+    let mut dispersal = Array2D::filled_with(0.0_f64, width * height, width * height);
+
+    for y in 0..height {
+        for x in 0..width {
+            let cell = y * width + x;
+
+            dispersal[(cell, cell)] = 1.0_f64;
+
+            if x > 0 {
+                dispersal[(cell, cell - 1)] = 1.0_f64;
+            }
+            if x + 1 < width {
+                dispersal[(cell, cell + 1)] = 1.0_f64;
+            }
+            if y > 0 {
+                dispersal[(cell, cell - width)] = 1.0_f64;
+            }
+            if y + 1 < height {
+                dispersal[(cell, cell + width)] = 1.0_f64;
+            }
+        }
+    }
+
+    dispersal
+}
+
+#[test]
+fn test_min_cut_decomposition() {
+    let mut indices: HashMap<u32, usize> = HashMap::with_capacity(64);
+
+    for width in 1..=6 {
+        for height in 1..=6 {
+            let habitat = InMemoryHabitat::new(Array2D::filled_with(1, height, width));
+            let dispersal = nearest_neighbour_dispersal(width, height);
+
+            #[allow(clippy::cast_possible_truncation)]
+            for partition in 1..=((width * height) as u32 + 1) {
+                let (successful, decomposition) = match MinCutDecomposition::new(
+                    &habitat,
+                    &dispersal,
+                    &UniformTurnoverRate::default(),
+                    0,
+                    NonZeroU32::new(partition).unwrap(),
+                ) {
+                    Ok(decomposition) => (true, decomposition),
+                    Err(decomposition) => (false, decomposition),
+                };
+
+                indices.clear();
+
+                for location in habitat.get_extent().iter() {
+                    let index = decomposition.map_location_to_subdomain_rank(&location, &habitat);
+
+                    *indices.entry(index).or_insert(0) += 1;
+                }
+
+                let assert_message = alloc::format!(
+                    "{}x{} / {} => {:?} => {}@{:?}",
+                    width,
+                    height,
+                    partition,
+                    decomposition,
+                    indices.len(),
+                    indices,
+                );
+
+                let num_indices = u32::try_from(indices.len()).expect(&assert_message);
+
+                // Check that every partition is used iff there are enough cells
+                assert_eq!(
+                    successful,
+                    (partition as usize) <= (width * height),
+                    "{}",
+                    &assert_message
+                );
+
+                if successful {
+                    assert_eq!(num_indices, partition, "{}", &assert_message);
+                }
+
+                // Check that all indices in [0, num_indices) have been assigned
+                for i in 0..num_indices {
+                    assert!(indices.contains_key(&i), "{}", assert_message);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_min_cut_decomposition_separates_clusters() {
+    // Two clusters of habitat which only disperse within themselves
+    let mut dispersal = Array2D::filled_with(0.0_f64, 8, 8);
+
+    for from in 0..8 {
+        for to in 0..8 {
+            if (from < 4) == (to < 4) {
+                dispersal[(from, to)] = 1.0_f64;
+            }
+        }
+    }
+
+    let habitat = InMemoryHabitat::new(Array2D::filled_with(1, 1, 8));
+
+    let decomposition = MinCutDecomposition::new(
+        &habitat,
+        &dispersal,
+        &UniformTurnoverRate::default(),
+        0,
+        NonZeroU32::new(2).unwrap(),
+    )
+    .unwrap();
+
+    for x in 0..8 {
+        assert_eq!(
+            decomposition.map_location_to_subdomain_rank(&Location::new(x, 0), &habitat),
+            decomposition.map_location_to_subdomain_rank(&Location::new(x / 4 * 4, 0), &habitat),
+        );
+    }
+
+    assert_ne!(
+        decomposition.map_location_to_subdomain_rank(&Location::new(0, 0), &habitat),
+        decomposition.map_location_to_subdomain_rank(&Location::new(7, 0), &habitat),
+    );
+}
+
+#[test]
+fn test_min_cut_decomposition_balances_large_grid() {
+    let habitat = InMemoryHabitat::new(Array2D::filled_with(1, 32, 32));
+    let dispersal = nearest_neighbour_dispersal(32, 32);
+
+    let decomposition = MinCutDecomposition::new(
+        &habitat,
+        &dispersal,
+        &UniformTurnoverRate::default(),
+        0,
+        NonZeroU32::new(4).unwrap(),
+    )
+    .unwrap();
+
+    let mut sizes = [0_usize; 4];
+    let mut cut = 0_usize;
+
+    for location in habitat.get_extent().iter() {
+        let rank = decomposition.map_location_to_subdomain_rank(&location, &habitat);
+
+        sizes[rank as usize] += 1;
+
+        if location.x() + 1 < 32
+            && decomposition.map_location_to_subdomain_rank(
+                &Location::new(location.x() + 1, location.y()),
+                &habitat,
+            ) != rank
+        {
+            cut += 1;
+        }
+
+        if location.y() + 1 < 32
+            && decomposition.map_location_to_subdomain_rank(
+                &Location::new(location.x(), location.y() + 1),
+                &habitat,
+            ) != rank
+        {
+            cut += 1;
+        }
+    }
+
+    // Check that the subdomains are balanced
+    for size in &sizes {
+        assert!(*size * 100 <= 256 * 105, "{:?}", sizes);
+    }
+
+    // Check that the boundaries are no longer than those between four stripes
+    assert!(cut <= 3 * 32, "{}", cut);
+}
+
+#[test]
+fn test_min_cut_decomposition_without_dispersal_between_cells() {
+    // Self-only dispersal leaves the graph without any edges to coarsen along
+    let mut dispersal = Array2D::filled_with(0.0_f64, 32 * 32, 32 * 32);
+
+    for cell in 0..(32 * 32) {
+        dispersal[(cell, cell)] = 1.0_f64;
+    }
+
+    let habitat = InMemoryHabitat::new(Array2D::filled_with(1, 32, 32));
+
+    let decomposition = MinCutDecomposition::new(
+        &habitat,
+        &dispersal,
+        &UniformTurnoverRate::default(),
+        0,
+        NonZeroU32::new(4).unwrap(),
+    )
+    .unwrap();
+
+    let mut sizes = [0_usize; 4];
+
+    for location in habitat.get_extent().iter() {
+        sizes[decomposition.map_location_to_subdomain_rank(&location, &habitat) as usize] += 1;
+    }
+
+    assert_eq!(sizes, [256; 4]);
+}
@@ -3,13 +3,10 @@ use core::num::{NonZeroU64, Wrapping};
 use necsim_core_bond::NonNegativeF64;
 
 use necsim_core::{
-    cogs::{
-        ActiveLineageSampler, DispersalSampler, Habitat, MinSpeciationTrackingEventSampler,
-        PrimeableRng, SingularActiveLineageSampler, SpeciationProbability, TurnoverRate,
-    },
+    cogs::{DispersalSampler, Habitat, PrimeableRng, SpeciationProbability, TurnoverRate},
     event::{DispersalEvent, LineageInteraction},
     landscape::IndexedLocation,
-    lineage::{GlobalLineageReference, Lineage, MigratingLineage},
+    lineage::{GlobalLineageReference, Lineage, MigratingLineage, UnresolvedLineage},
     reporter::Reporter,
     simulation::Simulation,
 };
@@ -23,16 +20,17 @@ use crate::{
         },
         coalescence_sampler::independent::IndependentCoalescenceSampler,
         emigration_exit::independent::{choice::EmigrationChoice, IndependentEmigrationExit},
-        event_sampler::independent::IndependentEventSampler,
+        event_sampler::independent::IndependentEventRate,
         immigration_entry::never::NeverImmigrationEntry,
         lineage_store::independent::IndependentLineageStore,
     },
     decomposition::Decomposition,
+    parallelisation::SimulationLimits,
 };
 
 use super::{reporter::IgnoreProgressReporterProxy, DedupCache};
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_lines)]
 pub fn simulate<
     H: Habitat,
     C: Decomposition<H>,
@@ -41,7 +39,8 @@ pub fn simulate<
     D: DispersalSampler<H, G>,
     T: TurnoverRate<H>,
     N: SpeciationProbability<H>,
-    J: EventTimeSampler<H, G, T>,
+    V: IndependentEventRate<H, G, IndependentEmigrationExit<H, C, E>, D, T, N>,
+    J: EventTimeSampler<H, G>,
     R: Reporter,
     P: LocalPartition<R>,
 >(
@@ -55,13 +54,14 @@ pub fn simulate<
         IndependentCoalescenceSampler<H>,
         T,
         N,
-        IndependentEventSampler<H, G, IndependentEmigrationExit<H, C, E>, D, T, N>,
+        V,
         NeverImmigrationEntry,
         IndependentActiveLineageSampler<H, G, IndependentEmigrationExit<H, C, E>, D, T, N, J>,
     >,
     mut lineages: VecDeque<Lineage>,
     dedup_cache: DedupCache,
     step_slice: NonZeroU64,
+    limits: SimulationLimits,
     local_partition: &mut P,
 ) -> (NonNegativeF64, u64) {
     let mut proxy = IgnoreProgressReporterProxy::from(local_partition);
@@ -79,10 +79,15 @@ pub fn simulate<
 
     let mut local_immigration_count = Wrapping(0_u64);
 
-    while !lineages.is_empty()
-        || simulation.active_lineage_sampler().number_active_lineages() > 0
-        || !simulation.emigration_exit().is_empty()
-        || proxy.local_partition().wait_for_termination()
+    let mut unresolved_lineages = Vec::new();
+
+    // Landscape partitions only synchronise once all of them have finished,
+    //  such that they cannot vote on an interrupt, which is thus unsupported
+    while limits.permits_steps(total_steps)
+        && (!lineages.is_empty()
+            || simulation.number_active_lineages() > 0
+            || !simulation.emigration_exit().is_empty()
+            || proxy.local_partition().wait_for_termination())
     {
         proxy.report_total_progress(
             (Wrapping(lineages.len() as u64) + simulation.get_balanced_remaining_work()
@@ -90,6 +95,11 @@ pub fn simulate<
                 .0,
         );
 
+        // Only peek at the next event if the time is limited
+        let previous_next_event_time = limits
+            .max_time
+            .and_then(|_| simulation.peek_time_of_next_event());
+
         let previous_task = simulation
             .active_lineage_sampler_mut()
             .replace_active_lineage(lineages.pop_front());
@@ -97,18 +107,35 @@ pub fn simulate<
         let previous_speciation_sample =
             simulation.event_sampler_mut().replace_min_speciation(None);
 
-        if let Some(previous_speciation_sample) = previous_speciation_sample {
-            if min_spec_samples.insert(previous_speciation_sample) {
-                if let Some(previous_task) = previous_task {
-                    if previous_task.is_active() {
-                        lineages.push_back(previous_task);
-                    }
+        // A lineage without a speciation sample has not moved since it was
+        //  last checked, e.g. since it is already beyond the time limit
+        let duplicate_individual = previous_speciation_sample
+            .map_or(false, |spec_sample| !min_spec_samples.insert(spec_sample));
+
+        if let (Some(previous_task), false) = (previous_task, duplicate_individual) {
+            if previous_task.is_active() {
+                // Lineages beyond the time limit remain unresolved
+                if previous_next_event_time
+                    .map_or(true, |next_time| limits.permits_event_at(next_time))
+                {
+                    lineages.push_back(previous_task);
+                } else {
+                    unresolved_lineages.push(previous_task);
                 }
             }
         }
 
-        let (new_time, new_steps) = simulation
-            .simulate_incremental_early_stop(|_, steps| steps >= step_slice.get(), &mut proxy);
+        let (new_time, new_steps) = simulation.simulate_incremental_early_stop(
+            |simulation, steps| {
+                steps >= step_slice.get()
+                    || !limits.permits_steps(total_steps + steps)
+                    || (limits.max_time.is_some()
+                        && simulation
+                            .peek_time_of_next_event()
+                            .map_or(false, |next_time| !limits.permits_event_at(next_time)))
+            },
+            &mut proxy,
+        );
 
         total_steps += new_steps;
         max_time = max_time.max(new_time);
@@ -181,7 +208,33 @@ pub fn simulate<
         }
     }
 
-    proxy.local_partition().report_progress_sync(0_u64);
+    // All lineages which remain after the simulation stopped early are unresolved
+    unresolved_lineages.extend(
+        simulation
+            .active_lineage_sampler_mut()
+            .replace_active_lineage(None),
+    );
+    unresolved_lineages.extend(lineages);
+
+    for lineage in &unresolved_lineages {
+        if let Some(lineage) = UnresolvedLineage::from_active_lineage(lineage) {
+            proxy
+                .local_partition()
+                .get_reporter()
+                .report_unresolved(&lineage);
+        }
+    }
+
+    // Only a simulation which stopped early has any remaining work
+    proxy
+        .local_partition()
+        .report_progress_sync(if unresolved_lineages.is_empty() {
+            0_u64
+        } else {
+            (Wrapping(unresolved_lineages.len() as u64) + simulation.get_balanced_remaining_work()
+                - local_immigration_count)
+                .0
+        });
 
     proxy
         .local_partition()
@@ -1,6 +1,6 @@
 use core::num::NonZeroUsize;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use necsim_core_bond::PositiveF64;
 
@@ -12,19 +12,19 @@ pub mod individuals;
 pub mod landscape;
 pub mod monolithic;
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct AbsoluteDedupCache {
     pub capacity: NonZeroUsize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct RelativeDedupCache {
     pub factor: PositiveF64,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum DedupCache {
     Absolute(AbsoluteDedupCache),
     Relative(RelativeDedupCache),
@@ -1,18 +1,15 @@
-use alloc::collections::VecDeque;
+use alloc::{collections::VecDeque, vec::Vec};
 use core::num::{NonZeroU64, NonZeroUsize, Wrapping};
 use necsim_core_bond::NonNegativeF64;
 
 use necsim_core::{
-    cogs::{
-        ActiveLineageSampler, DispersalSampler, Habitat, MinSpeciationTrackingEventSampler,
-        PrimeableRng, SingularActiveLineageSampler, SpeciationProbability, TurnoverRate,
-    },
-    lineage::{GlobalLineageReference, Lineage},
+    cogs::{DispersalSampler, Habitat, PrimeableRng, SpeciationProbability, TurnoverRate},
+    lineage::{GlobalLineageReference, Lineage, UnresolvedLineage},
     reporter::{boolean::Boolean, Reporter},
     simulation::Simulation,
 };
 
-use necsim_partitioning_core::LocalPartition;
+use necsim_partitioning_core::{interrupt::is_interrupted, LocalPartition};
 
 use crate::cogs::{
     active_lineage_sampler::independent::{
@@ -20,12 +17,12 @@ use crate::cogs::{
     },
     coalescence_sampler::independent::IndependentCoalescenceSampler,
     emigration_exit::never::NeverEmigrationExit,
-    event_sampler::independent::IndependentEventSampler,
+    event_sampler::independent::IndependentEventRate,
     immigration_entry::never::NeverImmigrationEntry,
     lineage_store::independent::IndependentLineageStore,
 };
 
-use crate::parallelisation::independent::DedupCache;
+use crate::parallelisation::{independent::DedupCache, SimulationLimits};
 
 pub mod reporter;
 
@@ -40,7 +37,8 @@ pub fn simulate<
     D: DispersalSampler<H, G>,
     T: TurnoverRate<H>,
     N: SpeciationProbability<H>,
-    J: EventTimeSampler<H, G, T>,
+    E: IndependentEventRate<H, G, NeverEmigrationExit, D, T, N>,
+    J: EventTimeSampler<H, G>,
     R: Reporter,
     P: LocalPartition<R>,
 >(
@@ -54,7 +52,7 @@ pub fn simulate<
         IndependentCoalescenceSampler<H>,
         T,
         N,
-        IndependentEventSampler<H, G, NeverEmigrationExit, D, T, N>,
+        E,
         NeverImmigrationEntry,
         IndependentActiveLineageSampler<H, G, NeverEmigrationExit, D, T, N, J>,
     >,
@@ -62,6 +60,7 @@ pub fn simulate<
     dedup_cache: DedupCache,
     step_slice: NonZeroU64,
     event_slice: NonZeroUsize,
+    limits: SimulationLimits,
     local_partition: &mut P,
 ) -> (NonNegativeF64, u64) {
     // Ensure that the progress bar starts with the expected target
@@ -81,10 +80,11 @@ pub fn simulate<
 
     let mut slow_lineages = lineages;
     let mut fast_lineages = VecDeque::new();
+    let mut unresolved_lineages = Vec::new();
 
     let mut level_time = NonNegativeF64::zero();
 
-    while !slow_lineages.is_empty() {
+    while !slow_lineages.is_empty() && !is_interrupted() && limits.permits_steps(total_steps) {
         // Calculate a new water-level time which all individuals should reach
         let total_event_rate: NonNegativeF64 = if R::ReportDispersal::VALUE {
             // Full event rate lambda with speciation
@@ -125,8 +125,9 @@ pub fn simulate<
 
         // Simulate all slow lineages until they have finished or exceeded the new water
         //  level
-        while !slow_lineages.is_empty()
-            || simulation.active_lineage_sampler().number_active_lineages() > 0
+        while (!slow_lineages.is_empty() || simulation.number_active_lineages() > 0)
+            && !is_interrupted()
+            && limits.permits_steps(total_steps)
         {
             let previous_next_event_time = simulation.peek_time_of_next_event();
 
@@ -144,8 +145,11 @@ pub fn simulate<
                     .map_or(false, |spec_sample| !min_spec_samples.insert(spec_sample));
 
                 if !duplicate_individual {
-                    // Reclassify lineages as either slow (still below water) or fast
-                    if previous_next_event_time < level_time {
+                    // Reclassify lineages as either unresolved (beyond the time
+                    //  limit), slow (still below water) or fast
+                    if !limits.permits_event_at(previous_next_event_time) {
+                        unresolved_lineages.push(previous_task);
+                    } else if previous_next_event_time < level_time {
                         slow_lineages.push_back(previous_task);
                     } else {
                         fast_lineages.push_back(previous_task);
@@ -156,9 +160,12 @@ pub fn simulate<
             let (new_time, new_steps) = simulation.simulate_incremental_early_stop(
                 |simulation, steps| {
                     steps >= step_slice.get()
+                        || !limits.permits_steps(total_steps + steps)
                         || simulation
                             .peek_time_of_next_event()
-                            .map_or(true, |next_time| next_time >= level_time)
+                            .map_or(true, |next_time| {
+                                next_time >= level_time || !limits.permits_event_at(next_time)
+                            })
                 },
                 &mut proxy,
             );
@@ -182,7 +189,24 @@ pub fn simulate<
     // [Report all remaining events]
     proxy.finalise();
 
-    local_partition.report_progress_sync(0_u64);
+    // All lineages which remain after the simulation stopped early are unresolved
+    unresolved_lineages.extend(
+        simulation
+            .active_lineage_sampler_mut()
+            .replace_active_lineage(None),
+    );
+    unresolved_lineages.extend(slow_lineages);
+    unresolved_lineages.extend(fast_lineages);
+
+    for lineage in &unresolved_lineages {
+        if let Some(lineage) = UnresolvedLineage::from_active_lineage(lineage) {
+            local_partition.get_reporter().report_unresolved(&lineage);
+        }
+    }
+
+    local_partition.report_progress_sync(
+        (Wrapping(unresolved_lineages.len() as u64) + simulation.get_balanced_remaining_work()).0,
+    );
 
     local_partition.reduce_global_time_steps(max_time, total_steps)
 }
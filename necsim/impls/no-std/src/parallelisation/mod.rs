@@ -1,2 +1,42 @@
+use necsim_core::reporter::Reporter;
+use necsim_core_bond::PositiveF64;
+
+use necsim_partitioning_core::{interrupt::is_interrupted, LocalPartition};
+
 pub mod independent;
 pub mod monolithic;
+
+/// Limits at which a simulation stops early, after which all of its
+///  remaining active lineages are reported as unresolved
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimulationLimits {
+    /// No events after `max_time` are simulated
+    pub max_time: Option<PositiveF64>,
+    /// No more than `max_steps` steps are simulated on every partition,
+    ///  though partitions which synchronise with each other may only stop
+    ///  at their next synchronisation point
+    pub max_steps: Option<u64>,
+}
+
+impl SimulationLimits {
+    #[must_use]
+    pub fn permits_event_at(&self, event_time: PositiveF64) -> bool {
+        self.max_time
+            .map_or(true, |max_time| event_time <= max_time)
+    }
+
+    #[must_use]
+    pub fn permits_steps(&self, steps: u64) -> bool {
+        self.max_steps.map_or(true, |max_steps| steps < max_steps)
+    }
+}
+
+/// Checks if any partition has been interrupted or has reached the step
+///  limit. This function must be called collectively by all partitions.
+fn reduce_vote_stop<R: Reporter, L: LocalPartition<R>>(
+    local_partition: &L,
+    limits: &SimulationLimits,
+    steps: u64,
+) -> bool {
+    local_partition.reduce_vote_continue(is_interrupted() || !limits.permits_steps(steps))
+}
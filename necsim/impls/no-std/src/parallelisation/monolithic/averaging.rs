@@ -17,6 +17,7 @@ use crate::{
         immigration_entry::buffered::BufferedImmigrationEntry,
     },
     decomposition::Decomposition,
+    parallelisation::{reduce_vote_stop, SimulationLimits},
 };
 
 #[allow(clippy::type_complexity)]
@@ -62,6 +63,7 @@ pub fn simulate<
         A,
     >,
     independent_time_slice: PositiveF64,
+    limits: SimulationLimits,
     local_partition: &mut L,
 ) -> (NonNegativeF64, u64) {
     // Ensure that the progress bar starts with the expected target
@@ -71,14 +73,21 @@ pub fn simulate<
 
     let mut total_steps = 0_u64;
 
-    while local_partition.reduce_vote_continue(simulation.peek_time_of_next_event().is_some()) {
+    while local_partition.reduce_vote_continue(
+        simulation
+            .peek_time_of_next_event()
+            .map_or(false, |next_time| limits.permits_event_at(next_time)),
+    ) && !reduce_vote_stop(local_partition, &limits, total_steps)
+    {
         let next_safe_time = global_safe_time + independent_time_slice;
 
         let (_, new_steps) = simulation.simulate_incremental_early_stop(
             |simulation, _| {
                 simulation
                     .peek_time_of_next_event()
-                    .map_or(true, |next_time| next_time >= next_safe_time)
+                    .map_or(true, |next_time| {
+                        next_time >= next_safe_time || !limits.permits_event_at(next_time)
+                    })
             },
             local_partition.get_reporter(),
         );
@@ -121,7 +130,9 @@ pub fn simulate<
         global_safe_time += independent_time_slice.into();
     }
 
-    local_partition.report_progress_sync(0_u64);
+    simulation.report_unresolved_lineages(local_partition.get_reporter());
+
+    local_partition.report_progress_sync(simulation.get_balanced_remaining_work().0);
 
     local_partition.reduce_global_time_steps(
         simulation.active_lineage_sampler().get_last_event_time(),
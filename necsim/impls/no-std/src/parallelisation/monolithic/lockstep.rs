@@ -17,6 +17,7 @@ use crate::{
         immigration_entry::buffered::BufferedImmigrationEntry,
     },
     decomposition::Decomposition,
+    parallelisation::{reduce_vote_stop, SimulationLimits},
 };
 
 #[allow(clippy::type_complexity)]
@@ -61,6 +62,7 @@ pub fn simulate<
         BufferedImmigrationEntry,
         A,
     >,
+    limits: SimulationLimits,
     local_partition: &mut L,
 ) -> (NonNegativeF64, u64) {
     // Ensure that the progress bar starts with the expected target
@@ -68,7 +70,12 @@ pub fn simulate<
 
     let mut total_steps = 0_u64;
 
-    while local_partition.reduce_vote_continue(simulation.peek_time_of_next_event().is_some()) {
+    while local_partition.reduce_vote_continue(
+        simulation
+            .peek_time_of_next_event()
+            .map_or(false, |next_time| limits.permits_event_at(next_time)),
+    ) && !reduce_vote_stop(local_partition, &limits, total_steps)
+    {
         // Get the next local event time or +inf
         //  (we already know at least one partition has some next event time)
         let next_local_time = simulation
@@ -110,7 +117,9 @@ pub fn simulate<
         }
     }
 
-    local_partition.report_progress_sync(0_u64);
+    simulation.report_unresolved_lineages(local_partition.get_reporter());
+
+    local_partition.report_progress_sync(simulation.get_balanced_remaining_work().0);
 
     local_partition.reduce_global_time_steps(
         simulation.active_lineage_sampler().get_last_event_time(),
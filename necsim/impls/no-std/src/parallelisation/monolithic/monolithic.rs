@@ -1,18 +1,22 @@
 use necsim_core::{
     cogs::{
         ActiveLineageSampler, CoalescenceSampler, DispersalSampler, EventSampler, Habitat,
-        LineageReference, LocallyCoherentLineageStore, RngCore, SpeciationProbability,
-        TurnoverRate,
+        LineageReference, LocallyCoherentLineageStore, OptionallyPeekableActiveLineageSampler,
+        RngCore, SpeciationProbability, TurnoverRate,
     },
     reporter::Reporter,
     simulation::Simulation,
 };
 use necsim_core_bond::NonNegativeF64;
 
-use necsim_partitioning_core::LocalPartition;
+use necsim_partitioning_core::{interrupt::is_interrupted, LocalPartition};
 
-use crate::cogs::{
-    emigration_exit::never::NeverEmigrationExit, immigration_entry::never::NeverImmigrationEntry,
+use crate::{
+    cogs::{
+        emigration_exit::never::NeverEmigrationExit,
+        immigration_entry::never::NeverImmigrationEntry,
+    },
+    parallelisation::SimulationLimits,
 };
 
 #[allow(clippy::type_complexity)]
@@ -30,7 +34,7 @@ pub fn simulate<
     P: Reporter,
     L: LocalPartition<P>,
 >(
-    simulation: Simulation<
+    mut simulation: Simulation<
         H,
         G,
         R,
@@ -44,14 +48,33 @@ pub fn simulate<
         NeverImmigrationEntry,
         A,
     >,
+    limits: SimulationLimits,
     local_partition: &mut L,
 ) -> (NonNegativeF64, u64) {
     // Ensure that the progress bar starts with the expected target
     local_partition.report_progress_sync(simulation.get_balanced_remaining_work().0);
 
-    let (time, steps, _rng) = simulation.simulate(local_partition.get_reporter());
+    let (time, steps) = simulation.simulate_incremental_early_stop(
+        |simulation, steps| {
+            // Only peek at the next event if the time is limited
+            is_interrupted()
+                || !limits.permits_steps(steps)
+                || (limits.max_time.is_some()
+                    && simulation
+                        .with_mut_split_active_lineage_sampler_and_rng(
+                            |active_lineage_sampler, simulation, rng| {
+                                active_lineage_sampler
+                                    .peek_optional_time_of_next_event(simulation, rng)
+                            },
+                        )
+                        .map_or(false, |next_time| !limits.permits_event_at(next_time)))
+        },
+        local_partition.get_reporter(),
+    );
 
-    local_partition.report_progress_sync(0_u64);
+    simulation.report_unresolved_lineages(local_partition.get_reporter());
+
+    local_partition.report_progress_sync(simulation.get_balanced_remaining_work().0);
 
     local_partition.reduce_global_time_steps(time, steps)
 }
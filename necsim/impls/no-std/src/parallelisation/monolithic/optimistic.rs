@@ -20,6 +20,7 @@ use crate::{
         immigration_entry::buffered::BufferedImmigrationEntry,
     },
     decomposition::Decomposition,
+    parallelisation::{reduce_vote_stop, SimulationLimits},
 };
 
 use super::reporter::BufferingReporterProxy;
@@ -67,6 +68,7 @@ pub fn simulate<
         A,
     >,
     independent_time_slice: PositiveF64,
+    limits: SimulationLimits,
     local_partition: &mut L,
 ) -> (NonNegativeF64, u64) {
     // Ensure that the progress bar starts with the expected target
@@ -82,9 +84,11 @@ pub fn simulate<
 
     let mut proxy = BufferingReporterProxy::from(local_partition);
 
-    while proxy
-        .local_partition()
-        .reduce_vote_continue(simulation.peek_time_of_next_event().is_some())
+    while proxy.local_partition().reduce_vote_continue(
+        simulation
+            .peek_time_of_next_event()
+            .map_or(false, |next_time| limits.permits_event_at(next_time)),
+    ) && !reduce_vote_stop(proxy.local_partition(), &limits, total_steps)
     {
         loop {
             let next_safe_time = global_safe_time + independent_time_slice;
@@ -93,7 +97,9 @@ pub fn simulate<
                 |simulation, _| {
                     simulation
                         .peek_time_of_next_event()
-                        .map_or(true, |next_time| next_time >= next_safe_time)
+                        .map_or(true, |next_time| {
+                            next_time >= next_safe_time || !limits.permits_event_at(next_time)
+                        })
                 },
                 &mut proxy,
             );
@@ -125,6 +131,7 @@ pub fn simulate<
                 // Roll back the simulation to the last backup, clear out all generated events
                 simulation = simulation_backup.resume();
                 proxy.clear_events();
+                proxy.local_partition().record_rollback();
 
                 // Back up the previous immigrating lineages in last_immigrants
                 last_immigrants.clear();
@@ -150,7 +157,11 @@ pub fn simulate<
         global_safe_time += independent_time_slice.into();
     }
 
-    proxy.local_partition().report_progress_sync(0_u64);
+    simulation.report_unresolved_lineages(proxy.local_partition().get_reporter());
+
+    proxy
+        .local_partition()
+        .report_progress_sync(simulation.get_balanced_remaining_work().0);
 
     proxy.local_partition().reduce_global_time_steps(
         simulation.active_lineage_sampler().get_last_event_time(),
@@ -221,8 +221,7 @@ impl<
 {
     fn peek_time_of_next_event(
         &mut self,
-        _habitat: &H,
-        _turnover_rate: &T,
+        _simulation: &PartialSimulation<H, G, R, S, X, D, C, T, N, E>,
         _rng: &mut G,
     ) -> Result<PositiveF64, EmptyActiveLineageSamplerError> {
         self.active_locations
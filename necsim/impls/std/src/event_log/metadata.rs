@@ -0,0 +1,90 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use necsim_core_bond::Partition;
+
+/// File name of the metadata which is stored alongside the event log segments
+pub const EVENT_LOG_METADATA_FILE_NAME: &str = "metadata.ron";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+pub struct EventLogMetadata {
+    /// Name and version of the program which recorded the event log
+    pub version: String,
+    /// Partition of the simulation which recorded the event log
+    pub partition: Partition,
+    /// Fully resolved simulation configuration, including all defaults
+    pub configuration: String,
+    /// Input files which the simulation read, with their checksums
+    pub inputs: Vec<EventLogInput>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+pub struct EventLogInput {
+    pub path: PathBuf,
+    pub sha256: String,
+}
+
+impl EventLogMetadata {
+    /// Checks if two event logs were recorded by different partitions of
+    ///  the same simulation
+    #[must_use]
+    pub fn is_consistent_with(&self, other: &Self) -> bool {
+        self.version == other.version
+            && self.partition.partitions() == other.partition.partitions()
+            && self.configuration == other.configuration
+            && self.inputs == other.inputs
+    }
+
+    /// # Errors
+    ///
+    /// Fails iff the metadata could not be written to `directory`.
+    pub fn write_to_directory(&self, directory: &Path) -> Result<()> {
+        let path = directory.join(EVENT_LOG_METADATA_FILE_NAME);
+
+        let metadata = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&path)
+            .with_context(|| format!("Failed to create the event log metadata {:?}.", path))?;
+
+        file.write_all(metadata.as_bytes())
+            .with_context(|| format!("Failed to write the event log metadata {:?}.", path))
+    }
+
+    /// # Errors
+    ///
+    /// Fails iff the metadata in `directory` exists but could not be read.
+    pub fn try_read_from_directory(directory: &Path) -> Result<Option<Self>> {
+        let path = directory.join(EVENT_LOG_METADATA_FILE_NAME);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let metadata = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read the event log metadata {:?}.", path))?;
+
+        ron::de::from_str(&metadata)
+            .map(Some)
+            .with_context(|| format!("Failed to parse the event log metadata {:?}.", path))
+    }
+}
+
+#[must_use]
+pub(crate) fn is_event_log_metadata_file(path: &Path) -> bool {
+    path.file_name()
+        .map_or(false, |name| name == EVENT_LOG_METADATA_FILE_NAME)
+}
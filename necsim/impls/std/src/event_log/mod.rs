@@ -1,10 +1,25 @@
-use std::{cmp::Ordering, fmt};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::Hasher,
+    io::{self, Read, Write},
+};
 
+use anyhow::Result;
 use necsim_core_bond::PositiveF64;
+use seahash::SeaHasher;
 use serde::{Deserialize, Serialize};
 
+pub mod metadata;
 pub mod recorder;
 pub mod replay;
+pub mod unresolved;
+
+/// Magic bytes which precede a versioned event log header. They encode a
+///  negative `f64`, which can never start an unversioned header, as its first
+///  field is the positive minimum event time.
+const EVENT_LOG_HEADER_MAGIC: [u8; 8] = *b"necsimL\xC6";
+const EVENT_LOG_HEADER_VERSION: u32 = 1;
 
 #[derive(Serialize, Deserialize, PartialEq)]
 #[allow(clippy::module_name_repetitions)]
@@ -16,15 +31,36 @@ pub struct EventLogHeader {
 
     with_speciation: bool,
     with_dispersal: bool,
+
+    checksum: Option<u64>,
+}
+
+/// Header of event log segments which were written before the header was
+///  versioned and before the segments were checksummed
+#[derive(Deserialize)]
+struct UnversionedEventLogHeader {
+    min_time: PositiveF64,
+    max_time: PositiveF64,
+
+    length: usize,
+
+    with_speciation: bool,
+    with_dispersal: bool,
 }
 
 impl fmt::Debug for EventLogHeader {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt.debug_struct("EventLogHeader")
+        let mut debug = fmt.debug_struct("EventLogHeader");
+        debug
             .field("min_time", &self.min_time)
             .field("max_time", &self.max_time)
-            .field("length", &self.length)
-            .finish()
+            .field("length", &self.length);
+
+        if let Some(checksum) = self.checksum {
+            debug.field("checksum", &format_args!("{:#018x}", checksum));
+        }
+
+        debug.finish()
     }
 }
 
@@ -36,6 +72,7 @@ impl EventLogHeader {
         length: usize,
         with_speciation: bool,
         with_dispersal: bool,
+        checksum: Option<u64>,
     ) -> Self {
         Self {
             min_time,
@@ -43,6 +80,7 @@ impl EventLogHeader {
             length,
             with_speciation,
             with_dispersal,
+            checksum,
         }
     }
 
@@ -70,6 +108,65 @@ impl EventLogHeader {
     pub fn with_dispersal(&self) -> bool {
         self.with_dispersal
     }
+
+    /// `SeaHash` checksum of the serialised events which follow the header,
+    ///  which is `None` for segments that were written before checksums
+    #[must_use]
+    pub fn checksum(&self) -> Option<u64> {
+        self.checksum
+    }
+
+    /// Reads a versioned event log header, or falls back to reading an
+    ///  unversioned header without a checksum
+    ///
+    /// # Errors
+    ///
+    /// Fails if the header cannot be read or has an unsupported version
+    pub fn read_from<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut magic = [0_u8; 8];
+        reader.read_exact(&mut magic)?;
+
+        if magic != EVENT_LOG_HEADER_MAGIC {
+            let unversioned: UnversionedEventLogHeader =
+                bincode::deserialize_from(io::Cursor::new(magic).chain(reader))?;
+
+            return Ok(Self::new(
+                unversioned.min_time,
+                unversioned.max_time,
+                unversioned.length,
+                unversioned.with_speciation,
+                unversioned.with_dispersal,
+                None,
+            ));
+        }
+
+        let mut version = [0_u8; 4];
+        reader.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+
+        anyhow::ensure!(
+            version == EVENT_LOG_HEADER_VERSION,
+            "The event log header version {} is not supported.",
+            version
+        );
+
+        Ok(bincode::deserialize_from(reader)?)
+    }
+
+    /// Writes the header with the current version, which always has the
+    ///  same size for a checksummed header
+    ///
+    /// # Errors
+    ///
+    /// Fails if the header cannot be written
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&EVENT_LOG_HEADER_MAGIC)?;
+        writer.write_all(&EVENT_LOG_HEADER_VERSION.to_le_bytes())?;
+
+        bincode::serialize_into(writer, self)?;
+
+        Ok(())
+    }
 }
 
 impl Eq for EventLogHeader {}
@@ -85,3 +182,49 @@ impl PartialOrd for EventLogHeader {
         }
     }
 }
+
+/// Reader or writer which computes the `SeaHash` checksum of all bytes
+///  which are read or written through it
+pub(crate) struct Checksummed<T> {
+    inner: T,
+    hasher: SeaHasher,
+}
+
+impl<T> Checksummed<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            hasher: SeaHasher::new(),
+        }
+    }
+
+    pub fn finish(self) -> (T, u64) {
+        let checksum = self.hasher.finish();
+
+        (self.inner, checksum)
+    }
+}
+
+impl<R: Read> Read for Checksummed<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+
+        self.hasher.write(&buf[..read]);
+
+        Ok(read)
+    }
+}
+
+impl<W: Write> Write for Checksummed<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+
+        self.hasher.write(&buf[..written]);
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -18,15 +18,29 @@ use std::{
     convert::TryFrom,
     fmt,
     fs::{self, OpenOptions},
-    io::BufWriter,
+    io::{BufWriter, Seek, SeekFrom},
     path::{Path, PathBuf},
 };
 
-use anyhow::{Error, Result};
+use anyhow::{Context, Error, Result};
 
-use necsim_core::event::{DispersalEvent, PackedEvent, SpeciationEvent};
+use necsim_core::{
+    event::{DispersalEvent, PackedEvent, SpeciationEvent},
+    lineage::UnresolvedLineage,
+};
+
+use super::{unresolved, Checksummed, EventLogHeader};
 
-use super::EventLogHeader;
+/// Extension of the temporary files into which event log segments are
+///  written before they are atomically renamed into place
+const TEMPORARY_SEGMENT_EXTENSION: &str = "tmp";
+
+/// Checks if `path` is a temporary event log segment file, which was left
+///  behind by an interrupted or failed segment write
+pub(crate) fn is_temporary_segment_file(path: &Path) -> bool {
+    path.extension()
+        .map_or(false, |extension| extension == TEMPORARY_SEGMENT_EXTENSION)
+}
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(serde::Deserialize)]
@@ -36,6 +50,8 @@ pub struct EventLogRecorder {
     directory: PathBuf,
     segment_index: usize,
     buffer: Vec<PackedEvent>,
+    unresolved: Vec<UnresolvedLineage>,
+    error: Option<Error>,
 
     record_speciation: bool,
     record_dispersal: bool,
@@ -51,7 +67,7 @@ impl TryFrom<PathBuf> for EventLogRecorder {
 
 impl Drop for EventLogRecorder {
     fn drop(&mut self) {
-        if !self.buffer.is_empty() {
+        if !self.buffer.is_empty() && self.error.is_none() {
             std::mem::drop(self.sort_and_write_segment());
         }
     }
@@ -82,6 +98,8 @@ impl EventLogRecorder {
             directory: path.to_owned(),
             segment_index: 0_usize,
             buffer: Vec::with_capacity(segment_size),
+            unresolved: Vec::new(),
+            error: None,
 
             record_speciation: false,
             record_dispersal: false,
@@ -101,20 +119,61 @@ impl EventLogRecorder {
     pub fn record_speciation(&mut self, event: &SpeciationEvent) {
         self.record_speciation = true;
 
-        self.buffer.push(event.clone().into());
-
-        if self.buffer.len() >= self.segment_size {
-            std::mem::drop(self.sort_and_write_segment());
-        }
+        self.record_event(event.clone().into());
     }
 
     pub fn record_dispersal(&mut self, event: &DispersalEvent) {
         self.record_dispersal = true;
 
-        self.buffer.push(event.clone().into());
+        self.record_event(event.clone().into());
+    }
+
+    pub fn record_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        self.unresolved.push(lineage.clone());
+    }
+
+    /// Writes out all remaining buffered events and unresolved lineages.
+    ///
+    /// # Errors
+    ///
+    /// Fails iff any event log segment or the unresolved lineages could not
+    ///  be written. Since no events are recorded after the first failure, the
+    ///  error of that failure is returned.
+    pub fn finish(&mut self) -> Result<()> {
+        if !self.buffer.is_empty() && self.error.is_none() {
+            self.write_or_store_error();
+        }
+
+        if !self.unresolved.is_empty() && self.error.is_none() {
+            if let Err(error) = unresolved::write_to_directory(&self.directory, &self.unresolved) {
+                self.error = Some(error);
+            }
+
+            self.unresolved.clear();
+        }
+
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn record_event(&mut self, event: PackedEvent) {
+        // Recording stops after the first failure, which is reported by `finish`
+        if self.error.is_some() {
+            return;
+        }
+
+        self.buffer.push(event);
 
         if self.buffer.len() >= self.segment_size {
-            std::mem::drop(self.sort_and_write_segment());
+            self.write_or_store_error();
+        }
+    }
+
+    fn write_or_store_error(&mut self) {
+        if let Err(error) = self.sort_and_write_segment() {
+            self.error = Some(error);
         }
     }
 
@@ -122,31 +181,62 @@ impl EventLogRecorder {
         self.buffer.sort();
 
         let segment_path = self.directory.join(format!("{}", self.segment_index));
+        let temporary_path = self.directory.join(format!(
+            ".{}.{}",
+            self.segment_index, TEMPORARY_SEGMENT_EXTENSION
+        ));
         self.segment_index += 1;
 
+        // The segment only appears under its final name once it has been
+        //  completely written, such that a crash cannot leave a truncated
+        //  segment behind
+        let result = self
+            .write_segment(&temporary_path)
+            .and_then(|()| fs::rename(&temporary_path, &segment_path).map_err(Error::from))
+            .with_context(|| format!("Failed to write the event log segment {:?}.", segment_path));
+
+        if result.is_err() {
+            std::mem::drop(fs::remove_file(&temporary_path));
+        }
+
+        self.buffer.clear();
+
+        result
+    }
+
+    fn write_segment(&self, path: &Path) -> Result<()> {
         let segment_file = OpenOptions::new()
             .create(true)
             .truncate(true)
             .write(true)
-            .open(&segment_path)?;
+            .open(path)?;
         let mut buf_writer = BufWriter::new(segment_file);
 
-        bincode::serialize_into(
-            &mut buf_writer,
-            &EventLogHeader::new(
-                self.buffer[0].event_time,
-                self.buffer[self.buffer.len() - 1].event_time,
-                self.buffer.len(),
-                self.record_speciation,
-                self.record_dispersal,
-            ),
-        )?;
-
-        for event in self.buffer.drain(0..) {
-            bincode::serialize_into(&mut buf_writer, &event)?;
+        let mut header = EventLogHeader::new(
+            self.buffer[0].event_time,
+            self.buffer[self.buffer.len() - 1].event_time,
+            self.buffer.len(),
+            self.record_speciation,
+            self.record_dispersal,
+            Some(0_u64),
+        );
+
+        header.write_to(&mut buf_writer)?;
+
+        let mut checksummed = Checksummed::new(buf_writer);
+
+        for event in &self.buffer {
+            bincode::serialize_into(&mut checksummed, event)?;
         }
 
-        buf_writer.into_inner()?;
+        let (mut buf_writer, checksum) = checksummed.finish();
+
+        // The header has a fixed size and can be overwritten with the checksum
+        header.checksum = Some(checksum);
+        buf_writer.seek(SeekFrom::Start(0))?;
+        header.write_to(&mut buf_writer)?;
+
+        buf_writer.into_inner()?.sync_all()?;
 
         Ok(())
     }
@@ -167,6 +257,8 @@ impl fmt::Debug for EventLogRecorder {
             .field("directory", &self.directory)
             .field("segment_index", &self.segment_index)
             .field("buffer", &EventBufferLen(self.buffer.len()))
+            .field("unresolved", &self.unresolved.len())
+            .field("failed", &self.error.is_some())
             .finish()
     }
 }
@@ -1,13 +1,23 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use glob::MatchOptions;
 use serde::{Deserialize, Deserializer};
 
+use necsim_core::lineage::UnresolvedLineage;
+
+use crate::event_log::{
+    metadata::{is_event_log_metadata_file, EventLogMetadata},
+    recorder::is_temporary_segment_file,
+    unresolved::{self, is_event_log_unresolved_file},
+};
+
 use super::segment::SortedSegment;
 
 #[allow(clippy::module_name_repetitions)]
 pub struct GlobbedSortedSegments {
     segments: Vec<SortedSegment>,
+    metadata: Vec<(PathBuf, Option<EventLogMetadata>)>,
+    unresolved: Vec<(PathBuf, Vec<UnresolvedLineage>)>,
 }
 
 impl<'de> Deserialize<'de> for GlobbedSortedSegments {
@@ -26,7 +36,38 @@ impl<'de> Deserialize<'de> for GlobbedSortedSegments {
         )
         .map_err(serde::de::Error::custom)?
         {
-            paths.push(path.map_err(serde::de::Error::custom)?);
+            let path = path.map_err(serde::de::Error::custom)?;
+
+            // The metadata and unresolved lineages are stored alongside, but
+            //  are not segments, and temporary files are leftovers from
+            //  incomplete writes
+            if !is_event_log_metadata_file(&path)
+                && !is_event_log_unresolved_file(&path)
+                && !is_temporary_segment_file(&path)
+            {
+                paths.push(path);
+            }
+        }
+
+        let mut metadata: Vec<(PathBuf, Option<EventLogMetadata>)> = Vec::new();
+        let mut unresolved: Vec<(PathBuf, Vec<UnresolvedLineage>)> = Vec::new();
+
+        for path in &paths {
+            let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+            if path.is_dir() || metadata.iter().any(|(dir, _)| dir == directory) {
+                continue;
+            }
+
+            metadata.push((
+                directory.to_owned(),
+                EventLogMetadata::try_read_from_directory(directory)
+                    .map_err(serde::de::Error::custom)?,
+            ));
+            unresolved.push((
+                directory.to_owned(),
+                unresolved::try_read_from_directory(directory).map_err(serde::de::Error::custom)?,
+            ));
         }
 
         let mut segments = Vec::with_capacity(paths.len().min(1));
@@ -53,7 +94,21 @@ impl<'de> Deserialize<'de> for GlobbedSortedSegments {
             }
         }
 
-        Ok(Self { segments })
+        Ok(Self {
+            segments,
+            metadata,
+            unresolved,
+        })
+    }
+}
+
+impl GlobbedSortedSegments {
+    pub fn take_metadata(&mut self) -> Vec<(PathBuf, Option<EventLogMetadata>)> {
+        std::mem::take(&mut self.metadata)
+    }
+
+    pub fn take_unresolved(&mut self) -> Vec<(PathBuf, Vec<UnresolvedLineage>)> {
+        std::mem::take(&mut self.unresolved)
     }
 }
 
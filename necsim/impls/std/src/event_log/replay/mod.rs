@@ -1,7 +1,9 @@
 use serde::Deserialize;
-use std::{collections::BinaryHeap, convert::TryFrom, iter::FromIterator};
+use std::{collections::BinaryHeap, convert::TryFrom, iter::FromIterator, path::PathBuf};
 
-use necsim_core::event::PackedEvent;
+use necsim_core::{event::PackedEvent, lineage::UnresolvedLineage};
+
+use super::metadata::EventLogMetadata;
 
 mod globbed;
 pub mod segment;
@@ -15,24 +17,64 @@ use sorted_segments::SortedSortedSegments;
 #[derive(Debug, Deserialize)]
 #[serde(try_from = "Vec<GlobbedSortedSegments>")]
 pub struct EventLogReplay {
+    // The segments are only grouped into the sorted frontier once the replay
+    //  starts, such that they can still be validated before
+    segments: Vec<SortedSegment>,
     frontier: BinaryHeap<SortedSortedSegments>,
 
     with_speciation: bool,
     with_dispersal: bool,
+
+    metadata: Vec<(PathBuf, Option<EventLogMetadata>)>,
+    unresolved: Vec<UnresolvedLineage>,
 }
 
 impl TryFrom<Vec<GlobbedSortedSegments>> for EventLogReplay {
     type Error = anyhow::Error;
 
-    fn try_from(vec: Vec<GlobbedSortedSegments>) -> Result<Self, Self::Error> {
-        vec.into_iter().flatten().collect()
+    fn try_from(mut vec: Vec<GlobbedSortedSegments>) -> Result<Self, Self::Error> {
+        let mut metadata: Vec<(PathBuf, Option<EventLogMetadata>)> = Vec::new();
+
+        for (directory, directory_metadata) in vec.iter_mut().flat_map(|g| g.take_metadata()) {
+            if !metadata.iter().any(|(dir, _)| dir == &directory) {
+                metadata.push((directory, directory_metadata));
+            }
+        }
+
+        let mut unresolved: Vec<(PathBuf, Vec<UnresolvedLineage>)> = Vec::new();
+
+        for (directory, directory_unresolved) in vec.iter_mut().flat_map(|g| g.take_unresolved()) {
+            if !unresolved.iter().any(|(dir, _)| dir == &directory) {
+                unresolved.push((directory, directory_unresolved));
+            }
+        }
+
+        let mut replay: Self = vec
+            .into_iter()
+            .flatten()
+            .collect::<anyhow::Result<Self>>()?;
+        replay.metadata = metadata;
+        replay.unresolved = unresolved
+            .into_iter()
+            .flat_map(|(_, directory_unresolved)| directory_unresolved)
+            .collect();
+
+        Ok(replay)
     }
 }
 
 impl EventLogReplay {
     #[must_use]
     pub fn length(&self) -> usize {
-        self.frontier.iter().map(SortedSortedSegments::length).sum()
+        self.segments
+            .iter()
+            .map(SortedSegment::length)
+            .sum::<usize>()
+            + self
+                .frontier
+                .iter()
+                .map(SortedSortedSegments::length)
+                .sum::<usize>()
     }
 
     #[must_use]
@@ -44,11 +86,78 @@ impl EventLogReplay {
     pub fn with_dispersal(&self) -> bool {
         self.with_dispersal
     }
+
+    /// Returns the metadata of every event log directory from which segments
+    ///  are replayed, or `None` if a directory does not contain any
+    #[must_use]
+    pub fn metadata(&self) -> &[(PathBuf, Option<EventLogMetadata>)] {
+        &self.metadata
+    }
+
+    /// Takes the lineages which were still unresolved when the simulations
+    ///  which recorded the replayed event logs stopped early, which should
+    ///  be reported after all events have been replayed
+    #[must_use]
+    pub fn take_unresolved(&mut self) -> Vec<UnresolvedLineage> {
+        std::mem::take(&mut self.unresolved)
+    }
+
+    /// Reads through all replayed event log segments to check that none of
+    ///  them have been corrupted or truncated since they were written. Iff
+    ///  `salvage` is enabled, only a warning is emitted for every corrupted
+    ///  segment, of which only the readable prefix is replayed.
+    ///
+    /// Validation is opt-in as it decodes every segment an additional time,
+    ///  and it must happen before the replay starts.
+    ///
+    /// # Errors
+    ///
+    /// Fails iff any segment cannot be read, or if any segment is corrupted
+    ///  and `salvage` is disabled.
+    pub fn validate(&mut self, salvage: bool) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.frontier.is_empty(),
+            "The EventLogReplay can only be validated before the replay starts."
+        );
+
+        for segment in &mut self.segments {
+            segment.validate()?;
+        }
+
+        let mut corrupted = self.segments.iter().filter(|s| s.is_corrupted());
+
+        if salvage {
+            for segment in corrupted {
+                warn!(
+                    "The event log segment {:?} is corrupted, only the first {} out of {} events \
+                     are salvaged.",
+                    segment.path(),
+                    segment.length(),
+                    segment.header().length()
+                );
+            }
+
+            return Ok(());
+        }
+
+        if let Some(segment) = corrupted.next() {
+            anyhow::bail!(
+                "{} event log segment(s) are corrupted or truncated, e.g. {:?} with only {} out \
+                 of {} readable events.",
+                corrupted.count() + 1,
+                segment.path(),
+                segment.length(),
+                segment.header().length(),
+            )
+        }
+
+        Ok(())
+    }
 }
 
 impl FromIterator<SortedSegment> for anyhow::Result<EventLogReplay> {
     fn from_iter<T: IntoIterator<Item = SortedSegment>>(iter: T) -> Self {
-        let mut segments: Vec<SortedSegment> = iter.into_iter().collect();
+        let segments: Vec<SortedSegment> = iter.into_iter().collect();
 
         if segments.is_empty() {
             anyhow::bail!("The EventLogReplay requires at least one event log segment.")
@@ -77,64 +186,75 @@ impl FromIterator<SortedSegment> for anyhow::Result<EventLogReplay> {
             }
         }
 
-        let mut grouped_segments: Vec<Vec<SortedSegment>> = Vec::new();
-        let mut current_group: Vec<SortedSegment> = Vec::new();
+        Ok(EventLogReplay {
+            segments,
+            frontier: BinaryHeap::new(),
+            with_speciation: with_speciation.unwrap(),
+            with_dispersal: with_dispersal.unwrap(),
+            metadata: Vec::new(),
+            unresolved: Vec::new(),
+        })
+    }
+}
+
+fn group_sorted_segments(mut segments: Vec<SortedSegment>) -> BinaryHeap<SortedSortedSegments> {
+    let mut grouped_segments: Vec<Vec<SortedSegment>> = Vec::new();
+    let mut current_group: Vec<SortedSegment> = Vec::new();
 
-        let mut max_time: f64 = f64::NEG_INFINITY;
+    let mut max_time: f64 = f64::NEG_INFINITY;
 
-        while !segments.is_empty() {
-            let mut min_time: f64 = f64::INFINITY;
-            let mut min_index: Option<usize> = None;
+    while !segments.is_empty() {
+        let mut min_time: f64 = f64::INFINITY;
+        let mut min_index: Option<usize> = None;
 
-            for (i, seg) in segments.iter().enumerate() {
-                if seg.header().min_time() > max_time && seg.header().min_time() < min_time {
-                    min_time = seg.header().min_time().get();
-                    min_index = Some(i);
-                }
+        for (i, seg) in segments.iter().enumerate() {
+            if seg.header().min_time() > max_time && seg.header().min_time() < min_time {
+                min_time = seg.header().min_time().get();
+                min_index = Some(i);
             }
+        }
 
-            let min_index = if let Some(min_index) = min_index {
-                min_index
-            } else {
-                if !current_group.is_empty() {
-                    grouped_segments.push(current_group);
-                    current_group = Vec::new();
-                }
+        let min_index = if let Some(min_index) = min_index {
+            min_index
+        } else {
+            if !current_group.is_empty() {
+                grouped_segments.push(current_group);
+                current_group = Vec::new();
+            }
 
-                max_time = f64::NEG_INFINITY;
+            max_time = f64::NEG_INFINITY;
 
-                continue;
-            };
+            continue;
+        };
 
-            let min_segement = segments.swap_remove(min_index);
+        let min_segement = segments.swap_remove(min_index);
 
-            max_time = min_segement.header().max_time().get();
+        max_time = min_segement.header().max_time().get();
 
-            current_group.push(min_segement);
-        }
-
-        if !current_group.is_empty() {
-            grouped_segments.push(current_group);
-        }
+        current_group.push(min_segement);
+    }
 
-        let mut frontier = BinaryHeap::with_capacity(grouped_segments.len());
+    if !current_group.is_empty() {
+        grouped_segments.push(current_group);
+    }
 
-        for group in grouped_segments {
-            frontier.push(SortedSortedSegments::new(group));
-        }
+    let mut frontier = BinaryHeap::with_capacity(grouped_segments.len());
 
-        Ok(EventLogReplay {
-            frontier,
-            with_speciation: with_speciation.unwrap(),
-            with_dispersal: with_dispersal.unwrap(),
-        })
+    for group in grouped_segments {
+        frontier.push(SortedSortedSegments::new(group));
     }
+
+    frontier
 }
 
 impl Iterator for EventLogReplay {
     type Item = PackedEvent;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if !self.segments.is_empty() {
+            self.frontier = group_sorted_segments(std::mem::take(&mut self.segments));
+        }
+
         let mut next_segment = self.frontier.pop()?;
 
         let next_event = next_segment.next();
@@ -3,29 +3,40 @@ use std::{
     collections::VecDeque,
     fmt,
     fs::{File, OpenOptions},
-    io::BufReader,
-    path::Path,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
 };
 
 use anyhow::Result;
 
 use necsim_core::event::PackedEvent;
 
-use crate::event_log::EventLogHeader;
+use crate::event_log::{Checksummed, EventLogHeader};
 
 #[allow(clippy::module_name_repetitions)]
 pub struct SortedSegment {
+    path: PathBuf,
     header: EventLogHeader,
     reader: BufReader<File>,
     buffer: VecDeque<PackedEvent>,
     capacity: usize,
+    readable: usize,
+    unread: usize,
+    checksum_valid: bool,
 }
 
 impl fmt::Debug for SortedSegment {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt.debug_struct("SortedSegment")
-            .field("header", &self.header)
-            .finish()
+        let mut debug = fmt.debug_struct("SortedSegment");
+        debug
+            .field("path", &self.path)
+            .field("header", &self.header);
+
+        if self.is_corrupted() {
+            debug.field("readable", &self.readable);
+        }
+
+        debug.finish()
     }
 }
 
@@ -38,30 +49,112 @@ impl SortedSegment {
 
         let mut buf_reader = BufReader::new(file);
 
-        let header: EventLogHeader = bincode::deserialize_from(&mut buf_reader)?;
+        let header = EventLogHeader::read_from(&mut buf_reader)?;
 
-        let mut buffer = VecDeque::with_capacity(header.length.min(capacity));
+        let mut buffer = VecDeque::with_capacity(header.length().min(capacity));
+        let mut unread = header.length();
 
-        if let Ok(event) = bincode::deserialize_from(&mut buf_reader) {
-            buffer.push_back(event);
+        if unread > 0 {
+            if let Ok(event) = bincode::deserialize_from(&mut buf_reader) {
+                buffer.push_back(event);
+                unread -= 1;
+            }
         }
 
         Ok(Self {
+            path: path.to_owned(),
+            readable: header.length(),
             header,
             reader: buf_reader,
             buffer,
             capacity,
+            unread,
+            checksum_valid: true,
         })
     }
 
+    /// Reads through the entire segment to find the longest prefix of
+    ///  readable and sorted events, and to check that the events' checksum
+    ///  matches the one stored in the header. Only this prefix is replayed.
+    ///
+    /// This method must be called before any events are replayed.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the segment cannot be read
+    pub fn validate(&mut self) -> Result<()> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(false)
+            .open(&self.path)?;
+
+        let mut buf_reader = BufReader::new(file);
+
+        let _ = EventLogHeader::read_from(&mut buf_reader)?;
+
+        let mut checksummed = Checksummed::new(buf_reader);
+
+        let mut readable = 0_usize;
+        let mut previous: Option<PackedEvent> = None;
+
+        while readable < self.header.length() {
+            match bincode::deserialize_from::<_, PackedEvent>(&mut checksummed) {
+                Ok(event)
+                    if previous
+                        .as_ref()
+                        .map_or(true, |previous| previous <= &event) =>
+                {
+                    previous = Some(event);
+                    readable += 1;
+                }
+                _ => break,
+            }
+        }
+
+        // Any trailing bytes must also be included in the checksum
+        io::copy(&mut checksummed, &mut io::sink())?;
+
+        let (_, checksum) = checksummed.finish();
+
+        // Unversioned segments were written without a checksum
+        self.checksum_valid = self
+            .header
+            .checksum()
+            .map_or(true, |expected| expected == checksum);
+
+        let decoded = self.readable - self.unread;
+
+        self.buffer.truncate(readable);
+        self.readable = readable;
+        self.unread = readable.saturating_sub(decoded);
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     #[must_use]
     pub fn header(&self) -> &EventLogHeader {
         &self.header
     }
 
+    /// Number of events which can be replayed from this segment, which is
+    ///  only shorter than the `header`'s length if the segment has been
+    ///  validated and is corrupted
     #[must_use]
     pub fn length(&self) -> usize {
-        self.header.length()
+        self.readable
+    }
+
+    /// Checks if the segment has been found to be corrupted or truncated
+    ///  since it was written, in which case only its readable prefix is
+    ///  replayed. Segments are only checked when they are validated.
+    #[must_use]
+    pub fn is_corrupted(&self) -> bool {
+        !self.checksum_valid || self.readable < self.header.length()
     }
 }
 
@@ -72,9 +165,10 @@ impl Iterator for SortedSegment {
         let next_event = self.buffer.pop_front();
 
         if next_event.is_some() && self.buffer.is_empty() {
-            for _ in 0..self.capacity {
+            for _ in 0..self.capacity.min(self.unread) {
                 if let Ok(event) = bincode::deserialize_from(&mut self.reader) {
                     self.buffer.push_back(event);
+                    self.unread -= 1;
                 } else {
                     break;
                 }
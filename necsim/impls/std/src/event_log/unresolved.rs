@@ -0,0 +1,73 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+use anyhow::{Context, Error, Result};
+
+use necsim_core::lineage::UnresolvedLineage;
+
+/// File name of the unresolved lineages which are stored alongside the event
+///  log segments iff the simulation stopped before all lineages had been
+///  resolved
+pub const EVENT_LOG_UNRESOLVED_FILE_NAME: &str = "unresolved.bin";
+
+/// # Errors
+///
+/// Fails iff the unresolved `lineages` could not be written to `directory`.
+pub fn write_to_directory(directory: &Path, lineages: &[UnresolvedLineage]) -> Result<()> {
+    let path = directory.join(EVENT_LOG_UNRESOLVED_FILE_NAME);
+    let temporary_path = directory.join(format!(".{}.tmp", EVENT_LOG_UNRESOLVED_FILE_NAME));
+
+    // The unresolved lineages only appear under their final name once they
+    //  have been completely written
+    let result = write_lineages(&temporary_path, lineages)
+        .and_then(|()| fs::rename(&temporary_path, &path).map_err(Error::from))
+        .with_context(|| format!("Failed to write the unresolved lineages {:?}.", path));
+
+    if result.is_err() {
+        std::mem::drop(fs::remove_file(&temporary_path));
+    }
+
+    result
+}
+
+fn write_lineages(path: &Path, lineages: &[UnresolvedLineage]) -> Result<()> {
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(path)?;
+    let mut buf_writer = BufWriter::new(file);
+
+    bincode::serialize_into(&mut buf_writer, lineages)?;
+
+    buf_writer.into_inner()?.sync_all()?;
+
+    Ok(())
+}
+
+/// # Errors
+///
+/// Fails iff the unresolved lineages in `directory` exist but could not be
+///  read.
+pub fn try_read_from_directory(directory: &Path) -> Result<Vec<UnresolvedLineage>> {
+    let path = directory.join(EVENT_LOG_UNRESOLVED_FILE_NAME);
+
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let file = File::open(&path)
+        .with_context(|| format!("Failed to open the unresolved lineages {:?}.", path))?;
+
+    bincode::deserialize_from(BufReader::new(file))
+        .with_context(|| format!("Failed to read the unresolved lineages {:?}.", path))
+}
+
+#[must_use]
+pub(crate) fn is_event_log_unresolved_file(path: &Path) -> bool {
+    path.file_name()
+        .map_or(false, |name| name == EVENT_LOG_UNRESOLVED_FILE_NAME)
+}
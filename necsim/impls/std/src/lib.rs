@@ -6,5 +6,8 @@
 #[macro_use]
 extern crate contracts;
 
+#[macro_use]
+extern crate log;
+
 pub mod cogs;
 pub mod event_log;
@@ -0,0 +1,74 @@
+//! Diagnostics of the communication between partitions, e.g. to compare how
+//!  well different decompositions of the landscape balance a distributed
+//!  simulation.
+
+use alloc::{vec, vec::Vec};
+use core::{convert::TryFrom, num::NonZeroU32, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+/// Communication statistics which are collected by a single partition
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartitionDiagnostics {
+    /// Number of lineages which this partition has sent to each partition,
+    ///  indexed by their rank, including those resent after rollbacks
+    pub emigrations: Vec<u64>,
+    /// Number of times that this partition rolled back its simulation
+    pub rollbacks: u64,
+    /// Number of votes in which this partition took part
+    pub vote_rounds: u64,
+    /// Wall time which this partition spent waiting for the others to vote
+    pub idle_time: Duration,
+}
+
+impl PartitionDiagnostics {
+    #[must_use]
+    pub fn new(partitions: NonZeroU32) -> Self {
+        Self {
+            emigrations: vec![0; partitions.get() as usize],
+            rollbacks: 0,
+            vote_rounds: 0,
+            idle_time: Duration::ZERO,
+        }
+    }
+
+    pub fn record_emigration(&mut self, partition: u32) {
+        self.emigrations[partition as usize] += 1;
+    }
+
+    pub fn record_vote(&mut self, idle_time: Duration) {
+        self.vote_rounds += 1;
+        self.idle_time += idle_time;
+    }
+
+    /// Flattens the diagnostics into `partitions + 3` words, such that they
+    ///  can be gathered across partitions
+    #[must_use]
+    pub fn to_words(&self) -> Vec<u64> {
+        let mut words = self.emigrations.clone();
+
+        words.push(self.rollbacks);
+        words.push(self.vote_rounds);
+        words.push(u64::try_from(self.idle_time.as_nanos()).unwrap_or(u64::MAX));
+
+        words
+    }
+
+    /// Inverse of [`Self::to_words`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `words` contains fewer than three words.
+    #[must_use]
+    pub fn from_words(words: &[u64]) -> Self {
+        let (emigrations, totals) = words.split_at(words.len() - 3);
+
+        Self {
+            emigrations: emigrations.to_vec(),
+            rollbacks: totals[0],
+            vote_rounds: totals[1],
+            idle_time: Duration::from_nanos(totals[2]),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Cooperative interruption of a running simulation, e.g. after the process
+//!  has received a SIGINT or SIGTERM signal. The simulation stops at the next
+//!  point at which all partitions can agree to stop, such that the reporters
+//!  can still be finalised.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use necsim_core::reporter::Reporter;
+
+use crate::LocalPartition;
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Requests that all running simulations stop early.
+///
+/// This function is async-signal-safe and can be called from a signal
+///  handler.
+#[allow(clippy::module_name_repetitions)]
+pub fn request_interrupt() {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+/// Checks if an interrupt has been requested for this partition
+#[must_use]
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Checks if an interrupt has been requested for any partition. This
+///  function must be called collectively by all partitions.
+pub fn reduce_vote_interrupted<R: Reporter, L: LocalPartition<R>>(local_partition: &L) -> bool {
+    local_partition.reduce_vote_continue(is_interrupted())
+}
@@ -8,18 +8,22 @@ extern crate alloc;
 #[macro_use]
 extern crate contracts;
 
+use alloc::vec::Vec;
 use core::num::NonZeroU32;
 
 use necsim_core::{
     lineage::MigratingLineage,
-    reporter::{boolean::Boolean, Reporter},
+    reporter::{boolean::Boolean, Reporter, ReporterSummary},
 };
 use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 pub mod context;
+pub mod diagnostics;
+pub mod interrupt;
 pub mod iterator;
 
 use context::ReporterContext;
+use diagnostics::PartitionDiagnostics;
 
 #[allow(clippy::inline_always, clippy::inline_fn_without_body)]
 #[contract_trait]
@@ -101,5 +105,16 @@ pub trait LocalPartition<R: Reporter>: Sized {
 
     fn report_progress_sync(&mut self, remaining: u64);
 
-    fn finalise_reporting(self);
+    fn record_rollback(&mut self);
+
+    /// Gathers the diagnostics of all partitions, ordered by their rank, on
+    ///  the root partition. This function must be called collectively by
+    ///  all partitions.
+    fn gather_diagnostics(&self) -> Option<Vec<PartitionDiagnostics>>;
+
+    /// # Errors
+    ///
+    /// Fails iff any events could not be recorded, e.g. since writing to the
+    ///  event log failed.
+    fn finalise_reporting(self) -> anyhow::Result<ReporterSummary>;
 }
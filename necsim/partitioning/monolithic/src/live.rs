@@ -2,13 +2,13 @@ use std::{fmt, num::NonZeroU32};
 
 use necsim_core::{
     lineage::MigratingLineage,
-    reporter::{boolean::True, FilteredReporter, Reporter},
+    reporter::{boolean::True, FilteredReporter, Reporter, ReporterSummary},
 };
 use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 use necsim_partitioning_core::{
-    context::ReporterContext, iterator::ImmigrantPopIterator, LocalPartition, MigrationMode,
-    Partitioning,
+    context::ReporterContext, diagnostics::PartitionDiagnostics, iterator::ImmigrantPopIterator,
+    LocalPartition, MigrationMode, Partitioning,
 };
 
 #[allow(clippy::module_name_repetitions)]
@@ -132,8 +132,19 @@ impl<R: Reporter> LocalPartition<R> for LiveMonolithicLocalPartition<R> {
         self.reporter.report_progress(&remaining.into());
     }
 
-    fn finalise_reporting(self) {
-        self.reporter.finalise();
+    fn record_rollback(&mut self) {
+        // A monolithic partition never has to roll back
+    }
+
+    fn gather_diagnostics(&self) -> Option<Vec<PartitionDiagnostics>> {
+        // A monolithic partition does not communicate with any other partition
+        Some(vec![PartitionDiagnostics::new(
+            self.get_number_of_partitions(),
+        )])
+    }
+
+    fn finalise_reporting(self) -> anyhow::Result<ReporterSummary> {
+        Ok(self.reporter.finalise())
     }
 }
 
@@ -4,10 +4,10 @@ use anyhow::Result;
 
 use necsim_core::{
     impl_report,
-    lineage::MigratingLineage,
+    lineage::{MigratingLineage, UnresolvedLineage},
     reporter::{
         boolean::{Boolean, False, True},
-        FilteredReporter, Reporter,
+        FilteredReporter, Reporter, ReporterSummary,
     },
 };
 use necsim_core_bond::{NonNegativeF64, PositiveF64};
@@ -15,7 +15,8 @@ use necsim_core_bond::{NonNegativeF64, PositiveF64};
 use necsim_impls_std::event_log::recorder::EventLogRecorder;
 
 use necsim_partitioning_core::{
-    context::ReporterContext, iterator::ImmigrantPopIterator, LocalPartition, MigrationMode,
+    context::ReporterContext, diagnostics::PartitionDiagnostics, iterator::ImmigrantPopIterator,
+    LocalPartition, MigrationMode,
 };
 
 #[allow(clippy::module_name_repetitions)]
@@ -102,8 +103,24 @@ impl<R: Reporter> LocalPartition<R> for RecordedMonolithicLocalPartition<R> {
         self.reporter.report_progress(&remaining.into());
     }
 
-    fn finalise_reporting(self) {
-        self.reporter.finalise();
+    fn record_rollback(&mut self) {
+        // A monolithic partition never has to roll back
+    }
+
+    fn gather_diagnostics(&self) -> Option<Vec<PartitionDiagnostics>> {
+        // A monolithic partition does not communicate with any other partition
+        Some(vec![PartitionDiagnostics::new(
+            self.get_number_of_partitions(),
+        )])
+    }
+
+    fn finalise_reporting(mut self) -> anyhow::Result<ReporterSummary> {
+        // The reporter is finalised even if the event log cannot be finished
+        let summary = self.reporter.finalise();
+
+        self.recorder.finish()?;
+
+        Ok(summary)
     }
 }
 
@@ -137,4 +154,8 @@ impl<R: Reporter> Reporter for RecordedMonolithicLocalPartition<R> {
     impl_report!(progress(&mut self, progress: MaybeUsed<R::ReportProgress>) {
         self.reporter.report_progress(progress.into());
     });
+
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        self.recorder.record_unresolved(lineage);
+    }
 }
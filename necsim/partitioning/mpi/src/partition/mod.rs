@@ -2,12 +2,15 @@ use std::num::NonZeroU32;
 
 use necsim_core::{
     impl_report,
-    lineage::MigratingLineage,
-    reporter::{boolean::True, Reporter},
+    lineage::{MigratingLineage, UnresolvedLineage},
+    reporter::{boolean::True, Reporter, ReporterSummary},
 };
 use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
-use necsim_partitioning_core::{iterator::ImmigrantPopIterator, LocalPartition, MigrationMode};
+use necsim_partitioning_core::{
+    diagnostics::PartitionDiagnostics, iterator::ImmigrantPopIterator, LocalPartition,
+    MigrationMode,
+};
 use necsim_partitioning_monolithic::{
     live::LiveMonolithicLocalPartition, recorded::RecordedMonolithicLocalPartition,
 };
@@ -144,7 +147,25 @@ impl<R: Reporter> LocalPartition<R> for MpiLocalPartition<R> {
         }
     }
 
-    fn finalise_reporting(self) {
+    fn record_rollback(&mut self) {
+        match self {
+            Self::LiveMonolithic(partition) => partition.record_rollback(),
+            Self::RecordedMonolithic(partition) => partition.record_rollback(),
+            Self::Root(partition) => partition.record_rollback(),
+            Self::Parallel(partition) => partition.record_rollback(),
+        }
+    }
+
+    fn gather_diagnostics(&self) -> Option<Vec<PartitionDiagnostics>> {
+        match self {
+            Self::LiveMonolithic(partition) => partition.gather_diagnostics(),
+            Self::RecordedMonolithic(partition) => partition.gather_diagnostics(),
+            Self::Root(partition) => partition.gather_diagnostics(),
+            Self::Parallel(partition) => partition.gather_diagnostics(),
+        }
+    }
+
+    fn finalise_reporting(self) -> anyhow::Result<ReporterSummary> {
         match self {
             Self::LiveMonolithic(partition) => partition.finalise_reporting(),
             Self::RecordedMonolithic(partition) => partition.finalise_reporting(),
@@ -205,4 +226,15 @@ impl<R: Reporter> Reporter for MpiLocalPartition<R> {
             ),
         }
     });
+
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        match self {
+            Self::LiveMonolithic(partition) => partition.get_reporter().report_unresolved(lineage),
+            Self::RecordedMonolithic(partition) => {
+                partition.get_reporter().report_unresolved(lineage);
+            },
+            Self::Root(partition) => partition.get_reporter().report_unresolved(lineage),
+            Self::Parallel(partition) => partition.get_reporter().report_unresolved(lineage),
+        }
+    }
 }
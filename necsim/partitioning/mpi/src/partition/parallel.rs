@@ -1,4 +1,5 @@
 use std::{
+    cell::RefCell,
     fmt,
     marker::PhantomData,
     num::NonZeroU32,
@@ -16,16 +17,19 @@ use mpi::{
 
 use necsim_core::{
     impl_report,
-    lineage::MigratingLineage,
+    lineage::{MigratingLineage, UnresolvedLineage},
     reporter::{
         boolean::{Boolean, False},
-        Reporter,
+        Reporter, ReporterSummary,
     },
 };
 use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 use necsim_impls_std::event_log::recorder::EventLogRecorder;
-use necsim_partitioning_core::{iterator::ImmigrantPopIterator, LocalPartition, MigrationMode};
+use necsim_partitioning_core::{
+    diagnostics::PartitionDiagnostics, iterator::ImmigrantPopIterator, LocalPartition,
+    MigrationMode,
+};
 
 use crate::MpiPartitioning;
 
@@ -46,6 +50,7 @@ pub struct MpiParallelPartition<R: Reporter> {
     emigration_requests: Box<[Option<Request<'static, StaticScope>>]>,
     barrier: Option<Request<'static, StaticScope>>,
     communicated_since_last_barrier: bool,
+    diagnostics: RefCell<PartitionDiagnostics>,
     recorder: EventLogRecorder,
     _marker: PhantomData<R>,
 }
@@ -89,6 +94,9 @@ impl<R: Reporter> MpiParallelPartition<R> {
         #[allow(clippy::cast_sign_loss)]
         let world_size = world.size() as usize;
 
+        #[allow(clippy::cast_sign_loss)]
+        let diagnostics = PartitionDiagnostics::new(NonZeroU32::new(world.size() as u32).unwrap());
+
         let mut mpi_migration_buffers = Vec::with_capacity(world_size);
         mpi_migration_buffers.resize_with(world_size, Vec::new);
 
@@ -114,6 +122,7 @@ impl<R: Reporter> MpiParallelPartition<R> {
             emigration_requests: emigration_requests.into_boxed_slice(),
             barrier: None,
             communicated_since_last_barrier: false,
+            diagnostics: RefCell::new(diagnostics),
             recorder,
             _marker: PhantomData::<R>,
         }
@@ -153,6 +162,7 @@ impl<R: Reporter> LocalPartition<R> for MpiParallelPartition<R> {
         immigration_mode: MigrationMode,
     ) -> Self::ImmigrantIterator<'_> {
         for (partition, emigrant) in emigrants {
+            self.diagnostics.get_mut().record_emigration(partition);
             self.migration_buffers[partition as usize].push(emigrant);
         }
 
@@ -253,12 +263,18 @@ impl<R: Reporter> LocalPartition<R> for MpiParallelPartition<R> {
     fn reduce_vote_continue(&self, local_continue: bool) -> bool {
         let mut global_continue = local_continue;
 
+        let vote_start = Instant::now();
+
         self.world.all_reduce_into(
             &local_continue,
             &mut global_continue,
             SystemOperation::logical_or(),
         );
 
+        self.diagnostics
+            .borrow_mut()
+            .record_vote(vote_start.elapsed());
+
         global_continue
     }
 
@@ -269,6 +285,8 @@ impl<R: Reporter> LocalPartition<R> for MpiParallelPartition<R> {
         let local_time_partition = TimePartition(local_time, self.get_partition_rank());
         let mut global_min_time_partition = local_time_partition;
 
+        let vote_start = Instant::now();
+
         self.world.all_reduce_into(
             &local_time_partition,
             &mut global_min_time_partition,
@@ -285,6 +303,10 @@ impl<R: Reporter> LocalPartition<R> for MpiParallelPartition<R> {
             }),
         );
 
+        self.diagnostics
+            .borrow_mut()
+            .record_vote(vote_start.elapsed());
+
         if global_min_time_partition.1 == local_time_partition.1 {
             Ok(local_time)
         } else {
@@ -370,8 +392,31 @@ impl<R: Reporter> LocalPartition<R> for MpiParallelPartition<R> {
         root_process.gather_into(&remaining);
     }
 
-    fn finalise_reporting(self) {
+    fn record_rollback(&mut self) {
+        self.diagnostics.get_mut().rollbacks += 1;
+    }
+
+    fn gather_diagnostics(&self) -> Option<Vec<PartitionDiagnostics>> {
+        let local_words = self.diagnostics.borrow().to_words();
+
+        let root_process = self.world.process_at_rank(MpiPartitioning::ROOT_RANK);
+
+        root_process.gather_into(&local_words[..]);
+
+        // Only the root partition receives the diagnostics of all partitions
+        None
+    }
+
+    fn finalise_reporting(mut self) -> anyhow::Result<ReporterSummary> {
+        // The partition is torn down even if the event log cannot be finished
+        let finished = self.recorder.finish();
+
         std::mem::drop(self);
+
+        finished?;
+
+        // The events of parallel partitions are only reported during replay
+        Ok(ReporterSummary::default())
     }
 }
 
@@ -414,4 +459,8 @@ impl<R: Reporter> Reporter for MpiParallelPartition<R> {
             }
         }
     });
+
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        self.recorder.record_unresolved(lineage);
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    cell::RefCell,
     fmt,
     mem::ManuallyDrop,
     num::{NonZeroU32, Wrapping},
@@ -16,16 +17,19 @@ use mpi::{
 
 use necsim_core::{
     impl_report,
-    lineage::MigratingLineage,
+    lineage::{MigratingLineage, UnresolvedLineage},
     reporter::{
         boolean::{Boolean, False, True},
-        FilteredReporter, Reporter,
+        FilteredReporter, Reporter, ReporterSummary,
     },
 };
 use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 use necsim_impls_std::event_log::recorder::EventLogRecorder;
-use necsim_partitioning_core::{iterator::ImmigrantPopIterator, LocalPartition, MigrationMode};
+use necsim_partitioning_core::{
+    diagnostics::PartitionDiagnostics, iterator::ImmigrantPopIterator, LocalPartition,
+    MigrationMode,
+};
 
 use crate::MpiPartitioning;
 
@@ -46,6 +50,7 @@ pub struct MpiRootPartition<R: Reporter> {
     recorder: EventLogRecorder,
     barrier: Option<Request<'static, StaticScope>>,
     communicated_since_last_barrier: bool,
+    diagnostics: RefCell<PartitionDiagnostics>,
     finalised: bool,
 }
 
@@ -57,9 +62,8 @@ impl<R: Reporter> fmt::Debug for MpiRootPartition<R> {
 
 impl<R: Reporter> Drop for MpiRootPartition<R> {
     fn drop(&mut self) {
-        if self.finalised {
-            unsafe { ManuallyDrop::take(&mut self.reporter) }.finalise();
-        } else {
+        // A finalised reporter has already been taken out
+        if !self.finalised {
             unsafe {
                 ManuallyDrop::drop(&mut self.reporter);
             }
@@ -93,6 +97,9 @@ impl<R: Reporter> MpiRootPartition<R> {
         #[allow(clippy::cast_sign_loss)]
         let world_size = world.size() as usize;
 
+        #[allow(clippy::cast_sign_loss)]
+        let diagnostics = PartitionDiagnostics::new(NonZeroU32::new(world.size() as u32).unwrap());
+
         let mut mpi_migration_buffers = Vec::with_capacity(world_size);
         mpi_migration_buffers.resize_with(world_size, Vec::new);
 
@@ -120,6 +127,7 @@ impl<R: Reporter> MpiRootPartition<R> {
             recorder,
             barrier: None,
             communicated_since_last_barrier: false,
+            diagnostics: RefCell::new(diagnostics),
             finalised: false,
         }
     }
@@ -158,6 +166,7 @@ impl<R: Reporter> LocalPartition<R> for MpiRootPartition<R> {
         immigration_mode: MigrationMode,
     ) -> Self::ImmigrantIterator<'_> {
         for (partition, emigrant) in emigrants {
+            self.diagnostics.get_mut().record_emigration(partition);
             self.migration_buffers[partition as usize].push(emigrant);
         }
 
@@ -258,12 +267,18 @@ impl<R: Reporter> LocalPartition<R> for MpiRootPartition<R> {
     fn reduce_vote_continue(&self, local_continue: bool) -> bool {
         let mut global_continue = local_continue;
 
+        let vote_start = Instant::now();
+
         self.world.all_reduce_into(
             &local_continue,
             &mut global_continue,
             SystemOperation::logical_or(),
         );
 
+        self.diagnostics
+            .borrow_mut()
+            .record_vote(vote_start.elapsed());
+
         global_continue
     }
 
@@ -274,6 +289,8 @@ impl<R: Reporter> LocalPartition<R> for MpiRootPartition<R> {
         let local_time_partition = TimePartition(local_time, self.get_partition_rank());
         let mut global_min_time_partition = local_time_partition;
 
+        let vote_start = Instant::now();
+
         self.world.all_reduce_into(
             &local_time_partition,
             &mut global_min_time_partition,
@@ -290,6 +307,10 @@ impl<R: Reporter> LocalPartition<R> for MpiRootPartition<R> {
             }),
         );
 
+        self.diagnostics
+            .borrow_mut()
+            .record_vote(vote_start.elapsed());
+
         if global_min_time_partition == local_time_partition {
             Ok(local_time)
         } else {
@@ -392,10 +413,40 @@ impl<R: Reporter> LocalPartition<R> for MpiRootPartition<R> {
         );
     }
 
-    fn finalise_reporting(mut self) {
+    fn record_rollback(&mut self) {
+        self.diagnostics.get_mut().rollbacks += 1;
+    }
+
+    fn gather_diagnostics(&self) -> Option<Vec<PartitionDiagnostics>> {
+        let local_words = self.diagnostics.borrow().to_words();
+        #[allow(clippy::cast_sign_loss)]
+        let mut all_words = vec![0_u64; local_words.len() * (self.world.size() as usize)];
+
+        let root_process = self.world.process_at_rank(MpiPartitioning::ROOT_RANK);
+
+        root_process.gather_into_root(&local_words[..], &mut all_words[..]);
+
+        Some(
+            all_words
+                .chunks(local_words.len())
+                .map(PartitionDiagnostics::from_words)
+                .collect(),
+        )
+    }
+
+    fn finalise_reporting(mut self) -> anyhow::Result<ReporterSummary> {
         self.finalised = true;
 
+        // The reporter is finalised even if the event log cannot be finished
+        let summary = unsafe { ManuallyDrop::take(&mut self.reporter) }.finalise();
+
+        let finished = self.recorder.finish();
+
         std::mem::drop(self);
+
+        finished?;
+
+        Ok(summary)
     }
 }
 
@@ -437,4 +488,8 @@ impl<R: Reporter> Reporter for MpiRootPartition<R> {
             );
         }
     });
+
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        self.recorder.record_unresolved(lineage);
+    }
 }
@@ -1,18 +1,25 @@
 use std::fmt;
 
-use necsim_core::{event::SpeciationEvent, impl_finalise, impl_report, reporter::Reporter};
+use necsim_core::{
+    event::SpeciationEvent,
+    impl_finalise, impl_report,
+    lineage::UnresolvedLineage,
+    reporter::{Reporter, ReporterSummary},
+};
 
 #[allow(clippy::module_name_repetitions)]
 pub struct BiodiversityReporter {
     last_event: Option<SpeciationEvent>,
 
     biodiversity: usize,
+    unresolved: usize,
 }
 
 impl fmt::Debug for BiodiversityReporter {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct("BiodiversityReporter")
             .field("biodiversity", &self.biodiversity)
+            .field("unresolved", &self.unresolved)
             .finish()
     }
 }
@@ -39,21 +46,40 @@ impl Reporter for BiodiversityReporter {
     impl_report!(progress(&mut self, _progress: Ignored) {});
 
     impl_finalise!((self) {
-        if self.biodiversity > 0 {
+        if self.unresolved > 0 {
+            warn!(
+                "The simulation stopped before {} lineages were resolved, which are counted as \
+                 distinct ancestral species such that the biodiversity is incomplete.",
+                self.unresolved
+            );
+        }
+
+        if self.biodiversity > 0 || self.unresolved > 0 {
             info!(
                 "The simulation resulted in a biodiversity of {} unique species.",
-                self.biodiversity
+                self.biodiversity + self.unresolved
             );
         }
+
+        ReporterSummary::new()
+            .with("biodiversity", self.biodiversity + self.unresolved)
+            .with("unresolved", self.unresolved)
     });
+
+    // Every unresolved lineage is treated as a distinct ancestral species
+    fn report_unresolved(&mut self, _lineage: &UnresolvedLineage) {
+        self.unresolved += 1;
+    }
 }
 
 impl Default for BiodiversityReporter {
     #[debug_ensures(ret.biodiversity == 0, "biodiversity initialised to 0")]
+    #[debug_ensures(ret.unresolved == 0, "unresolved initialised to 0")]
     fn default() -> Self {
         Self {
             last_event: None,
             biodiversity: 0,
+            unresolved: 0,
         }
     }
 }
@@ -3,7 +3,7 @@ use std::{fmt, fmt::Write};
 use necsim_core::{
     event::{DispersalEvent, LineageInteraction, SpeciationEvent},
     impl_finalise, impl_report,
-    reporter::Reporter,
+    reporter::{Reporter, ReporterSummary},
 };
 use necsim_core_bond::NonNegativeF64;
 
@@ -104,7 +104,7 @@ impl Reporter for EventCounterReporter {
 
     impl_finalise!((self) {
         if self.last_speciation_event.is_none() && self.last_dispersal_event.is_none() {
-            return;
+            return ReporterSummary::default();
         }
 
         let mut event_summary = String::new();
@@ -154,6 +154,15 @@ impl Reporter for EventCounterReporter {
         );
 
         log::info!("{}", event_summary);
+
+        ReporterSummary::new()
+            .with("speciation", self.speciation)
+            .with("out_dispersal", self.out_dispersal)
+            .with("self_dispersal", self.self_dispersal)
+            .with("out_coalescence", self.out_coalescence)
+            .with("self_coalescence", self.self_coalescence)
+            .with("late_dispersal", self.late_dispersal)
+            .with("late_coalescence", self.late_coalescence)
     });
 }
 
@@ -1,6 +1,9 @@
 use std::{fmt, time::Instant};
 
-use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
+use necsim_core::{
+    impl_finalise, impl_report,
+    reporter::{Reporter, ReporterSummary},
+};
 
 #[allow(clippy::module_name_repetitions)]
 pub struct ExecutionTimeReporter {
@@ -56,13 +59,22 @@ impl Reporter for ExecutionTimeReporter {
 
     impl_finalise!((self) {
         if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            let cleanup_time = end_time.elapsed();
+
             info!(
                 "The simulation took:\n - initialisation: {:?}\n - execution: {:?}\n - \
                 cleanup: {:?}",
                 (start_time - self.init_time),
                 (end_time - start_time),
-                end_time.elapsed()
+                cleanup_time
             );
+
+            ReporterSummary::new()
+                .with("initialisation", (start_time - self.init_time).as_secs_f64())
+                .with("execution", (end_time - start_time).as_secs_f64())
+                .with("cleanup", cleanup_time.as_secs_f64())
+        } else {
+            ReporterSummary::default()
         }
     });
 
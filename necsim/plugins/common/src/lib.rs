@@ -9,6 +9,7 @@ extern crate log;
 pub mod biodiversity;
 pub mod event_counter;
 pub mod execution_time;
+pub mod phylogeny;
 pub mod progress;
 pub mod verbose;
 
@@ -18,4 +19,5 @@ necsim_plugins_core::export_plugin!(
     Execution => execution_time::ExecutionTimeReporter,
     Counter => event_counter::EventCounterReporter,
     Verbose => verbose::VerboseReporter,
+    Phylogeny => phylogeny::PhylogenyReporter,
 );
@@ -0,0 +1,313 @@
+use std::{collections::HashMap, fmt};
+
+use necsim_core::{
+    event::{DispersalEvent, LineageInteraction, SpeciationEvent},
+    impl_finalise, impl_report,
+    lineage::{GlobalLineageReference, UnresolvedLineage},
+    reporter::{Reporter, ReporterSummary},
+};
+use necsim_core_bond::NonNegativeF64;
+
+#[cfg(test)]
+mod test;
+
+/// Reconstructs the genealogy of the sampled individuals, in which every
+///  lineage ends when it coalesces into another lineage or speciates, and
+///  reports its phylogenetic diversity (Faith's PD), mean pairwise distance,
+///  time to the most recent common ancestor, and coalescence times.
+///
+/// Lineages which are still unresolved when the simulation stops early are
+///  kept as open branches which end at their last event, such that the
+///  genealogy is incomplete.
+#[allow(clippy::module_name_repetitions)]
+pub struct PhylogenyReporter {
+    last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
+    last_speciation_event: Option<SpeciationEvent>,
+    last_dispersal_event: Option<DispersalEvent>,
+
+    // (time, child, parent) of every coalescence of a child into its parent
+    coalescences: Vec<(
+        NonNegativeF64,
+        GlobalLineageReference,
+        GlobalLineageReference,
+    )>,
+    speciations: Vec<NonNegativeF64>,
+    // last event time of every lineage which was still unresolved
+    unresolved: Vec<NonNegativeF64>,
+}
+
+impl fmt::Debug for PhylogenyReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("PhylogenyReporter")
+            .field("coalescences", &self.coalescences.len())
+            .field("speciations", &self.speciations.len())
+            .field("unresolved", &self.unresolved.len())
+            .finish()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PhylogenyReporter {
+    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::default())
+    }
+}
+
+impl Reporter for PhylogenyReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        if Some(speciation) == self.last_speciation_event.as_ref() {
+            self.report_duplicate_event(&speciation.global_lineage_reference, speciation.prior_time);
+
+            return;
+        }
+
+        self.last_speciation_event = Some(speciation.clone());
+        self.last_parent_prior_time = Some(
+            (speciation.global_lineage_reference.clone(), speciation.prior_time)
+        );
+
+        self.speciations.push(speciation.event_time.into());
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        if Some(dispersal) == self.last_dispersal_event.as_ref() {
+            self.report_duplicate_event(&dispersal.global_lineage_reference, dispersal.prior_time);
+
+            return;
+        }
+
+        self.last_dispersal_event = Some(dispersal.clone());
+        self.last_parent_prior_time = Some(
+            (dispersal.global_lineage_reference.clone(), dispersal.prior_time)
+        );
+
+        if let LineageInteraction::Coalescence(parent) = &dispersal.interaction {
+            self.coalescences.push((
+                dispersal.event_time.into(),
+                dispersal.global_lineage_reference.clone(),
+                parent.clone(),
+            ));
+        }
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((mut self) {
+        self.coalescences.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let lineages = self.coalescences.len() + self.speciations.len() + self.unresolved.len();
+
+        if lineages == 0 {
+            return ReporterSummary::new();
+        }
+
+        let mut genealogy = Genealogy::default();
+
+        // Faith's PD is the total length of all lineages' branches, which
+        //  start at time zero and end at the coalescence or speciation, or
+        //  at the last event of an unresolved lineage
+        let mut phylogenetic_diversity = 0.0_f64;
+
+        // All pairs of individuals whose lineages are joined by a coalescence
+        //  have their most recent common ancestor at the coalescence time
+        let mut pairwise_distance_sum = 0.0_f64;
+        let mut pairs = 0_u128;
+
+        for (time, child, parent) in &self.coalescences {
+            let joined_pairs = genealogy.merge(child, parent);
+
+            phylogenetic_diversity += time.get();
+
+            #[allow(clippy::cast_precision_loss)]
+            {
+                pairwise_distance_sum += 2.0_f64 * time.get() * (joined_pairs as f64);
+            }
+            pairs += joined_pairs;
+        }
+
+        phylogenetic_diversity += self.speciations.iter().map(|time| time.get()).sum::<f64>();
+        phylogenetic_diversity += self.unresolved.iter().map(|time| time.get()).sum::<f64>();
+
+        if !self.unresolved.is_empty() {
+            warn!(
+                "The simulation stopped before {} lineages were resolved, which are kept as open \
+                 branches such that the genealogy is incomplete.",
+                self.unresolved.len()
+            );
+        }
+
+        info!(
+            "The genealogy of {} individual(s) has a phylogenetic diversity of {}.",
+            lineages, phylogenetic_diversity
+        );
+
+        let mut summary = ReporterSummary::new()
+            .with("individuals", lineages)
+            .with("phylogenetic_diversity", phylogenetic_diversity)
+            .with("unresolved", self.unresolved.len());
+
+        if pairs > 0 {
+            #[allow(clippy::cast_precision_loss)]
+            let mean_pairwise_distance = pairwise_distance_sum / (pairs as f64);
+
+            info!(
+                "The {} pair(s) of individuals with a common ancestor have a mean pairwise \
+                 distance of {}.",
+                pairs, mean_pairwise_distance
+            );
+
+            summary = summary.with("mean_pairwise_distance", mean_pairwise_distance);
+        }
+
+        // The sample only has a common ancestor if all lineages coalesce
+        //  before the single speciation event or into a single unresolved
+        //  lineage
+        if self.speciations.len() + self.unresolved.len() == 1 {
+            let tmrca = self.coalescences.last().map_or(0.0_f64, |(time, _, _)| time.get());
+
+            info!("The most recent common ancestor of the sample lived at time {}.", tmrca);
+
+            summary = summary.with("tmrca", tmrca);
+        } else if self.unresolved.is_empty() {
+            info!(
+                "The sample has no common ancestor as it descends from {} speciation events.",
+                self.speciations.len()
+            );
+        } else {
+            info!(
+                "The sample has no known common ancestor as it descends from {} speciation \
+                 events and {} unresolved lineages.",
+                self.speciations.len(),
+                self.unresolved.len()
+            );
+        }
+
+        if !self.coalescences.is_empty() {
+            let times = self.coalescences.iter().map(|(time, _, _)| time.get()).collect::<Vec<_>>();
+
+            #[allow(clippy::cast_precision_loss)]
+            let mean = times.iter().sum::<f64>() / (times.len() as f64);
+
+            summary = summary.with(
+                "coalescence_times",
+                ReporterSummary::new()
+                    .with("count", times.len())
+                    .with("mean", mean)
+                    .with("min", quantile(&times, 0.0_f64))
+                    .with("q25", quantile(&times, 0.25_f64))
+                    .with("median", quantile(&times, 0.5_f64))
+                    .with("q75", quantile(&times, 0.75_f64))
+                    .with("max", quantile(&times, 1.0_f64)),
+            );
+        }
+
+        summary
+    });
+
+    // Every unresolved lineage is kept as an open branch
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        self.unresolved.push(lineage.last_event_time);
+    }
+}
+
+impl PhylogenyReporter {
+    fn report_duplicate_event(
+        &mut self,
+        lineage: &GlobalLineageReference,
+        prior_time: NonNegativeF64,
+    ) {
+        // A repeated event with a different prior time stems from a lineage
+        //  which has coalesced with the lineage of the original event
+        if let Some((parent, parent_prior_time)) = &self.last_parent_prior_time {
+            if parent_prior_time != &prior_time {
+                // Both lineages are identical once the later of them arrived
+                self.coalescences.push((
+                    (*parent_prior_time).max(prior_time),
+                    lineage.clone(),
+                    parent.clone(),
+                ));
+            }
+        }
+
+        self.last_parent_prior_time = Some((lineage.clone(), prior_time));
+    }
+}
+
+impl Default for PhylogenyReporter {
+    #[debug_ensures(
+        ret.coalescences.is_empty() && ret.speciations.is_empty() && ret.unresolved.is_empty(),
+        "genealogy initialised to be empty"
+    )]
+    fn default() -> Self {
+        Self {
+            last_parent_prior_time: None,
+            last_speciation_event: None,
+            last_dispersal_event: None,
+
+            coalescences: Vec::new(),
+            speciations: Vec::new(),
+            unresolved: Vec::new(),
+        }
+    }
+}
+
+/// Union-find forest of the clades of the genealogy
+#[derive(Default)]
+struct Genealogy {
+    ancestors: HashMap<GlobalLineageReference, GlobalLineageReference>,
+    clade_sizes: HashMap<GlobalLineageReference, u64>,
+}
+
+impl Genealogy {
+    fn root(&mut self, lineage: &GlobalLineageReference) -> GlobalLineageReference {
+        let mut root = lineage.clone();
+
+        while let Some(ancestor) = self.ancestors.get(&root) {
+            root = ancestor.clone();
+        }
+
+        // Compress the path to the root of the clade
+        let mut lineage = lineage.clone();
+
+        while lineage != root {
+            match self.ancestors.insert(lineage, root.clone()) {
+                Some(ancestor) => lineage = ancestor,
+                None => break,
+            }
+        }
+
+        root
+    }
+
+    /// Merges the clade of the `child` into the clade of the `parent` and
+    ///  returns the number of newly joined pairs of individuals
+    fn merge(&mut self, child: &GlobalLineageReference, parent: &GlobalLineageReference) -> u128 {
+        let child = self.root(child);
+        let parent = self.root(parent);
+
+        if child == parent {
+            return 0;
+        }
+
+        let child_size = self.clade_sizes.remove(&child).unwrap_or(1);
+        let parent_size = self.clade_sizes.entry(parent.clone()).or_insert(1);
+
+        let joined_pairs = u128::from(child_size) * u128::from(*parent_size);
+
+        *parent_size += child_size;
+        self.ancestors.insert(child, parent);
+
+        joined_pairs
+    }
+}
+
+/// Nearest-rank quantile of the sorted `values`
+fn quantile(values: &[f64], q: f64) -> f64 {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let index = (((values.len() - 1) as f64) * q).round() as usize;
+
+    values[index]
+}
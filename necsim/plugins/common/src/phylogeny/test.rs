@@ -0,0 +1,190 @@
+use serde::{
+    de::{value::Error, IntoDeserializer},
+    Deserialize,
+};
+
+use necsim_core::{
+    event::{DispersalEvent, LineageInteraction, SpeciationEvent},
+    landscape::{IndexedLocation, Location},
+    lineage::{GlobalLineageReference, UnresolvedLineage},
+    reporter::{Reporter, SummaryValue},
+};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use super::{Genealogy, PhylogenyReporter};
+
+fn lineage(id: u64) -> GlobalLineageReference {
+    GlobalLineageReference::deserialize(IntoDeserializer::<Error>::into_deserializer(id)).unwrap()
+}
+
+fn location(x: u32) -> IndexedLocation {
+    IndexedLocation::new(Location::new(x, 0), 0)
+}
+
+fn dispersal(
+    id: u64,
+    prior_time: f64,
+    event_time: f64,
+    interaction: LineageInteraction,
+) -> DispersalEvent {
+    DispersalEvent {
+        origin: location(0),
+        prior_time: NonNegativeF64::new(prior_time).unwrap(),
+        event_time: PositiveF64::new(event_time).unwrap(),
+        global_lineage_reference: lineage(id),
+        target: location(1),
+        interaction,
+    }
+}
+
+fn speciation(id: u64, prior_time: f64, event_time: f64) -> SpeciationEvent {
+    SpeciationEvent {
+        origin: location(1),
+        prior_time: NonNegativeF64::new(prior_time).unwrap(),
+        event_time: PositiveF64::new(event_time).unwrap(),
+        global_lineage_reference: lineage(id),
+    }
+}
+
+fn unresolved(id: u64, last_event_time: f64) -> UnresolvedLineage {
+    UnresolvedLineage {
+        global_reference: lineage(id),
+        location: Location::new(0, 0),
+        last_event_time: NonNegativeF64::new(last_event_time).unwrap(),
+    }
+}
+
+#[test]
+fn test_genealogy_merge() {
+    let mut genealogy = Genealogy::default();
+
+    assert_eq!(genealogy.merge(&lineage(0), &lineage(1)), 1);
+    assert_eq!(genealogy.merge(&lineage(2), &lineage(3)), 1);
+    // Merging two clades of two individuals joins four pairs
+    assert_eq!(genealogy.merge(&lineage(1), &lineage(3)), 4);
+    // Lineages from the same clade are already joined
+    assert_eq!(genealogy.merge(&lineage(0), &lineage(2)), 0);
+    assert_eq!(genealogy.merge(&lineage(4), &lineage(0)), 4);
+
+    assert_eq!(genealogy.root(&lineage(0)), genealogy.root(&lineage(4)));
+}
+
+#[test]
+fn test_coalescences_and_speciation() {
+    let mut reporter = PhylogenyReporter::default();
+
+    reporter.report_dispersal(
+        (&dispersal(0, 0.0, 1.0, LineageInteraction::Coalescence(lineage(1)))).into(),
+    );
+    reporter.report_dispersal(
+        (&dispersal(2, 0.0, 2.0, LineageInteraction::Coalescence(lineage(1)))).into(),
+    );
+    reporter.report_speciation((&speciation(1, 0.0, 3.0)).into());
+
+    let summary = reporter.finalise();
+
+    assert_eq!(summary.get("individuals"), Some(&SummaryValue::Unsigned(3)));
+    assert_eq!(
+        summary.get("phylogenetic_diversity"),
+        Some(&SummaryValue::Float(1.0 + 2.0 + 3.0))
+    );
+    // One pair joined at time 1.0 and two pairs joined at time 2.0
+    assert_eq!(
+        summary.get("mean_pairwise_distance"),
+        Some(&SummaryValue::Float((2.0 * 1.0 + 2.0 * 2.0 * 2.0) / 3.0))
+    );
+    assert_eq!(summary.get("tmrca"), Some(&SummaryValue::Float(2.0)));
+    assert_eq!(summary.get("unresolved"), Some(&SummaryValue::Unsigned(0)));
+
+    let coalescence_times = match summary.get("coalescence_times") {
+        Some(SummaryValue::Summary(coalescence_times)) => coalescence_times,
+        other => panic!("unexpected coalescence times {:?}", other),
+    };
+
+    assert_eq!(
+        coalescence_times.get("count"),
+        Some(&SummaryValue::Unsigned(2))
+    );
+    assert_eq!(
+        coalescence_times.get("mean"),
+        Some(&SummaryValue::Float(1.5))
+    );
+    assert_eq!(
+        coalescence_times.get("min"),
+        Some(&SummaryValue::Float(1.0))
+    );
+    assert_eq!(
+        coalescence_times.get("max"),
+        Some(&SummaryValue::Float(2.0))
+    );
+}
+
+#[test]
+fn test_duplicate_events() {
+    let mut reporter = PhylogenyReporter::default();
+
+    reporter.report_dispersal((&dispersal(0, 0.5, 1.0, LineageInteraction::None)).into());
+    // A duplicate event with a different prior time coalesces the lineages
+    //  once the later of them arrived
+    reporter.report_dispersal((&dispersal(1, 0.25, 1.0, LineageInteraction::None)).into());
+    reporter.report_speciation((&speciation(0, 1.0, 2.0)).into());
+    // A duplicate event with the same prior time stems from an already
+    //  coalesced lineage
+    reporter.report_speciation((&speciation(1, 1.0, 2.0)).into());
+
+    assert_eq!(reporter.coalescences.len(), 1);
+    assert_eq!(reporter.speciations.len(), 1);
+
+    let summary = reporter.finalise();
+
+    assert_eq!(summary.get("individuals"), Some(&SummaryValue::Unsigned(2)));
+    assert_eq!(
+        summary.get("phylogenetic_diversity"),
+        Some(&SummaryValue::Float(0.5 + 2.0))
+    );
+    assert_eq!(
+        summary.get("mean_pairwise_distance"),
+        Some(&SummaryValue::Float(2.0 * 0.5))
+    );
+    assert_eq!(summary.get("tmrca"), Some(&SummaryValue::Float(0.5)));
+}
+
+#[test]
+fn test_unresolved_lineages() {
+    let mut reporter = PhylogenyReporter::default();
+
+    reporter.report_dispersal(
+        (&dispersal(0, 0.0, 1.0, LineageInteraction::Coalescence(lineage(1)))).into(),
+    );
+    reporter.report_unresolved(&unresolved(1, 4.0));
+    reporter.report_unresolved(&unresolved(2, 3.0));
+
+    let summary = reporter.finalise();
+
+    assert_eq!(summary.get("individuals"), Some(&SummaryValue::Unsigned(3)));
+    assert_eq!(
+        summary.get("phylogenetic_diversity"),
+        Some(&SummaryValue::Float(1.0 + 4.0 + 3.0))
+    );
+    assert_eq!(
+        summary.get("mean_pairwise_distance"),
+        Some(&SummaryValue::Float(2.0 * 1.0))
+    );
+    assert_eq!(summary.get("unresolved"), Some(&SummaryValue::Unsigned(2)));
+    // The two unresolved lineages have no known common ancestor
+    assert_eq!(summary.get("tmrca"), None);
+}
+
+#[test]
+fn test_single_unresolved_lineage() {
+    let mut reporter = PhylogenyReporter::default();
+
+    reporter.report_dispersal(
+        (&dispersal(0, 0.0, 1.5, LineageInteraction::Coalescence(lineage(1)))).into(),
+    );
+    reporter.report_unresolved(&unresolved(1, 4.0));
+
+    let summary = reporter.finalise();
+
+    assert_eq!(summary.get("tmrca"), Some(&SummaryValue::Float(1.5)));
+}
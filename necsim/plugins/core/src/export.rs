@@ -37,33 +37,69 @@ impl ReporterPluginFilter {
             report_progress: R::ReportProgress::VALUE,
         }
     }
+
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Self {
+        Self {
+            report_speciation: self.report_speciation && other.report_speciation,
+            report_dispersal: self.report_dispersal && other.report_dispersal,
+            report_progress: self.report_progress && other.report_progress,
+        }
+    }
 }
 
+/// Any change to the layout of this struct, or to the `Reporter` trait and
+///  its bounds, breaks the plugin ABI and requires a new version of this
+///  crate, which plugins are checked against when they are loaded.
 #[repr(C)]
 pub struct UnsafeReporterPlugin {
-    pub(crate) reporter:
-        Box<dyn Reporter<ReportSpeciation = True, ReportDispersal = True, ReportProgress = True>>,
+    pub(crate) reporter: Box<
+        dyn Reporter<ReportSpeciation = True, ReportDispersal = True, ReportProgress = True> + Send,
+    >,
 
     pub(crate) filter: ReporterPluginFilter,
+
+    pub(crate) name: &'static str,
 }
 
-impl<R: Reporter> From<R> for UnsafeReporterPlugin {
+impl<R: Reporter + Send> From<R> for UnsafeReporterPlugin {
     fn from(reporter: R) -> Self {
         let boxed_reporter: Box<
             dyn Reporter<
-                ReportSpeciation = R::ReportSpeciation,
-                ReportDispersal = R::ReportDispersal,
-                ReportProgress = R::ReportProgress,
-            >,
+                    ReportSpeciation = R::ReportSpeciation,
+                    ReportDispersal = R::ReportDispersal,
+                    ReportProgress = R::ReportProgress,
+                > + Send,
         > = Box::new(reporter);
 
         Self {
             reporter: unsafe { std::mem::transmute(boxed_reporter) },
             filter: ReporterPluginFilter::from_reporter::<R>(),
+            name: std::any::type_name::<R>(),
         }
     }
 }
 
+#[cfg(feature = "export")]
+impl UnsafeReporterPlugin {
+    /// Restricts the events which are reported to the plugin at runtime,
+    ///  e.g. if the plugin forwards its events to dynamically loaded plugins
+    #[must_use]
+    pub fn with_filter(mut self, filter: ReporterPluginFilter) -> Self {
+        self.filter = self.filter.intersection(filter);
+
+        self
+    }
+
+    /// Names the plugin, e.g. to namespace its summary
+    #[must_use]
+    pub fn with_name(mut self, name: &'static str) -> Self {
+        self.name = name;
+
+        self
+    }
+}
+
 #[macro_export]
 macro_rules! export_plugin {
     ($($name:ident => $plugin:ty),+$(,)?) => {
@@ -93,7 +129,10 @@ macro_rules! export_plugin {
 
             $crate::erased_serde::deserialize::<Reporters>(deserializer).map(|reporter| {
                 match reporter {
-                    $(Reporters::$name(reporter) => reporter.into()),*
+                    $(Reporters::$name(reporter) => {
+                        $crate::export::UnsafeReporterPlugin::from(reporter)
+                            .with_name(stringify!($name))
+                    }),*
                 }
             }).map(::std::mem::ManuallyDrop::new)
         }
@@ -6,13 +6,14 @@ use std::{
 
 use necsim_core::{
     impl_finalise, impl_report,
+    lineage::UnresolvedLineage,
     reporter::{
         boolean::{Boolean, False, True},
-        Reporter,
+        Reporter, ReporterSummary,
     },
 };
 
-use crate::import::ReporterPlugin;
+use crate::{export::ReporterPluginFilter, import::ReporterPlugin};
 
 pub struct ReporterPluginVec<
     ReportSpeciation: Boolean,
@@ -87,11 +88,29 @@ impl<ReportSpeciation: Boolean, ReportDispersal: Boolean, ReportProgress: Boolea
     });
 
     impl_finalise!((self) {
+        let mut summary = ReporterSummary::default();
+
+        // Every summary is namespaced by the name of its plugin
         for plugin in self.plugins.into_vec() {
-            plugin.finalise();
+            let name = plugin.name.clone();
+            let plugin_summary = plugin.finalise();
+
+            if !plugin_summary.is_empty() {
+                summary.insert(name, plugin_summary);
+            }
         }
+
+        summary
     });
 
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        for plugin in self.plugins.iter_mut() {
+            if plugin.filter.report_speciation || plugin.filter.report_dispersal {
+                plugin.reporter.report_unresolved(lineage);
+            }
+        }
+    }
+
     fn initialise(&mut self) -> Result<(), String> {
         for plugin in self.plugins.iter_mut() {
             plugin.reporter.initialise()?;
@@ -113,6 +132,20 @@ pub enum AnyReporterPluginVec {
     ReportSpeciationReportDispersalReportProgress(ReporterPluginVec<True, True, True>),
 }
 
+impl AnyReporterPluginVec {
+    /// Returns the filter of events which are required by any of the plugins
+    #[must_use]
+    pub fn filter(&self) -> ReporterPluginFilter {
+        fn filter_of<R: Reporter>(_plugins: &R) -> ReporterPluginFilter {
+            ReporterPluginFilter::from_reporter::<R>()
+        }
+
+        crate::match_any_reporter_plugin_vec!(self => |plugins| {
+            filter_of(plugins)
+        })
+    }
+}
+
 impl FromIterator<ReporterPlugin> for AnyReporterPluginVec {
     fn from_iter<I: IntoIterator<Item = ReporterPlugin>>(iter: I) -> Self {
         let plugins = iter
@@ -183,6 +216,19 @@ impl FromIterator<ReporterPlugin> for AnyReporterPluginVec {
     }
 }
 
+impl IntoIterator for AnyReporterPluginVec {
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type Item = ReporterPlugin;
+
+    /// Splits the combinator back into its individual plugins, e.g. such that
+    ///  every plugin can report events on its own thread
+    fn into_iter(self) -> Self::IntoIter {
+        crate::match_any_reporter_plugin_vec!(self => |plugins| {
+            plugins.plugins.into_vec().into_iter()
+        })
+    }
+}
+
 #[macro_export]
 macro_rules! match_any_reporter_plugin_vec {
     ($any:expr => | $inner:ident | $code:block) => {{
@@ -1,6 +1,6 @@
-use std::{fmt, mem::ManuallyDrop, rc::Rc};
+use std::{fmt, mem::ManuallyDrop, sync::Arc};
 
-use necsim_core::reporter::{boolean::True, Reporter};
+use necsim_core::reporter::{boolean::True, Reporter, ReporterSummary};
 
 use crate::{
     export::{ReporterPluginFilter, UnsafeReporterPlugin},
@@ -9,35 +9,41 @@ use crate::{
 
 #[allow(clippy::module_name_repetitions)]
 pub struct ReporterPlugin {
-    pub(crate) library: Rc<PluginLibrary>,
+    pub(crate) library: Arc<PluginLibrary>,
 
     pub(crate) reporter: ManuallyDrop<
-        Box<dyn Reporter<ReportSpeciation = True, ReportDispersal = True, ReportProgress = True>>,
+        Box<
+            dyn Reporter<ReportSpeciation = True, ReportDispersal = True, ReportProgress = True>
+                + Send,
+        >,
     >,
     pub(crate) filter: ReporterPluginFilter,
+    pub(crate) name: String,
 
     pub(crate) finalised: bool,
 }
 
 impl ReporterPlugin {
-    pub(crate) fn finalise(mut self) {
+    pub(crate) fn finalise(mut self) -> ReporterSummary {
         self.finalised = true;
 
+        let summary = unsafe { ManuallyDrop::take(&mut self.reporter).finalise_boxed() };
+
         std::mem::drop(self);
+
+        summary
     }
 }
 
 impl Drop for ReporterPlugin {
     fn drop(&mut self) {
-        if self.finalised {
-            unsafe {
-                ManuallyDrop::take(&mut self.reporter).finalise_boxed();
-            }
-        } else {
+        // A finalised reporter has already been taken out
+        if !self.finalised {
             unsafe {
                 (self.library.declaration.drop)(ManuallyDrop::new(UnsafeReporterPlugin {
                     reporter: ManuallyDrop::take(&mut self.reporter),
                     filter: self.filter,
+                    name: "",
                 }));
             }
         }
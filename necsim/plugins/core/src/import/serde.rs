@@ -1,6 +1,6 @@
 /// Inspired by the <https://adventures.michaelfbryan.com/posts/plugins-in-rust/> blog post
 use std::{
-    convert::TryFrom, fmt, io, iter::IntoIterator, mem::ManuallyDrop, path::PathBuf, rc::Rc,
+    convert::TryFrom, fmt, io, iter::IntoIterator, mem::ManuallyDrop, path::PathBuf, sync::Arc,
 };
 
 use libloading::Library;
@@ -9,7 +9,7 @@ use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
 use crate::{export::ReporterPluginDeclaration, import::ReporterPlugin};
 
 pub struct ReporterPluginLibrary {
-    library: Rc<PluginLibrary>,
+    library: Arc<PluginLibrary>,
     reporters: Vec<ReporterPlugin>,
 }
 
@@ -98,16 +98,16 @@ impl TryFrom<PathBuf> for PluginLibrary {
     }
 }
 
-struct RcPluginLibrary(Rc<PluginLibrary>);
+struct ArcPluginLibrary(Arc<PluginLibrary>);
 
 // Deserialise a list of ReporterPlugins using the open library
-impl<'de> serde::de::DeserializeSeed<'de> for RcPluginLibrary {
+impl<'de> serde::de::DeserializeSeed<'de> for ArcPluginLibrary {
     type Value = Vec<ReporterPlugin>;
 
     fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
         // Helper struct to deserialise a single ReporterPlugin
         struct PluginReporter {
-            library: Rc<PluginLibrary>,
+            library: Arc<PluginLibrary>,
         }
 
         impl<'de> serde::de::DeserializeSeed<'de> for PluginReporter {
@@ -125,6 +125,8 @@ impl<'de> serde::de::DeserializeSeed<'de> for RcPluginLibrary {
                     Ok(reporter) => Ok(ReporterPlugin {
                         library: self.library,
                         filter: reporter.filter,
+                        // The name is copied as it is owned by the library
+                        name: String::from(reporter.name),
                         reporter: ManuallyDrop::new(ManuallyDrop::into_inner(reporter).reporter),
                         finalised: false,
                     }),
@@ -135,7 +137,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for RcPluginLibrary {
 
         // Helper struct to deserialise a list of ReporterPlugins
         struct ReporterVecVisitor<'a> {
-            library: Rc<PluginLibrary>,
+            library: Arc<PluginLibrary>,
             vec: &'a mut Vec<ReporterPlugin>,
         }
 
@@ -193,13 +195,13 @@ impl<'de> Visitor<'de> for ReporterPluginLibraryVisitor {
     where
         V: SeqAccess<'de>,
     {
-        let library: Rc<PluginLibrary> = Rc::new(
+        let library: Arc<PluginLibrary> = Arc::new(
             seq.next_element()?
                 .ok_or_else(|| de::Error::invalid_length(0, &self))?,
         );
 
         let reporters: Vec<ReporterPlugin> = seq
-            .next_element_seed(RcPluginLibrary(library.clone()))?
+            .next_element_seed(ArcPluginLibrary(library.clone()))?
             .ok_or_else(|| de::Error::invalid_length(1, &self))?;
 
         Ok(ReporterPluginLibrary { library, reporters })
@@ -209,16 +211,16 @@ impl<'de> Visitor<'de> for ReporterPluginLibraryVisitor {
     where
         V: MapAccess<'de>,
     {
-        let library: Rc<PluginLibrary> =
+        let library: Arc<PluginLibrary> =
             if let Some(ReporterPluginLibraryField::Library) = map.next_key()? {
-                Rc::new(map.next_value()?)
+                Arc::new(map.next_value()?)
             } else {
                 return Err(de::Error::missing_field("library"));
             };
 
         let reporters: Vec<ReporterPlugin> =
             if let Some(ReporterPluginLibraryField::Reporters) = map.next_key()? {
-                map.next_value_seed(RcPluginLibrary(library.clone()))?
+                map.next_value_seed(ArcPluginLibrary(library.clone()))?
             } else {
                 return Err(de::Error::missing_field("reporters"));
             };
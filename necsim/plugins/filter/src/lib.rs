@@ -0,0 +1,155 @@
+#![deny(clippy::pedantic)]
+
+use std::fmt;
+
+use serde::Deserialize;
+
+use necsim_core::{
+    impl_finalise, impl_report,
+    landscape::IndexedLocation,
+    lineage::{GlobalLineageReference, UnresolvedLineage},
+    reporter::{Reporter, ReporterSummary},
+};
+use necsim_core_bond::PositiveF64;
+use necsim_plugins_core::{
+    export::UnsafeReporterPlugin,
+    import::{AnyReporterPluginVec, ReporterPluginLibrary},
+    match_any_reporter_plugin_vec,
+};
+
+mod predicate;
+
+use predicate::EventPredicate;
+
+necsim_plugins_core::export_plugin!(Filter => FilterReporterPlugin);
+
+#[allow(clippy::module_name_repetitions)]
+pub struct FilterReporter {
+    filters: Vec<EventPredicate>,
+    reporters: AnyReporterPluginVec,
+
+    speciation: usize,
+    dispersal: usize,
+    unresolved: usize,
+}
+
+impl fmt::Debug for FilterReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("FilterReporter")
+            .field("filters", &self.filters)
+            .field("reporters", &self.reporters)
+            .finish()
+    }
+}
+
+/// The exported plugin only requests the events which the inner reporters
+///  require, which are only known after they have been loaded.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Deserialize)]
+#[serde(from = "FilterReporterArgs")]
+pub struct FilterReporterPlugin(FilterReporter);
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FilterReporterArgs {
+    filters: Vec<EventPredicate>,
+    reporters: Vec<ReporterPluginLibrary>,
+}
+
+impl From<FilterReporterArgs> for FilterReporterPlugin {
+    fn from(args: FilterReporterArgs) -> Self {
+        Self(FilterReporter {
+            filters: args.filters,
+            reporters: args.reporters.into_iter().flatten().collect(),
+
+            speciation: 0,
+            dispersal: 0,
+            unresolved: 0,
+        })
+    }
+}
+
+impl From<FilterReporterPlugin> for UnsafeReporterPlugin {
+    fn from(plugin: FilterReporterPlugin) -> Self {
+        let filter = plugin.0.reporters.filter();
+
+        UnsafeReporterPlugin::from(plugin.0).with_filter(filter)
+    }
+}
+
+impl Reporter for FilterReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        if self.matches(
+            &speciation.origin, speciation.event_time, &speciation.global_lineage_reference
+        ) {
+            self.speciation += 1;
+
+            match_any_reporter_plugin_vec!(&mut self.reporters => |reporters| {
+                reporters.report_speciation(speciation.into());
+            });
+        }
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        if self.matches(
+            &dispersal.origin, dispersal.event_time, &dispersal.global_lineage_reference
+        ) {
+            self.dispersal += 1;
+
+            match_any_reporter_plugin_vec!(&mut self.reporters => |reporters| {
+                reporters.report_dispersal(dispersal.into());
+            });
+        }
+    });
+
+    impl_report!(progress(&mut self, progress: Used) {
+        match_any_reporter_plugin_vec!(&mut self.reporters => |reporters| {
+            reporters.report_progress(progress.into());
+        });
+    });
+
+    impl_finalise!((self) {
+        let summary = match_any_reporter_plugin_vec!(self.reporters => |reporters| {
+            reporters.finalise()
+        });
+
+        ReporterSummary::new()
+            .with("speciation", self.speciation)
+            .with("dispersal", self.dispersal)
+            .with("unresolved", self.unresolved)
+            .with("reporters", summary)
+    });
+
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        if self
+            .filters
+            .iter()
+            .all(|filter| filter.matches_unresolved(lineage))
+        {
+            self.unresolved += 1;
+
+            match_any_reporter_plugin_vec!(&mut self.reporters => |reporters| {
+                reporters.report_unresolved(lineage);
+            });
+        }
+    }
+
+    fn initialise(&mut self) -> Result<(), String> {
+        match_any_reporter_plugin_vec!(&mut self.reporters => |reporters| {
+            reporters.initialise()
+        })
+    }
+}
+
+impl FilterReporter {
+    fn matches(
+        &self,
+        origin: &IndexedLocation,
+        event_time: PositiveF64,
+        reference: &GlobalLineageReference,
+    ) -> bool {
+        self.filters
+            .iter()
+            .all(|filter| filter.matches(origin, event_time, reference))
+    }
+}
@@ -0,0 +1,200 @@
+use std::{convert::TryFrom, fmt, fs::File, path::PathBuf};
+
+use serde::Deserialize;
+use tiff::decoder::{Decoder, DecodingResult};
+
+use necsim_core::{
+    landscape::{IndexedLocation, Location},
+    lineage::{GlobalLineageReference, UnresolvedLineage},
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum EventPredicate {
+    /// Keeps events whose origin lies inside the rectangle
+    Region {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    },
+    /// Keeps events whose origin lies on a non-zero pixel of the TIFF mask
+    Mask(LocationMask),
+    /// Keeps events which occur at `start <= event_time < end`, and
+    ///  unresolved lineages whose last event occurred before `end`
+    Time {
+        #[serde(default)]
+        start: Option<NonNegativeF64>,
+        #[serde(default)]
+        end: Option<PositiveF64>,
+    },
+    /// Keeps events from a pseudo-random subset of the lineages
+    Lineages {
+        percentage: ClosedUnitF64,
+        #[serde(default)]
+        seed: u64,
+    },
+}
+
+impl EventPredicate {
+    pub fn matches(
+        &self,
+        origin: &IndexedLocation,
+        event_time: PositiveF64,
+        reference: &GlobalLineageReference,
+    ) -> bool {
+        match self {
+            Self::Region { .. } | Self::Mask(_) => self.matches_location(origin.location()),
+            Self::Time { start, end } => {
+                start.map_or(true, |start| event_time.get() >= start.get())
+                    && end.map_or(true, |end| event_time.get() < end.get())
+            },
+            Self::Lineages { .. } => self.matches_lineage(reference),
+        }
+    }
+
+    /// Unresolved lineages are still active after their last event, and are
+    ///  thus kept by a time window iff that event occurred before its end
+    pub fn matches_unresolved(&self, lineage: &UnresolvedLineage) -> bool {
+        match self {
+            Self::Region { .. } | Self::Mask(_) => self.matches_location(&lineage.location),
+            Self::Time { end, .. } => {
+                end.map_or(true, |end| lineage.last_event_time.get() < end.get())
+            },
+            Self::Lineages { .. } => self.matches_lineage(&lineage.global_reference),
+        }
+    }
+
+    fn matches_location(&self, location: &Location) -> bool {
+        match self {
+            Self::Region {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                location.x() >= *x
+                    && (u64::from(location.x()) < u64::from(*x) + u64::from(*width))
+                    && location.y() >= *y
+                    && (u64::from(location.y()) < u64::from(*y) + u64::from(*height))
+            },
+            Self::Mask(mask) => mask.contains(location),
+            Self::Time { .. } | Self::Lineages { .. } => true,
+        }
+    }
+
+    fn matches_lineage(&self, reference: &GlobalLineageReference) -> bool {
+        match self {
+            Self::Lineages { percentage, seed } => {
+                #[allow(clippy::cast_precision_loss)]
+                let sample = (seahash_diffuse(reference.id() ^ *seed) as f64) / (u64::MAX as f64);
+
+                sample < percentage.get()
+            },
+            Self::Region { .. } | Self::Mask(_) | Self::Time { .. } => true,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(try_from = "PathBuf")]
+pub struct LocationMask {
+    path: PathBuf,
+    width: u32,
+    height: u32,
+    mask: Vec<bool>,
+}
+
+impl fmt::Debug for LocationMask {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("Mask").field(&self.path).finish()
+    }
+}
+
+impl LocationMask {
+    fn contains(&self, location: &Location) -> bool {
+        if location.x() >= self.width || location.y() >= self.height {
+            return false;
+        }
+
+        self.mask[(location.y() as usize) * (self.width as usize) + (location.x() as usize)]
+    }
+}
+
+impl TryFrom<PathBuf> for LocationMask {
+    type Error = String;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let file = File::open(&path)
+            .map_err(|err| format!("Could not read the mask {:?}: {}", path, err))?;
+
+        let mut decoder = Decoder::new(file)
+            .map_err(|err| format!("Could not decode the mask {:?}: {}", path, err))?;
+
+        let (width, height) = decoder.dimensions().map_err(|err| {
+            format!(
+                "Could not read the dimensions of the mask {:?}: {}",
+                path, err
+            )
+        })?;
+
+        let mask: Vec<bool> = match decoder
+            .read_image()
+            .map_err(|err| format!("Could not read the mask {:?}: {}", path, err))?
+        {
+            DecodingResult::U8(values) => values.into_iter().map(|value| value != 0).collect(),
+            DecodingResult::U16(values) => values.into_iter().map(|value| value != 0).collect(),
+            DecodingResult::U32(values) => values.into_iter().map(|value| value != 0).collect(),
+            DecodingResult::U64(values) => values.into_iter().map(|value| value != 0).collect(),
+            DecodingResult::F32(values) => values.into_iter().map(|value| value != 0.0).collect(),
+            DecodingResult::F64(values) => values.into_iter().map(|value| value != 0.0).collect(),
+            #[allow(unreachable_patterns)]
+            _ => {
+                return Err(format!(
+                    "The mask {:?} must be a grayscale unsigned integer or float image.",
+                    path
+                ))
+            },
+        };
+
+        if mask.len() != (width as usize) * (height as usize) {
+            return Err(format!(
+                "The mask {:?} must contain exactly one sample per pixel.",
+                path
+            ));
+        }
+
+        Ok(Self {
+            path,
+            width,
+            height,
+            mask,
+        })
+    }
+}
+
+const fn seahash_diffuse(mut x: u64) -> u64 {
+    // SeaHash diffusion function
+    // https://docs.rs/seahash/4.1.0/src/seahash/helper.rs.html#75-92
+
+    // These are derived from the PCG RNG's round. Thanks to @Veedrac for proposing
+    // this. The basic idea is that we use dynamic shifts, which are determined
+    // by the input itself. The shift is chosen by the higher bits, which means
+    // that changing those flips the lower bits, which scatters upwards because
+    // of the multiplication.
+
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    x = x.wrapping_mul(0x6eed_0e9d_a4d9_4a4f);
+
+    let a = x >> 32;
+    let b = x >> 60;
+
+    x ^= a >> b;
+
+    x = x.wrapping_mul(0x6eed_0e9d_a4d9_4a4f);
+
+    x
+}
@@ -3,91 +3,177 @@
 #[macro_use]
 extern crate log;
 
-use std::{collections::HashSet, fmt, num::NonZeroU64};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    fmt,
+    fs::OpenOptions,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
 
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use serde::Deserialize;
 
-use necsim_core::{event::SpeciationEvent, impl_finalise, impl_report, reporter::Reporter};
+use necsim_core::{
+    event::{DispersalEvent, LineageInteraction, SpeciationEvent},
+    impl_finalise, impl_report,
+    lineage::GlobalLineageReference,
+    reporter::{Reporter, ReporterSummary},
+};
+use necsim_core_bond::NonNegativeF64;
+
+mod metacommunity;
+
+use metacommunity::{Metacommunity, SpeciesAbundances};
 
 necsim_plugins_core::export_plugin!(Metacommunity => MetacommunityMigrationReporter);
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Deserialize)]
-#[serde(from = "MetacommunityMigrationReporterArgs")]
+#[serde(try_from = "MetacommunityMigrationReporterArgs")]
 pub struct MetacommunityMigrationReporter {
-    last_event: Option<SpeciationEvent>,
+    last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
+    last_speciation_event: Option<SpeciationEvent>,
+    last_dispersal_event: Option<DispersalEvent>,
 
     metacommunity: Metacommunity,
+    abundances: Option<SpeciesAbundances>,
     seed: u64,
+    output: Option<PathBuf>,
 
     migrations: usize,
+
+    individuals: HashSet<GlobalLineageReference>,
+    parents: HashMap<GlobalLineageReference, GlobalLineageReference>,
+    lineage_migrations: HashMap<GlobalLineageReference, usize>,
 }
 
 impl fmt::Debug for MetacommunityMigrationReporter {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct("MetacommunityMigrationReporter")
             .field("metacommunity", &self.metacommunity)
+            .field("abundances", &self.abundances)
             .field("seed", &self.seed)
+            .field("output", &self.output)
             .field("migrations", &self.migrations)
             .finish()
     }
 }
 
-#[derive(Debug, Deserialize)]
-enum Metacommunity {
-    Infinite,
-    Finite(NonZeroU64),
-}
-
 #[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
 struct MetacommunityMigrationReporterArgs {
     metacommunity: Metacommunity,
     seed: u64,
+    #[serde(default)]
+    output: Option<PathBuf>,
 }
 
-impl From<MetacommunityMigrationReporterArgs> for MetacommunityMigrationReporter {
-    fn from(args: MetacommunityMigrationReporterArgs) -> Self {
-        Self {
-            last_event: None,
+impl TryFrom<MetacommunityMigrationReporterArgs> for MetacommunityMigrationReporter {
+    type Error = String;
+
+    fn try_from(args: MetacommunityMigrationReporterArgs) -> Result<Self, Self::Error> {
+        let abundances = args.metacommunity.load_species_abundances()?;
+
+        if let Some(output) = &args.output {
+            // Preliminary argument parsing check if the output is a writable file
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(output)
+                .map_err(|err| err.to_string())?;
+            std::mem::drop(file);
+        }
+
+        Ok(Self {
+            last_parent_prior_time: None,
+            last_speciation_event: None,
+            last_dispersal_event: None,
 
             metacommunity: args.metacommunity,
+            abundances,
             seed: args.seed,
+            output: args.output,
 
             migrations: 0_usize,
-        }
+
+            individuals: HashSet::new(),
+            parents: HashMap::new(),
+            lineage_migrations: HashMap::new(),
+        })
     }
 }
 
 impl Reporter for MetacommunityMigrationReporter {
     impl_report!(speciation(&mut self, speciation: Used) {
-        if Some(speciation) == self.last_event.as_ref() {
-            return;
+        if speciation.prior_time == 0.0_f64 {
+            self.individuals.insert(speciation.global_lineage_reference.clone());
         }
 
-        self.last_event = Some(speciation.clone());
+        if Some(speciation) == self.last_speciation_event.as_ref() {
+            if let Some((parent, prior_time)) = &self.last_parent_prior_time {
+                if prior_time != &speciation.prior_time {
+                    let parent = parent.clone();
+                    self.parents.insert(speciation.global_lineage_reference.clone(), parent);
+                }
+            }
+        } else {
+            self.lineage_migrations.insert(
+                speciation.global_lineage_reference.clone(), self.migrations
+            );
 
-        self.migrations += 1;
+            self.migrations += 1;
+        }
+
+        self.last_speciation_event = Some(speciation.clone());
+        self.last_parent_prior_time = Some(
+            (speciation.global_lineage_reference.clone(), speciation.prior_time)
+        );
     });
 
-    impl_report!(dispersal(&mut self, _dispersal: Ignored) {});
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        if dispersal.prior_time == 0.0_f64 {
+            self.individuals.insert(dispersal.global_lineage_reference.clone());
+        }
+
+        if Some(dispersal) == self.last_dispersal_event.as_ref() {
+            if let Some((parent, prior_time)) = &self.last_parent_prior_time {
+                if prior_time != &dispersal.prior_time {
+                    let parent = parent.clone();
+                    self.parents.insert(dispersal.global_lineage_reference.clone(), parent);
+                }
+            }
+        } else if let LineageInteraction::Coalescence(parent) = &dispersal.interaction {
+            self.parents.insert(dispersal.global_lineage_reference.clone(), parent.clone());
+        }
+
+        self.last_dispersal_event = Some(dispersal.clone());
+        self.last_parent_prior_time = Some(
+            (dispersal.global_lineage_reference.clone(), dispersal.prior_time)
+        );
+    });
 
     impl_report!(progress(&mut self, _progress: Ignored) {});
 
     impl_finalise!((self) {
         if self.migrations == 0 {
-            return
+            return ReporterSummary::default();
         }
 
-        let metacommunity_size = match self.metacommunity {
+        let metacommunity_size = match &self.metacommunity {
             Metacommunity::Infinite => {
-                return info!(
+                info!(
                     "There were {} migrations to an infinite metacommunity during the simulation.",
                     self.migrations
-                )
+                );
+
+                return ReporterSummary::new().with("migrations", self.migrations);
+            },
+            Metacommunity::Finite(metacommunity_size) => *metacommunity_size,
+            Metacommunity::Abundances(_) | Metacommunity::Simulation { .. } => {
+                return self.report_species_abundances()
             },
-            Metacommunity::Finite(metacommunity_size) => metacommunity_size,
         };
 
         let mut rng = StdRng::seed_from_u64(self.seed);
@@ -103,5 +189,135 @@ impl Reporter for MetacommunityMigrationReporter {
             size {} during the simulation.",
             self.migrations, unique_migration_targets.len(), metacommunity_size,
         );
+
+        ReporterSummary::new()
+            .with("migrations", self.migrations)
+            .with("unique_ancestors", unique_migration_targets.len())
     });
 }
+
+impl MetacommunityMigrationReporter {
+    fn report_species_abundances(&self) -> ReporterSummary {
+        let metacommunity = match &self.abundances {
+            Some(abundances) => abundances,
+            None => return ReporterSummary::new().with("migrations", self.migrations),
+        };
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        // Each migration draws its ancestor species from the metacommunity
+        let migration_species = (0..self.migrations)
+            .map(|_| metacommunity.sample_species(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut local_abundances = HashMap::new();
+        let mut unresolved_individuals = 0_usize;
+
+        for individual in &self.individuals {
+            match self.find_individual_migration(individual) {
+                Some(migration) => {
+                    *local_abundances
+                        .entry(migration_species[migration])
+                        .or_insert(0_u64) += 1;
+                },
+                None => unresolved_individuals += 1,
+            }
+        }
+
+        if unresolved_individuals > 0 {
+            warn!(
+                "{} individuals could not be traced back to a migration from the metacommunity.",
+                unresolved_individuals
+            );
+        }
+
+        let local_richness = local_abundances.len();
+        let shared_metacommunity_abundance = local_abundances
+            .keys()
+            .map(|species| metacommunity.abundance(*species).get())
+            .sum::<u64>();
+
+        #[allow(clippy::cast_precision_loss)]
+        let abundance_overlap =
+            (shared_metacommunity_abundance as f64) / (metacommunity.size() as f64);
+
+        let migration_richness = migration_species.iter().collect::<HashSet<_>>().len();
+
+        info!(
+            "There were {} migrations to {} species on a metacommunity of {} species and size {} \
+             during the simulation.",
+            self.migrations,
+            migration_richness,
+            metacommunity.richness(),
+            metacommunity.size(),
+        );
+        info!(
+            "The local community has a species richness of {} and shares {:.2}% of the \
+             metacommunity's individuals through its species.",
+            local_richness,
+            abundance_overlap * 100.0_f64,
+        );
+
+        if let Some(output) = &self.output {
+            if let Err(err) = write_species_abundances(output, metacommunity, &local_abundances) {
+                error!(
+                    "Failed to write the local species abundances to {:?}: {}",
+                    output, err
+                );
+            }
+        }
+
+        ReporterSummary::new()
+            .with("migrations", self.migrations)
+            .with("migration_species", migration_richness)
+            .with("local_richness", local_richness)
+            .with("abundance_overlap", abundance_overlap)
+            .with("unresolved_individuals", unresolved_individuals)
+    }
+
+    fn find_individual_migration(&self, individual: &GlobalLineageReference) -> Option<usize> {
+        let mut lineage = individual;
+
+        // Bound the traversal to guard against cyclic parent relationships
+        for _ in 0..=self.parents.len() {
+            if let Some(migration) = self.lineage_migrations.get(lineage) {
+                return Some(*migration);
+            }
+
+            lineage = self.parents.get(lineage)?;
+        }
+
+        None
+    }
+}
+
+fn write_species_abundances(
+    output: &Path,
+    metacommunity: &SpeciesAbundances,
+    local_abundances: &HashMap<usize, u64>,
+) -> io::Result<()> {
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(output)?;
+
+    let mut writer = BufWriter::new(file);
+
+    writeln!(writer, "species,local,metacommunity")?;
+
+    let mut species = local_abundances.iter().collect::<Vec<_>>();
+    species.sort_unstable();
+
+    for (species, local_abundance) in species {
+        writeln!(
+            writer,
+            "{},{},{}",
+            metacommunity.species(*species),
+            local_abundance,
+            metacommunity.abundance(*species),
+        )?;
+    }
+
+    writer.flush()
+}
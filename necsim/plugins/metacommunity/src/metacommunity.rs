@@ -0,0 +1,236 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader},
+    num::NonZeroU64,
+    path::{Path, PathBuf},
+};
+
+use rand::Rng;
+use rusqlite::Connection;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub enum Metacommunity {
+    Infinite,
+    Finite(NonZeroU64),
+    Abundances(PathBuf),
+    Simulation {
+        output: PathBuf,
+        #[serde(default = "default_table_name")]
+        table: String,
+    },
+}
+
+fn default_table_name() -> String {
+    String::from("SPECIES_LOCATIONS")
+}
+
+impl Metacommunity {
+    /// Loads the species abundances of the metacommunity, or returns `None`
+    ///  if the metacommunity does not assign species identities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error `String` iff the abundances could not be loaded.
+    pub fn load_species_abundances(&self) -> Result<Option<SpeciesAbundances>, String> {
+        match self {
+            Self::Infinite | Self::Finite(_) => Ok(None),
+            Self::Abundances(path) => load_csv_abundances(path).map(Some).map_err(|err| {
+                format!(
+                    "Failed to load the metacommunity species abundances from {:?}: {}",
+                    path, err
+                )
+            }),
+            Self::Simulation { output, table } => load_simulation_abundances(output, table)
+                .map(Some)
+                .map_err(|err| {
+                    format!(
+                        "Failed to load the metacommunity species from table {:?} at {:?}: {}",
+                        table, output, err
+                    )
+                }),
+        }
+    }
+}
+
+pub struct SpeciesAbundances {
+    species: Vec<String>,
+    abundances: Vec<NonZeroU64>,
+    cumulative: Vec<u64>,
+}
+
+impl fmt::Debug for SpeciesAbundances {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("SpeciesAbundances")
+            .field("richness", &self.richness())
+            .field("size", &self.size())
+            .finish()
+    }
+}
+
+impl SpeciesAbundances {
+    fn new(abundances: HashMap<String, u64>) -> Result<Self, String> {
+        let mut abundances = abundances
+            .into_iter()
+            .filter_map(|(species, abundance)| NonZeroU64::new(abundance).map(|a| (species, a)))
+            .collect::<Vec<_>>();
+
+        // Sort the species to make the sampling independent of the input order
+        abundances.sort_unstable();
+
+        let mut total = 0_u64;
+        let mut cumulative = Vec::with_capacity(abundances.len());
+
+        for (_, abundance) in &abundances {
+            total = total
+                .checked_add(abundance.get())
+                .ok_or_else(|| String::from("the total metacommunity size overflows a u64"))?;
+
+            cumulative.push(total);
+        }
+
+        if total == 0 {
+            return Err(String::from("the metacommunity contains no individuals"));
+        }
+
+        let (species, abundances) = abundances.into_iter().unzip();
+
+        Ok(Self {
+            species,
+            abundances,
+            cumulative,
+        })
+    }
+
+    #[must_use]
+    pub fn richness(&self) -> usize {
+        self.species.len()
+    }
+
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.cumulative.last().copied().unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn species(&self, index: usize) -> &str {
+        &self.species[index]
+    }
+
+    #[must_use]
+    pub fn abundance(&self, index: usize) -> NonZeroU64 {
+        self.abundances[index]
+    }
+
+    /// Samples the index of the species of a uniformly chosen ancestor
+    ///  individual from the metacommunity
+    pub fn sample_species<G: Rng>(&self, rng: &mut G) -> usize {
+        let ancestor = rng.gen_range(0..self.size());
+
+        // The first species whose cumulative abundance exceeds the ancestor
+        match self.cumulative.binary_search(&ancestor) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        }
+    }
+}
+
+fn load_csv_abundances(path: &Path) -> Result<SpeciesAbundances, String> {
+    let reader = BufReader::new(File::open(path).map_err(|err| err.to_string())?);
+
+    let mut abundances = HashMap::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|err| err.to_string())?;
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut columns = line.split(',').map(str::trim);
+
+        let (species, abundance) = match (columns.next(), columns.next(), columns.next()) {
+            (Some(species), Some(abundance), None) => (species, abundance),
+            _ => {
+                return Err(format!(
+                    "line {} must have the format `species,abundance`",
+                    i + 1
+                ))
+            },
+        };
+
+        let abundance = match abundance.parse::<u64>() {
+            Ok(abundance) => abundance,
+            // The first line may optionally be a header
+            Err(_) if i == 0 => continue,
+            Err(err) => return Err(format!("line {} has an invalid abundance: {}", i + 1, err)),
+        };
+
+        let total = abundances.entry(String::from(species)).or_insert(0_u64);
+        *total = total
+            .checked_add(abundance)
+            .ok_or_else(|| format!("the abundance of species {:?} overflows a u64", species))?;
+    }
+
+    SpeciesAbundances::new(abundances)
+}
+
+fn load_simulation_abundances(path: &Path, table: &str) -> Result<SpeciesAbundances, String> {
+    let connection = Connection::open(path).map_err(|err| err.to_string())?;
+
+    let mut statement = connection
+        .prepare(&format!("SELECT id, parent, species FROM {}", table))
+        .map_err(|err| err.to_string())?;
+
+    // Every individual links to its parent, only the root of each
+    //  coalescence tree stores the species identity
+    let mut individuals = HashMap::new();
+
+    let rows = statement
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, Option<String>>(2)?,
+            ))
+        })
+        .map_err(|err| err.to_string())?;
+
+    for row in rows {
+        let (id, parent, species) = row.map_err(|err| err.to_string())?;
+
+        individuals.insert(id, (parent, species));
+    }
+
+    let mut abundances = HashMap::new();
+
+    for &id in individuals.keys() {
+        let species = find_ancestor_species(&individuals, id)
+            .ok_or_else(|| format!("individual {} does not descend from any species", id))?;
+
+        *abundances.entry(species.clone()).or_insert(0_u64) += 1;
+    }
+
+    SpeciesAbundances::new(abundances)
+}
+
+fn find_ancestor_species(
+    individuals: &HashMap<i64, (i64, Option<String>)>,
+    id: i64,
+) -> Option<&String> {
+    let mut current = id;
+
+    // Bound the traversal to guard against cyclic parent relationships
+    for _ in 0..=individuals.len() {
+        match individuals.get(&current)? {
+            (_, Some(species)) => return Some(species),
+            (parent, None) if *parent != current => current = *parent,
+            (_, None) => return None,
+        }
+    }
+
+    None
+}
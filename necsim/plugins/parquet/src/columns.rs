@@ -0,0 +1,186 @@
+use parquet::{
+    column::writer::ColumnWriter,
+    data_type::ByteArray,
+    errors::{ParquetError, Result},
+    file::writer::RowGroupWriter,
+};
+
+use necsim_core::{
+    event::{DispersalEvent, LineageInteraction, SpeciationEvent},
+    landscape::IndexedLocation,
+};
+
+pub const EVENT_SCHEMA: &str = "
+    message event {
+        REQUIRED INT64 reference (UINT_64);
+        REQUIRED BYTE_ARRAY type (UTF8);
+        REQUIRED DOUBLE prior_time;
+        REQUIRED DOUBLE event_time;
+        REQUIRED INT32 origin_x (UINT_32);
+        REQUIRED INT32 origin_y (UINT_32);
+        REQUIRED INT32 origin_index (UINT_32);
+        OPTIONAL INT32 target_x (UINT_32);
+        OPTIONAL INT32 target_y (UINT_32);
+        OPTIONAL INT32 target_index (UINT_32);
+        OPTIONAL BYTE_ARRAY interaction (UTF8);
+        OPTIONAL INT64 parent (UINT_64);
+    }
+";
+
+/// Buffers the columns of one row group of events in memory
+#[derive(Default)]
+#[allow(clippy::module_name_repetitions)]
+pub struct EventColumns {
+    reference: Vec<i64>,
+    r#type: Vec<ByteArray>,
+    prior_time: Vec<f64>,
+    event_time: Vec<f64>,
+    origin_x: Vec<i32>,
+    origin_y: Vec<i32>,
+    origin_index: Vec<i32>,
+    // Speciation events have no target and no interaction
+    target_x: Vec<i32>,
+    target_y: Vec<i32>,
+    target_index: Vec<i32>,
+    target_levels: Vec<i16>,
+    interaction: Vec<ByteArray>,
+    interaction_levels: Vec<i16>,
+    parent: Vec<i64>,
+    parent_levels: Vec<i16>,
+}
+
+impl EventColumns {
+    pub fn len(&self) -> usize {
+        self.reference.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reference.is_empty()
+    }
+
+    pub fn push_speciation(&mut self, speciation: &SpeciationEvent) {
+        self.push_common(
+            speciation.global_lineage_reference.id(),
+            "speciation",
+            speciation.prior_time.get(),
+            speciation.event_time.get(),
+            &speciation.origin,
+        );
+
+        self.target_levels.push(0);
+        self.interaction_levels.push(0);
+        self.parent_levels.push(0);
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn push_dispersal(&mut self, dispersal: &DispersalEvent) {
+        self.push_common(
+            dispersal.global_lineage_reference.id(),
+            "dispersal",
+            dispersal.prior_time.get(),
+            dispersal.event_time.get(),
+            &dispersal.origin,
+        );
+
+        self.target_x.push(dispersal.target.location().x() as i32);
+        self.target_y.push(dispersal.target.location().y() as i32);
+        self.target_index.push(dispersal.target.index() as i32);
+        self.target_levels.push(1);
+
+        let interaction = match &dispersal.interaction {
+            LineageInteraction::None => "none",
+            LineageInteraction::Maybe => "maybe",
+            LineageInteraction::Coalescence(parent) => {
+                self.parent.push(parent.id() as i64);
+
+                "coalescence"
+            },
+        };
+
+        self.parent_levels.push(i16::from(matches!(
+            dispersal.interaction,
+            LineageInteraction::Coalescence(_)
+        )));
+        self.interaction.push(ByteArray::from(interaction));
+        self.interaction_levels.push(1);
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn push_common(
+        &mut self,
+        reference: u64,
+        r#type: &str,
+        prior_time: f64,
+        event_time: f64,
+        origin: &IndexedLocation,
+    ) {
+        // Unsigned values are stored with the same bit pattern in signed columns
+        self.reference.push(reference as i64);
+        self.r#type.push(ByteArray::from(r#type));
+        self.prior_time.push(prior_time);
+        self.event_time.push(event_time);
+        self.origin_x.push(origin.location().x() as i32);
+        self.origin_y.push(origin.location().y() as i32);
+        self.origin_index.push(origin.index() as i32);
+    }
+
+    /// Writes all buffered events into the `row_group` and clears the buffer
+    pub fn write_to_row_group(&mut self, row_group: &mut dyn RowGroupWriter) -> Result<()> {
+        let mut column = 0_usize;
+
+        while let Some(mut writer) = row_group.next_column()? {
+            match (column, &mut writer) {
+                (0, ColumnWriter::Int64ColumnWriter(writer)) => {
+                    writer.write_batch(&self.reference, None, None)?;
+                },
+                (1, ColumnWriter::ByteArrayColumnWriter(writer)) => {
+                    writer.write_batch(&self.r#type, None, None)?;
+                },
+                (2, ColumnWriter::DoubleColumnWriter(writer)) => {
+                    writer.write_batch(&self.prior_time, None, None)?;
+                },
+                (3, ColumnWriter::DoubleColumnWriter(writer)) => {
+                    writer.write_batch(&self.event_time, None, None)?;
+                },
+                (4, ColumnWriter::Int32ColumnWriter(writer)) => {
+                    writer.write_batch(&self.origin_x, None, None)?;
+                },
+                (5, ColumnWriter::Int32ColumnWriter(writer)) => {
+                    writer.write_batch(&self.origin_y, None, None)?;
+                },
+                (6, ColumnWriter::Int32ColumnWriter(writer)) => {
+                    writer.write_batch(&self.origin_index, None, None)?;
+                },
+                (7, ColumnWriter::Int32ColumnWriter(writer)) => {
+                    writer.write_batch(&self.target_x, Some(&self.target_levels), None)?;
+                },
+                (8, ColumnWriter::Int32ColumnWriter(writer)) => {
+                    writer.write_batch(&self.target_y, Some(&self.target_levels), None)?;
+                },
+                (9, ColumnWriter::Int32ColumnWriter(writer)) => {
+                    writer.write_batch(&self.target_index, Some(&self.target_levels), None)?;
+                },
+                (10, ColumnWriter::ByteArrayColumnWriter(writer)) => {
+                    writer.write_batch(&self.interaction, Some(&self.interaction_levels), None)?;
+                },
+                (11, ColumnWriter::Int64ColumnWriter(writer)) => {
+                    writer.write_batch(&self.parent, Some(&self.parent_levels), None)?;
+                },
+                _ => {
+                    return Err(ParquetError::General(format!(
+                        "Column {} does not match the event schema.",
+                        column
+                    )))
+                },
+            }
+
+            row_group.close_column(writer)?;
+
+            column += 1;
+        }
+
+        *self = Self::default();
+
+        Ok(())
+    }
+}
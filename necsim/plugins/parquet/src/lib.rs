@@ -0,0 +1,272 @@
+#![deny(clippy::pedantic)]
+
+#[macro_use]
+extern crate log;
+
+use std::{
+    convert::TryFrom,
+    fmt,
+    fs::{File, OpenOptions},
+    io,
+    num::NonZeroUsize,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use parquet::{
+    basic::Compression,
+    errors::Result as ParquetResult,
+    file::{
+        properties::WriterProperties,
+        writer::{FileWriter, SerializedFileWriter},
+    },
+    schema::parser::parse_message_type,
+};
+use serde::Deserialize;
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    impl_finalise, impl_report,
+    reporter::{Reporter, ReporterSummary},
+};
+
+mod columns;
+
+#[cfg(test)]
+mod test;
+
+use columns::{EventColumns, EVENT_SCHEMA};
+
+necsim_plugins_core::export_plugin!(Parquet => ParquetReporter);
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Deserialize)]
+#[serde(try_from = "ParquetReporterArgs")]
+pub struct ParquetReporter {
+    last_speciation_event: Option<SpeciationEvent>,
+    last_dispersal_event: Option<DispersalEvent>,
+
+    output: PathBuf,
+    compression: ParquetCompression,
+    row_group_size: NonZeroUsize,
+
+    writer: Option<SerializedFileWriter<File>>,
+    columns: EventColumns,
+
+    speciation: usize,
+    dispersal: usize,
+    row_groups: usize,
+}
+
+impl fmt::Debug for ParquetReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ParquetReporter")
+            .field("output", &self.output)
+            .field("compression", &self.compression)
+            .field("row_group_size", &self.row_group_size)
+            .finish()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ParquetReporterArgs {
+    output: PathBuf,
+    #[serde(default)]
+    compression: ParquetCompression,
+    #[serde(default = "default_row_group_size")]
+    row_group_size: NonZeroUsize,
+}
+
+fn default_row_group_size() -> NonZeroUsize {
+    NonZeroUsize::new(1_000_000).unwrap()
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+enum ParquetCompression {
+    Uncompressed,
+    Snappy,
+    Gzip,
+    Brotli,
+    Lz4,
+    Zstd,
+}
+
+impl Default for ParquetCompression {
+    fn default() -> Self {
+        Self::Snappy
+    }
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::Uncompressed => Compression::UNCOMPRESSED,
+            ParquetCompression::Snappy => Compression::SNAPPY,
+            ParquetCompression::Gzip => Compression::GZIP,
+            ParquetCompression::Brotli => Compression::BROTLI,
+            ParquetCompression::Lz4 => Compression::LZ4,
+            ParquetCompression::Zstd => Compression::ZSTD,
+        }
+    }
+}
+
+impl TryFrom<ParquetReporterArgs> for ParquetReporter {
+    type Error = io::Error;
+
+    fn try_from(args: ParquetReporterArgs) -> Result<Self, Self::Error> {
+        // Preliminary argument parsing check if the output is a writable file
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&args.output)?;
+        std::mem::drop(file);
+
+        Ok(Self {
+            last_speciation_event: None,
+            last_dispersal_event: None,
+
+            output: args.output,
+            compression: args.compression,
+            row_group_size: args.row_group_size,
+
+            writer: None,
+            columns: EventColumns::default(),
+
+            speciation: 0,
+            dispersal: 0,
+            row_groups: 0,
+        })
+    }
+}
+
+impl Reporter for ParquetReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        if matches!(&self.last_speciation_event, Some(last) if is_same_speciation(last, speciation)) {
+            return;
+        }
+        self.last_speciation_event = Some(speciation.clone());
+
+        if self.writer.is_some() {
+            self.columns.push_speciation(speciation);
+            self.speciation += 1;
+
+            self.try_flush_full_row_group();
+        }
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        if matches!(&self.last_dispersal_event, Some(last) if is_same_dispersal(last, dispersal)) {
+            return;
+        }
+        self.last_dispersal_event = Some(dispersal.clone());
+
+        if self.writer.is_some() {
+            self.columns.push_dispersal(dispersal);
+            self.dispersal += 1;
+
+            self.try_flush_full_row_group();
+        }
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((mut self) {
+        if let Err(err) = self.close() {
+            error!("Failed to write the events to {:?}: {}", self.output, err);
+        }
+
+        ReporterSummary::new()
+            .with("output", self.output.display().to_string())
+            .with("speciation", self.speciation)
+            .with("dispersal", self.dispersal)
+            .with("row_groups", self.row_groups)
+    });
+
+    fn initialise(&mut self) -> Result<(), String> {
+        if self.writer.is_some() {
+            return Ok(());
+        }
+
+        let result = (|| -> ParquetResult<SerializedFileWriter<File>> {
+            let file = OpenOptions::new()
+                .create(true)
+                .truncate(true)
+                .write(true)
+                .open(&self.output)?;
+
+            let schema = Arc::new(parse_message_type(EVENT_SCHEMA)?);
+            let properties = Arc::new(
+                WriterProperties::builder()
+                    .set_compression(self.compression.into())
+                    .set_max_row_group_size(self.row_group_size.get())
+                    .build(),
+            );
+
+            SerializedFileWriter::new(file, schema, properties)
+        })();
+
+        match result {
+            Ok(writer) => {
+                self.writer = Some(writer);
+
+                Ok(())
+            },
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
+
+impl ParquetReporter {
+    fn try_flush_full_row_group(&mut self) {
+        if self.columns.len() < self.row_group_size.get() {
+            return;
+        }
+
+        if let Err(err) = self.flush_row_group() {
+            error!("Failed to write the events to {:?}: {}", self.output, err);
+
+            // Stop recording events after the output has been corrupted
+            self.writer = None;
+        }
+    }
+
+    fn flush_row_group(&mut self) -> ParquetResult<()> {
+        let writer = match &mut self.writer {
+            Some(writer) if !self.columns.is_empty() => writer,
+            _ => return Ok(()),
+        };
+
+        let mut row_group = writer.next_row_group()?;
+        self.columns.write_to_row_group(&mut *row_group)?;
+        writer.close_row_group(row_group)?;
+
+        self.row_groups += 1;
+
+        Ok(())
+    }
+
+    fn close(&mut self) -> ParquetResult<()> {
+        self.flush_row_group()?;
+
+        if let Some(mut writer) = self.writer.take() {
+            writer.close()?;
+        }
+
+        Ok(())
+    }
+}
+
+// Only exact repeats of an event are skipped, as the `PartialEq` impls ignore
+//  the `global_lineage_reference` and `prior_time` which are written out
+fn is_same_speciation(last: &SpeciationEvent, speciation: &SpeciationEvent) -> bool {
+    last == speciation
+        && last.global_lineage_reference == speciation.global_lineage_reference
+        && last.prior_time == speciation.prior_time
+}
+
+fn is_same_dispersal(last: &DispersalEvent, dispersal: &DispersalEvent) -> bool {
+    last == dispersal
+        && last.global_lineage_reference == dispersal.global_lineage_reference
+        && last.prior_time == dispersal.prior_time
+}
@@ -0,0 +1,145 @@
+use std::{convert::TryFrom, fs::File, num::NonZeroUsize};
+
+use parquet::{
+    file::reader::{FileReader, SerializedFileReader},
+    record::{Field, Row, RowAccessor},
+};
+use serde::{
+    de::{value::Error, IntoDeserializer},
+    Deserialize,
+};
+
+use necsim_core::{
+    event::{DispersalEvent, LineageInteraction, SpeciationEvent},
+    landscape::{IndexedLocation, Location},
+    lineage::GlobalLineageReference,
+    reporter::{Reporter, SummaryValue},
+};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use super::{ParquetCompression, ParquetReporter, ParquetReporterArgs};
+
+fn lineage(id: u64) -> GlobalLineageReference {
+    GlobalLineageReference::deserialize(IntoDeserializer::<Error>::into_deserializer(id)).unwrap()
+}
+
+fn dispersal(
+    id: u64,
+    prior_time: f64,
+    event_time: f64,
+    interaction: LineageInteraction,
+) -> DispersalEvent {
+    DispersalEvent {
+        origin: IndexedLocation::new(Location::new(1, 2), 3),
+        prior_time: NonNegativeF64::new(prior_time).unwrap(),
+        event_time: PositiveF64::new(event_time).unwrap(),
+        global_lineage_reference: lineage(id),
+        target: IndexedLocation::new(Location::new(4, 5), 6),
+        interaction,
+    }
+}
+
+fn speciation(id: u64, prior_time: f64, event_time: f64) -> SpeciationEvent {
+    SpeciationEvent {
+        origin: IndexedLocation::new(Location::new(7, 8), 9),
+        prior_time: NonNegativeF64::new(prior_time).unwrap(),
+        event_time: PositiveF64::new(event_time).unwrap(),
+        global_lineage_reference: lineage(id),
+    }
+}
+
+fn is_null(row: &Row, column: usize) -> bool {
+    matches!(row.get_column_iter().nth(column), Some((_, Field::Null)))
+}
+
+#[test]
+fn test_event_round_trip() {
+    let output = std::env::temp_dir().join(format!(
+        "necsim-plugins-parquet-{}.parquet",
+        std::process::id()
+    ));
+
+    let mut reporter = ParquetReporter::try_from(ParquetReporterArgs {
+        output: output.clone(),
+        compression: ParquetCompression::default(),
+        // Split the events across several row groups
+        row_group_size: NonZeroUsize::new(2).unwrap(),
+    })
+    .unwrap();
+    reporter.initialise().unwrap();
+
+    reporter.report_dispersal((&dispersal(0, 0.0, 1.0, LineageInteraction::None)).into());
+    // An exact repeat of the previous event is only written once
+    reporter.report_dispersal((&dispersal(0, 0.0, 1.0, LineageInteraction::None)).into());
+    // An event which only differs in its lineage reference and prior time
+    //  is still written
+    reporter.report_dispersal((&dispersal(1, 0.5, 1.0, LineageInteraction::None)).into());
+    reporter.report_dispersal(
+        (&dispersal(2, 0.25, 1.5, LineageInteraction::Coalescence(lineage(0)))).into(),
+    );
+    reporter.report_speciation((&speciation(0, 1.0, 2.0)).into());
+    reporter.report_speciation((&speciation(3, 1.25, 2.0)).into());
+
+    let summary = reporter.finalise();
+
+    assert_eq!(summary.get("dispersal"), Some(&SummaryValue::Unsigned(3)));
+    assert_eq!(summary.get("speciation"), Some(&SummaryValue::Unsigned(2)));
+    assert_eq!(summary.get("row_groups"), Some(&SummaryValue::Unsigned(3)));
+
+    let reader = SerializedFileReader::new(File::open(&output).unwrap()).unwrap();
+    let rows = reader.get_row_iter(None).unwrap().collect::<Vec<_>>();
+
+    std::fs::remove_file(&output).unwrap();
+
+    assert_eq!(reader.metadata().num_row_groups(), 3);
+    assert_eq!(rows.len(), 5);
+
+    let references = rows
+        .iter()
+        .map(|row| row.get_ulong(0).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(references, vec![0, 1, 2, 0, 3]);
+
+    let prior_times = rows
+        .iter()
+        .map(|row| row.get_double(2).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(prior_times, vec![0.0, 0.5, 0.25, 1.0, 1.25]);
+
+    let dispersal = &rows[2];
+    assert_eq!(dispersal.get_string(1).unwrap(), "dispersal");
+    assert!((dispersal.get_double(3).unwrap() - 1.5).abs() < f64::EPSILON);
+    assert_eq!(
+        (
+            dispersal.get_uint(4).unwrap(),
+            dispersal.get_uint(5).unwrap(),
+            dispersal.get_uint(6).unwrap()
+        ),
+        (1, 2, 3)
+    );
+    assert_eq!(
+        (
+            dispersal.get_uint(7).unwrap(),
+            dispersal.get_uint(8).unwrap(),
+            dispersal.get_uint(9).unwrap()
+        ),
+        (4, 5, 6)
+    );
+    assert_eq!(dispersal.get_string(10).unwrap(), "coalescence");
+    assert_eq!(dispersal.get_ulong(11).unwrap(), 0);
+    assert_eq!(rows[0].get_string(10).unwrap(), "none");
+    assert!(is_null(&rows[0], 11));
+
+    let speciation = &rows[4];
+    assert_eq!(speciation.get_string(1).unwrap(), "speciation");
+    assert!((speciation.get_double(3).unwrap() - 2.0).abs() < f64::EPSILON);
+    assert_eq!(
+        (
+            speciation.get_uint(4).unwrap(),
+            speciation.get_uint(5).unwrap(),
+            speciation.get_uint(6).unwrap()
+        ),
+        (7, 8, 9)
+    );
+    assert!((7..12).all(|column| is_null(speciation, column)));
+}
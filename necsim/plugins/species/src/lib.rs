@@ -5,7 +5,7 @@ extern crate log;
 
 use std::{convert::TryFrom, fmt, path::PathBuf};
 
-use rusqlite::{params, Connection, Statement};
+use rusqlite::{params, Connection};
 use serde::Deserialize;
 
 use necsim_core::{
@@ -32,9 +32,10 @@ pub struct SpeciesLocationsReporter {
 
     connection: Connection,
 
-    insertion: Option<Statement<'static>>,
-    speciation: Option<Statement<'static>>,
-    coalescence: Option<Statement<'static>>,
+    // The statements are prepared lazily from the connection's statement cache
+    insertion: String,
+    speciation: String,
+    coalescence: String,
 }
 
 impl fmt::Debug for SpeciesLocationsReporter {
@@ -64,6 +65,13 @@ impl TryFrom<SpeciesLocationsReporterArgs> for SpeciesLocationsReporter {
     fn try_from(args: SpeciesLocationsReporterArgs) -> Result<Self, Self::Error> {
         let connection = Connection::open(&args.output)?;
 
+        let insertion = format!(
+            "INSERT OR REPLACE INTO {} VALUES (?1, ?2, ?3, ?4, ?1, NULL)",
+            args.table,
+        );
+        let speciation = format!("UPDATE {} SET species = ?2 WHERE id = ?1", args.table);
+        let coalescence = format!("UPDATE {} SET parent = ?2 WHERE id = ?1", args.table);
+
         Ok(Self {
             last_parent_prior_time: None,
             last_speciation_event: None,
@@ -74,9 +82,9 @@ impl TryFrom<SpeciesLocationsReporterArgs> for SpeciesLocationsReporter {
 
             connection,
 
-            insertion: None,
-            speciation: None,
-            coalescence: None,
+            insertion,
+            speciation,
+            coalescence,
         })
     }
 }
@@ -128,26 +136,13 @@ impl Reporter for SpeciesLocationsReporter {
 
     impl_report!(progress(&mut self, _progress: Ignored) {});
 
-    impl_finalise!((mut self) {
-        let output = self.output.clone();
-        let table = self.table.clone();
-
-        if let Err(err) = (|| -> rusqlite::Result<()> {
-            if let Some(insertion) = self.insertion.take() {
-                insertion.finalize()?;
-            }
-
-            if let Some(speciation) = self.speciation.take() {
-                speciation.finalize()?;
-            }
-
-            if let Some(coalescence) = self.coalescence.take() {
-                coalescence.finalize()?;
-            }
-
-            self.connection.close().map_err(|(_, err)| err)
-        })() {
-            error!("Failed to write the lineage locations to table {:?} at {:?}: {:?}", table, output, err);
+    impl_finalise!((self) {
+        // Closing the connection also finalises all cached statements
+        if let Err((_, err)) = self.connection.close() {
+            error!(
+                "Failed to write the lineage locations to table {:?} at {:?}: {:?}",
+                self.table, self.output, err
+            );
         }
     });
 
@@ -168,18 +163,10 @@ impl Reporter for SpeciesLocationsReporter {
                 [],
             )?;
 
-            self.insertion = Some(extend_statement(self.connection.prepare(&format!(
-                "INSERT OR REPLACE INTO {} VALUES (?1, ?2, ?3, ?4, ?1, NULL)",
-                self.table,
-            ))?));
-            self.speciation = Some(extend_statement(self.connection.prepare(&format!(
-                "UPDATE {} SET species = ?2 WHERE id = ?1",
-                self.table
-            ))?));
-            self.coalescence = Some(extend_statement(self.connection.prepare(&format!(
-                "UPDATE {} SET parent = ?2 WHERE id = ?1",
-                self.table
-            ))?));
+            // Check that all statements can be prepared before the simulation
+            self.connection.prepare_cached(&self.insertion)?;
+            self.connection.prepare_cached(&self.speciation)?;
+            self.connection.prepare_cached(&self.coalescence)?;
 
             Ok(())
         })()
@@ -194,11 +181,11 @@ impl Reporter for SpeciesLocationsReporter {
 
 impl SpeciesLocationsReporter {
     fn store_individual_origin(
-        &mut self,
+        &self,
         individual: &GlobalLineageReference,
         origin: &IndexedLocation,
     ) {
-        if let Some(insertion) = &mut self.insertion {
+        if let Ok(mut insertion) = self.connection.prepare_cached(&self.insertion) {
             std::mem::drop(insertion.execute(params![
                 individual.to_string(),
                 origin.location().x(),
@@ -208,8 +195,8 @@ impl SpeciesLocationsReporter {
         }
     }
 
-    fn store_individual_speciation(&mut self, event: &SpeciationEvent) {
-        if let Some(speciation) = &mut self.speciation {
+    fn store_individual_speciation(&self, event: &SpeciationEvent) {
+        if let Ok(mut speciation) = self.connection.prepare_cached(&self.speciation) {
             let location = (u64::from(event.origin.location().y()) << 32)
                 | u64::from(event.origin.location().x());
             let index = u64::from(event.origin.index()) << 16;
@@ -230,11 +217,11 @@ impl SpeciesLocationsReporter {
     }
 
     fn store_individual_coalescence(
-        &mut self,
+        &self,
         individual: &GlobalLineageReference,
         parent: &GlobalLineageReference,
     ) {
-        if let Some(coalescence) = &mut self.coalescence {
+        if let Ok(mut coalescence) = self.connection.prepare_cached(&self.coalescence) {
             std::mem::drop(
                 coalescence.execute(params![individual.to_string(), parent.to_string(),]),
             );
@@ -265,7 +252,3 @@ const fn seahash_diffuse(mut x: u64) -> u64 {
 
     x
 }
-
-fn extend_statement<'conn>(statement: Statement<'conn>) -> Statement<'static> {
-    unsafe { std::mem::transmute::<Statement<'conn>, Statement<'static>>(statement) }
-}
@@ -0,0 +1,199 @@
+//! Distribution functions of the test statistics, adapted from Press et al.,
+//!  Numerical Recipes in C (2nd ed.), chapters 6 and 14
+
+const MAX_ITERATIONS: usize = 1000;
+const EPSILON: f64 = 1.0e-15_f64;
+const TINY: f64 = 1.0e-300_f64;
+
+/// Computes Pearson's chi-squared statistic of the `observed` counts against
+///  the `expected` counts
+pub fn chi_squared(observed: &[u64], expected: &[f64]) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    observed
+        .iter()
+        .zip(expected)
+        .map(|(observed, expected)| {
+            let difference = (*observed as f64) - expected;
+
+            difference * difference / expected
+        })
+        .sum()
+}
+
+/// Upper tail probability of the chi-squared distribution with
+///  `degrees_of_freedom`
+pub fn chi_squared_p_value(statistic: f64, degrees_of_freedom: usize) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    regularised_upper_gamma((degrees_of_freedom as f64) * 0.5_f64, statistic * 0.5_f64)
+}
+
+/// Upper tail probability of the Kolmogorov-Smirnov statistic `d` for `n`
+///  samples
+pub fn kolmogorov_smirnov_p_value(d: f64, n: usize) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let sqrt_n = (n as f64).sqrt();
+    let lambda = (sqrt_n + 0.12_f64 + 0.11_f64 / sqrt_n) * d;
+
+    let mut sign = 2.0_f64;
+    let mut sum = 0.0_f64;
+    let mut previous_term = 0.0_f64;
+
+    for j in 1..=100_u32 {
+        let j = f64::from(j);
+
+        let term = sign * (-2.0_f64 * lambda * lambda * j * j).exp();
+        sum += term;
+
+        if term.abs() <= 0.001_f64 * previous_term || term.abs() <= 1.0e-8_f64 * sum {
+            return sum.max(0.0_f64).min(1.0_f64);
+        }
+
+        sign = -sign;
+        previous_term = term.abs();
+    }
+
+    // The series only fails to converge for very small statistics
+    1.0_f64
+}
+
+/// Cumulative distribution function of the standard normal distribution
+pub fn standard_normal_cdf(x: f64) -> f64 {
+    0.5_f64 * erfc(-x * core::f64::consts::FRAC_1_SQRT_2)
+}
+
+/// Probability mass function of the Poisson distribution with mean `lambda`
+pub fn poisson_pmf(k: u64, lambda: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let k = k as f64;
+
+    (k * lambda.ln() - lambda - ln_gamma(k + 1.0_f64)).exp()
+}
+
+/// Probability mass function of the binomial distribution with `n` trials
+///  and a success probability of one half
+pub fn fair_binomial_pmf(k: u64, n: u64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let (k, n) = (k as f64, n as f64);
+
+    (ln_gamma(n + 1.0_f64)
+        - ln_gamma(k + 1.0_f64)
+        - ln_gamma(n - k + 1.0_f64)
+        - n * core::f64::consts::LN_2)
+        .exp()
+}
+
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46_f64,
+        -86.505_320_329_416_78_f64,
+        24.014_098_240_830_91_f64,
+        -1.231_739_572_450_155_f64,
+        1.208_650_973_866_179e-3_f64,
+        -0.539_523_938_495_3e-5_f64,
+    ];
+
+    let tmp = x + 5.5_f64;
+    let tmp = tmp - (x + 0.5_f64) * tmp.ln();
+
+    let mut y = x;
+    let mut series = 1.000_000_000_190_015_f64;
+
+    for coefficient in &COEFFICIENTS {
+        y += 1.0_f64;
+        series += coefficient / y;
+    }
+
+    -tmp + (2.506_628_274_631_000_7_f64 * series / x).ln()
+}
+
+/// Regularised upper incomplete gamma function Q(a, x)
+fn regularised_upper_gamma(a: f64, x: f64) -> f64 {
+    if x <= 0.0_f64 {
+        return 1.0_f64;
+    }
+
+    if x < (a + 1.0_f64) {
+        1.0_f64 - lower_gamma_series(a, x)
+    } else {
+        upper_gamma_continued_fraction(a, x)
+    }
+}
+
+fn lower_gamma_series(a: f64, x: f64) -> f64 {
+    let mut denominator = a;
+    let mut term = 1.0_f64 / a;
+    let mut sum = term;
+
+    for _ in 0..MAX_ITERATIONS {
+        denominator += 1.0_f64;
+        term *= x / denominator;
+        sum += term;
+
+        if term.abs() < sum.abs() * EPSILON {
+            break;
+        }
+    }
+
+    sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+#[allow(clippy::many_single_char_names)]
+fn upper_gamma_continued_fraction(a: f64, x: f64) -> f64 {
+    // Modified Lentz's method
+    let mut b = x + 1.0_f64 - a;
+    let mut c = 1.0_f64 / TINY;
+    let mut d = 1.0_f64 / b;
+    let mut h = d;
+
+    for i in 1..=MAX_ITERATIONS {
+        #[allow(clippy::cast_precision_loss)]
+        let i = i as f64;
+
+        let an = -i * (i - a);
+        b += 2.0_f64;
+
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+
+        d = 1.0_f64 / d;
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0_f64).abs() < EPSILON {
+            break;
+        }
+    }
+
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+/// Complementary error function with a fractional error below 1.2e-7
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0_f64 / (1.0_f64 + 0.5_f64 * z);
+
+    let polynomial = -1.265_512_23_f64
+        + t * (1.000_023_68_f64
+            + t * (0.374_091_96_f64
+                + t * (0.096_784_18_f64
+                    + t * (-0.186_288_06_f64
+                        + t * (0.278_868_07_f64
+                            + t * (-1.135_203_98_f64
+                                + t * (1.488_515_87_f64
+                                    + t * (-0.822_152_23_f64 + t * 0.170_872_77_f64))))))));
+
+    let ans = t * (-z * z + polynomial).exp();
+
+    if x >= 0.0_f64 {
+        ans
+    } else {
+        2.0_f64 - ans
+    }
+}
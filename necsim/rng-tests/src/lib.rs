@@ -0,0 +1,81 @@
+#![deny(clippy::pedantic)]
+#![feature(total_cmp)]
+
+//! `necsim-rng-tests` runs a battery of statistical tests on `RngCore`
+//!  implementations, on the distributions of the derived `RngSampler`
+//!  methods, and on the correlations between the streams of a
+//!  `PrimeableRng` which are primed with nearby locations and times.
+
+use std::fmt;
+
+mod distribution;
+
+pub mod raw;
+pub mod sampler;
+pub mod stream;
+
+/// Two-sided significance level below which (or above one minus which) a
+///  test's p-value is regarded as a failure
+pub const SIGNIFICANCE_LEVEL: f64 = 1.0e-6_f64;
+
+/// Outcome of a single statistical test
+#[derive(Debug, Clone)]
+#[allow(clippy::module_name_repetitions)]
+pub struct TestOutcome {
+    pub name: String,
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+impl TestOutcome {
+    #[must_use]
+    pub fn new(name: impl Into<String>, statistic: f64, p_value: f64) -> Self {
+        Self {
+            name: name.into(),
+            statistic,
+            p_value,
+        }
+    }
+
+    /// Tests whether the p-value lies within the two-sided significance
+    ///  level, such that both too bad and too good fits are failures
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.p_value.is_finite()
+            && self.p_value >= SIGNIFICANCE_LEVEL
+            && self.p_value <= (1.0_f64 - SIGNIFICANCE_LEVEL)
+    }
+}
+
+impl fmt::Display for TestOutcome {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "{}: statistic = {:.4}, p = {:.6} ({})",
+            self.name,
+            self.statistic,
+            self.p_value,
+            if self.passed() { "passed" } else { "FAILED" }
+        )
+    }
+}
+
+/// Asserts that all tests in the battery `outcomes` of the RNG `name` passed
+///
+/// # Panics
+///
+/// Panics with a report of the whole battery if any test failed.
+pub fn assert_passed(name: &str, outcomes: &[TestOutcome]) {
+    let report = outcomes
+        .iter()
+        .map(|outcome| format!("  {}", outcome))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(
+        outcomes.iter().all(TestOutcome::passed),
+        "{} failed the statistical test battery:\n{}",
+        name,
+        report
+    );
+}
@@ -0,0 +1,247 @@
+//! Tests of the raw `u64` output of an `RngCore`
+
+use necsim_core::cogs::{RngCore, RngSampler};
+
+use crate::{
+    distribution::{chi_squared, chi_squared_p_value, kolmogorov_smirnov_p_value, poisson_pmf},
+    TestOutcome,
+};
+
+const FREQUENCY_SAMPLES: usize = 1 << 16;
+
+const GAP_LOWER: f64 = 0.25_f64;
+const GAP_UPPER: f64 = 0.5_f64;
+const GAP_CLASSES: usize = 16;
+const GAP_COUNT: usize = 1 << 14;
+
+const SERIAL_BITS: u32 = 4;
+const SERIAL_PAIRS: usize = 1 << 16;
+
+const BIRTHDAY_BITS: u32 = 24;
+const BIRTHDAY_BIRTHDAYS: usize = 512;
+const BIRTHDAY_REPETITIONS: usize = 1000;
+const BIRTHDAY_CLASSES: usize = 7;
+
+pub(crate) const KOLMOGOROV_SMIRNOV_SAMPLES: usize = 1 << 14;
+
+/// Runs the frequency, gap, serial, birthday spacings and Kolmogorov-Smirnov
+///  tests on the output of the `rng`
+#[must_use]
+pub fn battery<G: RngCore>(mut rng: G) -> Vec<TestOutcome> {
+    vec![
+        frequency(&mut rng),
+        gap(&mut rng),
+        serial(&mut rng),
+        birthday_spacings(&mut rng),
+        kolmogorov_smirnov(&mut rng),
+    ]
+}
+
+/// Tests that every bit of the output is set with probability one half
+pub fn frequency<G: RngCore>(rng: &mut G) -> TestOutcome {
+    bit_frequency(
+        "frequency",
+        (0..FREQUENCY_SAMPLES).map(|_| rng.sample_u64()),
+    )
+}
+
+/// Tests the distribution of the gaps between uniform samples which fall
+///  into [0.25, 0.5)
+pub fn gap<G: RngCore>(rng: &mut G) -> TestOutcome {
+    let mut observed = [0_u64; GAP_CLASSES + 1];
+    let mut gap = 0_usize;
+    let mut gaps = 0_usize;
+
+    while gaps < GAP_COUNT {
+        let u = rng.sample_uniform().get();
+
+        if (GAP_LOWER..GAP_UPPER).contains(&u) {
+            observed[gap.min(GAP_CLASSES)] += 1;
+
+            gap = 0;
+            gaps += 1;
+        } else {
+            gap += 1;
+        }
+    }
+
+    let p = GAP_UPPER - GAP_LOWER;
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap
+    )]
+    let expected = (0..=GAP_CLASSES)
+        .map(|r| {
+            let probability = if r < GAP_CLASSES {
+                p * (1.0_f64 - p).powi(r as i32)
+            } else {
+                (1.0_f64 - p).powi(r as i32)
+            };
+
+            probability * (GAP_COUNT as f64)
+        })
+        .collect::<Vec<_>>();
+
+    let statistic = chi_squared(&observed, &expected);
+
+    TestOutcome::new(
+        "gap",
+        statistic,
+        chi_squared_p_value(statistic, GAP_CLASSES),
+    )
+}
+
+/// Tests that non-overlapping pairs of outputs are equidistributed in two
+///  dimensions
+pub fn serial<G: RngCore>(rng: &mut G) -> TestOutcome {
+    pair_equidistribution(
+        "serial",
+        (0..SERIAL_PAIRS).map(|_| (rng.sample_u64(), rng.sample_u64())),
+    )
+}
+
+/// Marsaglia's birthday spacings test, which checks that the number of
+///  repeated spacings between sorted birthdays is Poisson distributed
+pub fn birthday_spacings<G: RngCore>(rng: &mut G) -> TestOutcome {
+    let mut observed = [0_u64; BIRTHDAY_CLASSES];
+
+    let mut birthdays = vec![0_u64; BIRTHDAY_BIRTHDAYS];
+    let mut spacings = vec![0_u64; BIRTHDAY_BIRTHDAYS];
+
+    for _ in 0..BIRTHDAY_REPETITIONS {
+        for birthday in &mut birthdays {
+            *birthday = rng.sample_u64() >> (64 - BIRTHDAY_BITS);
+        }
+
+        birthdays.sort_unstable();
+
+        spacings[0] = birthdays[0];
+        for (spacing, pair) in spacings[1..].iter_mut().zip(birthdays.windows(2)) {
+            *spacing = pair[1] - pair[0];
+        }
+
+        spacings.sort_unstable();
+
+        let repeated = spacings
+            .windows(2)
+            .filter(|pair| pair[0] == pair[1])
+            .count();
+
+        observed[repeated.min(BIRTHDAY_CLASSES - 1)] += 1;
+    }
+
+    // The number of repeated spacings is asymptotically Poisson distributed
+    //  with mean m^3 / (4n) for m birthdays in a year of n days
+    #[allow(clippy::cast_precision_loss)]
+    let lambda =
+        (BIRTHDAY_BIRTHDAYS as f64).powi(3) / (4.0_f64 * f64::from(1_u32 << BIRTHDAY_BITS));
+
+    let mut expected = (0..(BIRTHDAY_CLASSES as u64 - 1))
+        .map(|k| poisson_pmf(k, lambda))
+        .collect::<Vec<_>>();
+    expected.push(1.0_f64 - expected.iter().sum::<f64>());
+
+    #[allow(clippy::cast_precision_loss)]
+    for expected in &mut expected {
+        *expected *= BIRTHDAY_REPETITIONS as f64;
+    }
+
+    let statistic = chi_squared(&observed, &expected);
+
+    TestOutcome::new(
+        "birthday spacings",
+        statistic,
+        chi_squared_p_value(statistic, BIRTHDAY_CLASSES - 1),
+    )
+}
+
+/// Tests that the output, scaled to [0, 1), is uniformly distributed
+pub fn kolmogorov_smirnov<G: RngCore>(rng: &mut G) -> TestOutcome {
+    kolmogorov_smirnov_test(
+        "Kolmogorov-Smirnov",
+        (0..KOLMOGOROV_SMIRNOV_SAMPLES).map(|_| rng.sample_uniform().get()),
+        |u| u,
+    )
+}
+
+pub(crate) fn bit_frequency(name: &str, samples: impl Iterator<Item = u64>) -> TestOutcome {
+    let mut ones = [0_u64; 64];
+    let mut n = 0_u64;
+
+    for sample in samples {
+        for (bit, ones) in ones.iter_mut().enumerate() {
+            *ones += (sample >> bit) & 0x1;
+        }
+
+        n += 1;
+    }
+
+    // Each bit count is approximately normal with variance n / 4
+    #[allow(clippy::cast_precision_loss)]
+    let statistic = ones
+        .iter()
+        .map(|ones| {
+            let difference = 2.0_f64 * (*ones as f64) - (n as f64);
+
+            difference * difference / (n as f64)
+        })
+        .sum::<f64>();
+
+    TestOutcome::new(name, statistic, chi_squared_p_value(statistic, ones.len()))
+}
+
+pub(crate) fn pair_equidistribution(
+    name: &str,
+    pairs: impl Iterator<Item = (u64, u64)>,
+) -> TestOutcome {
+    let cells = 1_usize << (2 * SERIAL_BITS);
+
+    let mut observed = vec![0_u64; cells];
+    let mut n = 0_usize;
+
+    #[allow(clippy::cast_possible_truncation)]
+    for (first, second) in pairs {
+        let cell = ((first >> (64 - SERIAL_BITS)) << SERIAL_BITS) | (second >> (64 - SERIAL_BITS));
+
+        observed[cell as usize] += 1;
+        n += 1;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let expected = vec![(n as f64) / (cells as f64); cells];
+
+    let statistic = chi_squared(&observed, &expected);
+
+    TestOutcome::new(name, statistic, chi_squared_p_value(statistic, cells - 1))
+}
+
+pub(crate) fn kolmogorov_smirnov_test(
+    name: &str,
+    samples: impl Iterator<Item = f64>,
+    cdf: impl Fn(f64) -> f64,
+) -> TestOutcome {
+    let mut samples = samples.collect::<Vec<_>>();
+    samples.sort_unstable_by(f64::total_cmp);
+
+    #[allow(clippy::cast_precision_loss)]
+    let n = samples.len() as f64;
+
+    #[allow(clippy::cast_precision_loss)]
+    let statistic = samples
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let cdf = cdf(*x);
+
+            ((((i + 1) as f64) / n) - cdf).max(cdf - ((i as f64) / n))
+        })
+        .fold(0.0_f64, f64::max);
+
+    TestOutcome::new(
+        name,
+        statistic,
+        kolmogorov_smirnov_p_value(statistic, samples.len()),
+    )
+}
@@ -0,0 +1,83 @@
+//! Tests of the distributions sampled by the `RngSampler` methods
+
+use necsim_core::cogs::{RngCore, RngSampler};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use crate::{
+    distribution::standard_normal_cdf,
+    raw::{kolmogorov_smirnov_test, KOLMOGOROV_SMIRNOV_SAMPLES},
+    TestOutcome,
+};
+
+const EXPONENTIAL_LAMBDA: f64 = 2.5_f64;
+
+const NORMAL_MU: f64 = -1.5_f64;
+const NORMAL_SIGMA: f64 = 4.0_f64;
+
+/// Runs Kolmogorov-Smirnov tests on the distributions sampled by
+///  `sample_uniform`, `sample_exponential` and `sample_2d_normal`
+#[must_use]
+pub fn battery<G: RngCore>(mut rng: G) -> Vec<TestOutcome> {
+    vec![
+        uniform(&mut rng),
+        exponential(&mut rng),
+        normal_marginal(&mut rng),
+        normal_radius(&mut rng),
+    ]
+}
+
+/// Tests that `sample_uniform` follows U(0, 1)
+pub fn uniform<G: RngCore>(rng: &mut G) -> TestOutcome {
+    kolmogorov_smirnov_test(
+        "sample_uniform",
+        (0..KOLMOGOROV_SMIRNOV_SAMPLES).map(|_| rng.sample_uniform().get()),
+        |x| x,
+    )
+}
+
+/// Tests that `sample_exponential` follows Exp(2.5)
+pub fn exponential<G: RngCore>(rng: &mut G) -> TestOutcome {
+    let lambda = unsafe { PositiveF64::new_unchecked(EXPONENTIAL_LAMBDA) };
+
+    kolmogorov_smirnov_test(
+        "sample_exponential",
+        (0..KOLMOGOROV_SMIRNOV_SAMPLES).map(|_| rng.sample_exponential(lambda).get()),
+        |x| 1.0_f64 - (-EXPONENTIAL_LAMBDA * x).exp(),
+    )
+}
+
+/// Tests that both coordinates of `sample_2d_normal` follow N(-1.5, 4^2)
+pub fn normal_marginal<G: RngCore>(rng: &mut G) -> TestOutcome {
+    let sigma = unsafe { NonNegativeF64::new_unchecked(NORMAL_SIGMA) };
+
+    kolmogorov_smirnov_test(
+        "sample_2d_normal marginals",
+        (0..(KOLMOGOROV_SMIRNOV_SAMPLES / 2)).flat_map(|_| {
+            let (x, y) = rng.sample_2d_normal(NORMAL_MU, sigma);
+
+            [x, y]
+        }),
+        |x| standard_normal_cdf((x - NORMAL_MU) / NORMAL_SIGMA),
+    )
+}
+
+/// Tests that the squared standardised distance of `sample_2d_normal`
+///  from its mean follows the chi-squared distribution with two degrees of
+///  freedom, which is only the case if both coordinates are independent
+pub fn normal_radius<G: RngCore>(rng: &mut G) -> TestOutcome {
+    let sigma = unsafe { NonNegativeF64::new_unchecked(NORMAL_SIGMA) };
+
+    kolmogorov_smirnov_test(
+        "sample_2d_normal radius",
+        (0..KOLMOGOROV_SMIRNOV_SAMPLES).map(|_| {
+            let (x, y) = rng.sample_2d_normal(NORMAL_MU, sigma);
+            let (x, y) = (
+                (x - NORMAL_MU) / NORMAL_SIGMA,
+                (y - NORMAL_MU) / NORMAL_SIGMA,
+            );
+
+            x * x + y * y
+        }),
+        |r| 1.0_f64 - (-0.5_f64 * r).exp(),
+    )
+}
@@ -0,0 +1,123 @@
+//! Tests of the correlations between the streams of a `PrimeableRng` which
+//!  are primed with neighbouring `(location, time)` indices
+
+use necsim_core::cogs::PrimeableRng;
+
+use crate::{
+    distribution::{chi_squared, chi_squared_p_value, fair_binomial_pmf},
+    raw::{bit_frequency, kolmogorov_smirnov_test, pair_equidistribution},
+    TestOutcome,
+};
+
+const LOCATIONS: u64 = 256;
+const TIMES: u64 = 256;
+
+// Hamming distances outside of [24, 40] are merged into the outermost classes
+const AVALANCHE_MIN: u64 = 24;
+const AVALANCHE_MAX: u64 = 40;
+
+/// First two outputs of the streams on a grid of consecutive location and
+///  time indices
+struct StreamGrid {
+    outputs: Vec<(u64, u64)>,
+}
+
+impl StreamGrid {
+    #[allow(clippy::cast_possible_truncation)]
+    fn sample<G: PrimeableRng>(rng: &mut G) -> Self {
+        let mut outputs = Vec::with_capacity((LOCATIONS * TIMES) as usize);
+
+        for location in 0..LOCATIONS {
+            for time in 0..TIMES {
+                rng.prime_with(location, time);
+
+                outputs.push((rng.sample_u64(), rng.sample_u64()));
+            }
+        }
+
+        Self { outputs }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn first(&self, location: u64, time: u64) -> u64 {
+        self.outputs[(location * TIMES + time) as usize].0
+    }
+
+    fn firsts(&self) -> impl Iterator<Item = u64> + '_ {
+        self.outputs.iter().map(|(first, _)| *first)
+    }
+
+    /// Pairs of the first outputs of streams at consecutive times
+    fn time_neighbours(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        (0..LOCATIONS).flat_map(move |location| {
+            (1..TIMES).map(move |time| (self.first(location, time - 1), self.first(location, time)))
+        })
+    }
+
+    /// Pairs of the first outputs of streams at consecutive locations
+    fn location_neighbours(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        (1..LOCATIONS).flat_map(move |location| {
+            (0..TIMES).map(move |time| (self.first(location - 1, time), self.first(location, time)))
+        })
+    }
+}
+
+/// Runs frequency, serial, avalanche and Kolmogorov-Smirnov tests across
+///  the streams of the `rng` which are primed with a grid of consecutive
+///  location and time indices
+#[must_use]
+pub fn battery<G: PrimeableRng>(mut rng: G) -> Vec<TestOutcome> {
+    let grid = StreamGrid::sample(&mut rng);
+
+    #[allow(clippy::cast_precision_loss)]
+    let uniforms = grid
+        .firsts()
+        .map(|first| ((first >> 11) as f64) * f64::from_bits(0x3CA0_0000_0000_0000_u64)); // 0x1.0p-53
+
+    vec![
+        bit_frequency("stream frequency", grid.firsts()),
+        kolmogorov_smirnov_test("stream Kolmogorov-Smirnov", uniforms, |u| u),
+        pair_equidistribution("stream serial within", grid.outputs.iter().copied()),
+        pair_equidistribution("stream serial across times", grid.time_neighbours()),
+        pair_equidistribution("stream serial across locations", grid.location_neighbours()),
+        avalanche("stream avalanche across times", grid.time_neighbours()),
+        avalanche(
+            "stream avalanche across locations",
+            grid.location_neighbours(),
+        ),
+    ]
+}
+
+/// Tests that the Hamming distance between the outputs of neighbouring
+///  streams follows the binomial distribution B(64, 0.5)
+#[allow(clippy::cast_possible_truncation)]
+fn avalanche(name: &str, pairs: impl Iterator<Item = (u64, u64)>) -> TestOutcome {
+    let classes = (AVALANCHE_MAX - AVALANCHE_MIN + 1) as usize;
+
+    let mut observed = vec![0_u64; classes];
+    let mut n = 0_u64;
+
+    for (first, second) in pairs {
+        let distance = u64::from((first ^ second).count_ones());
+
+        observed[(distance.max(AVALANCHE_MIN).min(AVALANCHE_MAX) - AVALANCHE_MIN) as usize] += 1;
+        n += 1;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let expected = (AVALANCHE_MIN..=AVALANCHE_MAX)
+        .map(|distance| {
+            let probability = match distance {
+                AVALANCHE_MIN => (0..=AVALANCHE_MIN).map(|k| fair_binomial_pmf(k, 64)).sum(),
+                AVALANCHE_MAX => (AVALANCHE_MAX..=64).map(|k| fair_binomial_pmf(k, 64)).sum(),
+                distance => fair_binomial_pmf(distance, 64),
+            };
+
+            probability * (n as f64)
+        })
+        .collect::<Vec<_>>();
+
+    let statistic = chi_squared(&observed, &expected);
+
+    TestOutcome::new(name, statistic, chi_squared_p_value(statistic, classes - 1))
+}
@@ -0,0 +1,49 @@
+use necsim_core::cogs::RngCore;
+
+use necsim_impls_no_std::cogs::rng::{
+    aes::AesRng, fixedseahash::FixedSeaHash, philox::Philox4x32, seahash::SeaHash,
+    threefry::Threefry4x64, wyhash::WyHash, wyrand::WyRand, xxhash::XxHash,
+};
+use necsim_impls_std::cogs::rng::{pcg::Pcg, std::StdRng};
+
+use necsim_rng_tests::{assert_passed, raw, sampler, stream};
+
+const SEED: u64 = 0x2A3F_8E1C_5B7D_9064;
+
+macro_rules! test_rngs {
+    ($($name:ident: $rng:ty $(=> $primeable:ident)?),* $(,)?) => {
+        $(mod $name {
+            use super::*;
+
+            #[test]
+            fn raw_battery() {
+                assert_passed(stringify!($rng), &raw::battery(<$rng>::seed_from_u64(SEED)));
+            }
+
+            #[test]
+            fn sampler_battery() {
+                assert_passed(stringify!($rng), &sampler::battery(<$rng>::seed_from_u64(SEED)));
+            }
+
+            $(
+            #[test]
+            fn $primeable() {
+                assert_passed(stringify!($rng), &stream::battery(<$rng>::seed_from_u64(SEED)));
+            }
+            )?
+        })*
+    };
+}
+
+test_rngs! {
+    aes: AesRng => stream_battery,
+    fixedseahash: FixedSeaHash => stream_battery,
+    philox: Philox4x32 => stream_battery,
+    seahash: SeaHash => stream_battery,
+    threefry: Threefry4x64 => stream_battery,
+    wyhash: WyHash => stream_battery,
+    wyrand: WyRand,
+    xxhash: XxHash => stream_battery,
+    pcg: Pcg,
+    std: StdRng,
+}
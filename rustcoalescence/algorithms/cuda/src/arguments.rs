@@ -1,26 +1,26 @@
 use std::num::{NonZeroU64, NonZeroUsize};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_state::DeserializeState;
 
 use necsim_core_bond::{Partition, PositiveF64};
 
 use necsim_impls_no_std::parallelisation::independent::{DedupCache, RelativeDedupCache};
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct MonolithicParallelismMode {
     pub event_slice: NonZeroUsize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct IsolatedParallelismMode {
     pub event_slice: NonZeroUsize,
     pub partition: Partition,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ParallelismMode {
     Monolithic(MonolithicParallelismMode),
     IsolatedIndividuals(IsolatedParallelismMode),
@@ -52,17 +52,29 @@ impl<'de> DeserializeState<'de, Partition> for ParallelismMode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+pub enum CudaRngChoice {
+    WyHash,
+    #[serde(alias = "Philox")]
+    Philox4x32,
+    #[serde(alias = "Threefry")]
+    Threefry4x64,
+}
+
+#[derive(Clone, Debug, Serialize)]
 #[allow(clippy::module_name_repetitions)]
 pub struct CudaArguments {
     pub device: u32,
     pub ptx_jit: bool,
     pub delta_t: PositiveF64,
+    pub skipping: bool,
     pub block_size: u32,
     pub grid_size: u32,
     pub step_slice: NonZeroU64,
     pub dedup_cache: DedupCache,
     pub parallelism_mode: ParallelismMode,
+    pub rng: CudaRngChoice,
 }
 
 impl<'de> DeserializeState<'de, Partition> for CudaArguments {
@@ -88,11 +100,13 @@ impl<'de> DeserializeState<'de, Partition> for CudaArguments {
             device: raw.device,
             ptx_jit: raw.ptx_jit,
             delta_t: raw.delta_t,
+            skipping: raw.skipping,
             block_size: raw.block_size,
             grid_size: raw.grid_size,
             step_slice: raw.step_slice,
             dedup_cache: raw.dedup_cache,
             parallelism_mode,
+            rng: raw.rng,
         })
     }
 }
@@ -104,12 +118,14 @@ pub struct CudaArgumentsRaw {
     pub device: u32,
     pub ptx_jit: bool,
     pub delta_t: PositiveF64,
+    pub skipping: bool,
     pub block_size: u32,
     pub grid_size: u32,
     pub step_slice: NonZeroU64,
     pub dedup_cache: DedupCache,
     #[serde(deserialize_state)]
     pub parallelism_mode: Option<ParallelismMode>,
+    pub rng: CudaRngChoice,
 }
 
 impl Default for CudaArgumentsRaw {
@@ -118,6 +134,7 @@ impl Default for CudaArgumentsRaw {
             device: 0_u32,
             ptx_jit: false,
             delta_t: PositiveF64::new(1.0_f64).unwrap(),
+            skipping: false,
             block_size: 32_u32,
             grid_size: 256_u32,
             step_slice: NonZeroU64::new(200_u64).unwrap(),
@@ -125,6 +142,7 @@ impl Default for CudaArgumentsRaw {
                 factor: PositiveF64::new(2.0_f64).unwrap(),
             }),
             parallelism_mode: None,
+            rng: CudaRngChoice::WyHash,
         }
     }
 }
@@ -1,14 +1,18 @@
 #![deny(clippy::pedantic)]
 #![feature(option_result_unwrap_unchecked)]
 #![feature(drain_filter)]
+#![feature(never_type)]
 
 #[macro_use]
 extern crate serde_derive_state;
 
-use std::collections::VecDeque;
+use std::{collections::VecDeque, marker::PhantomData};
 
 use necsim_core::{
-    cogs::RngCore,
+    cogs::{
+        DispersalSampler, Habitat, PrimeableRng, SeparableDispersalSampler, SpeciationProbability,
+        TurnoverRate,
+    },
     lineage::{GlobalLineageReference, Lineage},
     reporter::Reporter,
     simulation::Simulation,
@@ -16,18 +20,29 @@ use necsim_core::{
 use necsim_core_bond::NonNegativeF64;
 
 use necsim_impls_cuda::cogs::rng::CudaRng;
-use necsim_impls_no_std::cogs::{
-    active_lineage_sampler::independent::{
-        event_time_sampler::exp::ExpEventTimeSampler, IndependentActiveLineageSampler,
+use necsim_impls_no_std::{
+    cogs::{
+        active_lineage_sampler::independent::{
+            event_time_sampler::exp::ExpEventTimeSampler, IndependentActiveLineageSampler,
+        },
+        coalescence_sampler::independent::IndependentCoalescenceSampler,
+        dispersal_sampler::in_memory::{
+            packed_alias::InMemoryPackedAliasDispersalSampler,
+            packed_separable_alias::InMemoryPackedSeparableAliasDispersalSampler,
+        },
+        emigration_exit::never::NeverEmigrationExit,
+        event_sampler::independent::{
+            skipping::SkippingIndependentEventSampler, IndependentEventRate,
+            IndependentEventSampler,
+        },
+        immigration_entry::never::NeverImmigrationEntry,
+        lineage_store::independent::IndependentLineageStore,
+        origin_sampler::{
+            decomposition::DecompositionOriginSampler, pre_sampler::OriginPreSampler,
+        },
+        rng::wyhash::WyHash,
     },
-    coalescence_sampler::independent::IndependentCoalescenceSampler,
-    dispersal_sampler::in_memory::packed_alias::InMemoryPackedAliasDispersalSampler,
-    emigration_exit::never::NeverEmigrationExit,
-    event_sampler::independent::IndependentEventSampler,
-    immigration_entry::never::NeverImmigrationEntry,
-    lineage_store::independent::IndependentLineageStore,
-    origin_sampler::{decomposition::DecompositionOriginSampler, pre_sampler::OriginPreSampler},
-    rng::wyhash::WyHash,
+    parallelisation::SimulationLimits,
 };
 use necsim_partitioning_core::LocalPartition;
 
@@ -43,7 +58,7 @@ use rust_cuda::{
     },
 };
 
-mod arguments;
+pub mod arguments;
 mod cuda;
 mod info;
 mod kernel;
@@ -56,32 +71,36 @@ use arguments::{
 use crate::kernel::SimulationKernel;
 use cuda::with_initialised_cuda;
 
-#[allow(clippy::module_name_repetitions, clippy::empty_enum)]
-pub enum CudaAlgorithm {}
+/// The CUDA algorithm, which is generic over its counter-based or hash-based
+///  primeable RNG
+#[allow(clippy::module_name_repetitions)]
+pub struct CudaAlgorithm<G: PrimeableRng = WyHash>(!, PhantomData<G>);
 
-impl AlgorithmArguments for CudaAlgorithm {
+impl<G: PrimeableRng> AlgorithmArguments for CudaAlgorithm<G> {
     type Arguments = CudaArguments;
 }
 
 #[allow(clippy::type_complexity)]
-impl<O: Scenario<CudaRng<WyHash>>> Algorithm<O> for CudaAlgorithm
+impl<G: PrimeableRng, O: Scenario<CudaRng<G>>> Algorithm<O> for CudaAlgorithm<G>
 where
     O::Habitat: RustToCuda,
-    O::DispersalSampler<InMemoryPackedAliasDispersalSampler<O::Habitat, CudaRng<WyHash>>>:
-        RustToCuda,
+    O::DispersalSampler<InMemoryPackedAliasDispersalSampler<O::Habitat, CudaRng<G>>>: RustToCuda,
+    O::DispersalSampler<InMemoryPackedSeparableAliasDispersalSampler<O::Habitat, CudaRng<G>>>:
+        SeparableDispersalSampler<O::Habitat, CudaRng<G>> + RustToCuda,
     O::TurnoverRate: RustToCuda,
     O::SpeciationProbability: RustToCuda,
 {
     type Error = anyhow::Error;
     type LineageReference = GlobalLineageReference;
     type LineageStore = IndependentLineageStore<O::Habitat>;
-    type Rng = CudaRng<WyHash>;
+    type Rng = CudaRng<G>;
 
     fn initialise_and_simulate<I: Iterator<Item = u64>, R: Reporter, P: LocalPartition<R>>(
         args: Self::Arguments,
         seed: u64,
         scenario: O,
         pre_sampler: OriginPreSampler<I>,
+        limits: SimulationLimits,
         local_partition: &mut P,
     ) -> Result<(NonNegativeF64, u64), Self::Error> {
         let lineages: VecDeque<Lineage> = match args.parallelism_mode {
@@ -103,70 +122,126 @@ where
             ParallelismMode::IsolatedLandscape(IsolatedParallelismMode { partition, .. }) => {
                 DecompositionOriginSampler::new(
                     scenario.sample_habitat(pre_sampler),
-                    &O::decompose(scenario.habitat(), partition.rank(), partition.partitions()),
+                    &scenario.decompose(partition.rank(), partition.partitions()),
                 )
                 .map(|indexed_location| Lineage::new(indexed_location, scenario.habitat()))
                 .collect()
             },
         };
 
-        let (habitat, dispersal_sampler, turnover_rate, speciation_probability) =
-            scenario.build::<InMemoryPackedAliasDispersalSampler<O::Habitat, CudaRng<WyHash>>>();
-        let rng = CudaRng::from(WyHash::seed_from_u64(seed));
-        let lineage_store = IndependentLineageStore::default();
-        let emigration_exit = NeverEmigrationExit::default();
-        let coalescence_sampler = IndependentCoalescenceSampler::default();
-        let event_sampler = IndependentEventSampler::default();
-        let immigration_entry = NeverImmigrationEntry::default();
-
-        let active_lineage_sampler =
-            IndependentActiveLineageSampler::empty(ExpEventTimeSampler::new(args.delta_t));
-
-        let simulation = Simulation::builder()
-            .habitat(habitat)
-            .rng(rng)
-            .speciation_probability(speciation_probability)
-            .dispersal_sampler(dispersal_sampler)
-            .lineage_reference(std::marker::PhantomData::<GlobalLineageReference>)
-            .lineage_store(lineage_store)
-            .emigration_exit(emigration_exit)
-            .coalescence_sampler(coalescence_sampler)
-            .turnover_rate(turnover_rate)
-            .event_sampler(event_sampler)
-            .immigration_entry(immigration_entry)
-            .active_lineage_sampler(active_lineage_sampler)
-            .build();
-
-        // Note: It seems to be more performant to spawn smaller blocks
-        let block_size = BlockSize::x(args.block_size);
-        let grid_size = GridSize::x(args.grid_size);
-
-        let event_slice = match args.parallelism_mode {
-            ParallelismMode::Monolithic(MonolithicParallelismMode { event_slice })
-            | ParallelismMode::IsolatedIndividuals(IsolatedParallelismMode {
-                event_slice, ..
-            })
-            | ParallelismMode::IsolatedLandscape(IsolatedParallelismMode { event_slice, .. }) => {
-                event_slice
-            },
-        };
-
-        with_initialised_cuda(args.device, || {
-            let stream = CudaDropWrapper::from(Stream::new(StreamFlags::NON_BLOCKING, None)?);
-
-            SimulationKernel::with_kernel(args.ptx_jit, |kernel| {
-                info::print_kernel_function_attributes(kernel.function());
+        if args.skipping {
+            let (habitat, dispersal_sampler, turnover_rate, speciation_probability) =
+                scenario
+                    .build::<InMemoryPackedSeparableAliasDispersalSampler<O::Habitat, CudaRng<G>>>(
+                    );
+
+            simulate_with_cuda(
+                args,
+                seed,
+                (
+                    habitat,
+                    dispersal_sampler,
+                    turnover_rate,
+                    speciation_probability,
+                ),
+                SkippingIndependentEventSampler::default(),
+                lineages,
+                limits,
+                local_partition,
+            )
+        } else {
+            let (habitat, dispersal_sampler, turnover_rate, speciation_probability) =
+                scenario.build::<InMemoryPackedAliasDispersalSampler<O::Habitat, CudaRng<G>>>();
+
+            simulate_with_cuda(
+                args,
+                seed,
+                (
+                    habitat,
+                    dispersal_sampler,
+                    turnover_rate,
+                    speciation_probability,
+                ),
+                IndependentEventSampler::default(),
+                lineages,
+                limits,
+                local_partition,
+            )
+        }
+    }
+}
 
-                parallelisation::monolithic::simulate(
-                    simulation,
-                    kernel,
-                    &stream,
-                    (grid_size, block_size, args.dedup_cache, args.step_slice),
-                    lineages,
-                    event_slice,
-                    local_partition,
-                )
-            })
+#[allow(clippy::type_complexity)]
+fn simulate_with_cuda<
+    H: Habitat + RustToCuda,
+    G: PrimeableRng,
+    D: DispersalSampler<H, CudaRng<G>> + RustToCuda,
+    T: TurnoverRate<H> + RustToCuda,
+    N: SpeciationProbability<H> + RustToCuda,
+    E: IndependentEventRate<H, CudaRng<G>, NeverEmigrationExit, D, T, N> + RustToCuda,
+    R: Reporter,
+    P: LocalPartition<R>,
+>(
+    args: CudaArguments,
+    seed: u64,
+    (habitat, dispersal_sampler, turnover_rate, speciation_probability): (H, D, T, N),
+    event_sampler: E,
+    lineages: VecDeque<Lineage>,
+    limits: SimulationLimits,
+    local_partition: &mut P,
+) -> anyhow::Result<(NonNegativeF64, u64)> {
+    let rng = CudaRng::from(G::seed_from_u64(seed));
+    let lineage_store = IndependentLineageStore::default();
+    let emigration_exit = NeverEmigrationExit::default();
+    let coalescence_sampler = IndependentCoalescenceSampler::default();
+    let immigration_entry = NeverImmigrationEntry::default();
+
+    let active_lineage_sampler =
+        IndependentActiveLineageSampler::empty(ExpEventTimeSampler::new(args.delta_t));
+
+    let simulation = Simulation::builder()
+        .habitat(habitat)
+        .rng(rng)
+        .speciation_probability(speciation_probability)
+        .dispersal_sampler(dispersal_sampler)
+        .lineage_reference(std::marker::PhantomData::<GlobalLineageReference>)
+        .lineage_store(lineage_store)
+        .emigration_exit(emigration_exit)
+        .coalescence_sampler(coalescence_sampler)
+        .turnover_rate(turnover_rate)
+        .event_sampler(event_sampler)
+        .immigration_entry(immigration_entry)
+        .active_lineage_sampler(active_lineage_sampler)
+        .build();
+
+    // Note: It seems to be more performant to spawn smaller blocks
+    let block_size = BlockSize::x(args.block_size);
+    let grid_size = GridSize::x(args.grid_size);
+
+    let event_slice = match args.parallelism_mode {
+        ParallelismMode::Monolithic(MonolithicParallelismMode { event_slice })
+        | ParallelismMode::IsolatedIndividuals(IsolatedParallelismMode { event_slice, .. })
+        | ParallelismMode::IsolatedLandscape(IsolatedParallelismMode { event_slice, .. }) => {
+            event_slice
+        },
+    };
+
+    with_initialised_cuda(args.device, || {
+        let stream = CudaDropWrapper::from(Stream::new(StreamFlags::NON_BLOCKING, None)?);
+
+        SimulationKernel::with_kernel(args.ptx_jit, |kernel| {
+            info::print_kernel_function_attributes(kernel.function());
+
+            parallelisation::monolithic::simulate(
+                simulation,
+                kernel,
+                &stream,
+                (grid_size, block_size, args.dedup_cache, args.step_slice),
+                lineages,
+                event_slice,
+                limits,
+                local_partition,
+            )
         })
-    }
+    })
 }
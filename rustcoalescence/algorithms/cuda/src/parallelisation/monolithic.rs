@@ -26,19 +26,22 @@ use necsim_core::{
         PeekableActiveLineageSampler, PrimeableRng, SingularActiveLineageSampler,
         SpeciationProbability, TurnoverRate,
     },
-    lineage::Lineage,
+    lineage::{Lineage, UnresolvedLineage},
     reporter::{boolean::Boolean, Reporter},
     simulation::Simulation,
 };
 use necsim_core_bond::NonNegativeF64;
 
-use necsim_impls_no_std::parallelisation::independent::{
-    monolithic::reporter::{
-        WaterLevelReporterConstructor, WaterLevelReporterProxy, WaterLevelReporterStrategy,
+use necsim_impls_no_std::parallelisation::{
+    independent::{
+        monolithic::reporter::{
+            WaterLevelReporterConstructor, WaterLevelReporterProxy, WaterLevelReporterStrategy,
+        },
+        DedupCache,
     },
-    DedupCache,
+    SimulationLimits,
 };
-use necsim_partitioning_core::LocalPartition;
+use necsim_partitioning_core::{interrupt::is_interrupted, LocalPartition};
 
 use necsim_impls_cuda::{event_buffer::EventBuffer, value_buffer::ValueBuffer};
 
@@ -86,6 +89,7 @@ pub fn simulate<
     config: (GridSize, BlockSize, DedupCache, NonZeroU64),
     lineages: VecDeque<Lineage>,
     event_slice: NonZeroUsize,
+    limits: SimulationLimits,
     local_partition: &mut L,
 ) -> Result<(NonNegativeF64, u64)> {
     // Ensure that the progress bar starts with the expected target
@@ -127,8 +131,16 @@ pub fn simulate<
 
     let mut slow_lineages = lineages;
     let mut fast_lineages = VecDeque::new();
+    let mut unresolved_lineages = Vec::new();
 
     let mut level_time = NonNegativeF64::zero();
+    let mut total_steps = 0_u64;
+
+    // Events beyond the time limit must not be simulated on the GPU
+    let max_next_event_time = |level_time: NonNegativeF64| match limits.max_time {
+        Some(max_time) if NonNegativeF64::from(max_time) < level_time => max_time.into(),
+        _ => level_time,
+    };
 
     let cpu_habitat = simulation.habitat().backup();
     let cpu_turnover_rate = simulation.turnover_rate().backup();
@@ -137,7 +149,10 @@ pub fn simulate<
     // TODO: Pipeline async launches and callbacks of simulation/event analysis
     simulation
         .lend_to_cuda_mut(|mut simulation_cuda_repr| {
-            while !slow_lineages.is_empty() {
+            while !slow_lineages.is_empty()
+                && !is_interrupted()
+                && limits.permits_steps(total_steps)
+            {
                 let total_event_rate: NonNegativeF64 = if P::ReportDispersal::VALUE {
                     // Full event rate lambda with speciation
                     slow_lineages
@@ -174,7 +189,10 @@ pub fn simulate<
 
                 // Simulate all slow lineages until they have finished or exceeded the new water
                 //  level
-                while !slow_lineages.is_empty() {
+                while !slow_lineages.is_empty()
+                    && !is_interrupted()
+                    && limits.permits_steps(total_steps)
+                {
                     // Upload the new tasks from the front of the task queue
                     for task in task_list.iter_mut() {
                         *task = slow_lineages.pop_front();
@@ -199,10 +217,15 @@ pub fn simulate<
                             &mut total_time_max,
                             &mut total_steps_sum,
                             step_slice.get(),
-                            level_time,
+                            max_next_event_time(level_time),
                         )?;
                     }
 
+                    // Only fetch the total number of steps if the steps are limited
+                    if limits.max_steps.is_some() {
+                        total_steps_sum.copy_to(&mut total_steps)?;
+                    }
+
                     min_spec_sample_buffer = min_spec_sample_buffer_cuda.move_to_host()?;
                     next_event_time_buffer = next_event_time_buffer_cuda.move_to_host()?;
                     task_list = task_list_cuda.move_to_host()?;
@@ -222,8 +245,11 @@ pub fn simulate<
                             (task.take(), next_event_time.take())
                         {
                             if !duplicate_individual {
-                                // Reclassify lineages as either slow (still below water) or fast
-                                if next_event_time < level_time {
+                                // Reclassify lineages as either unresolved (beyond the time
+                                //  limit), slow (still below water) or fast
+                                if !limits.permits_event_at(next_event_time) {
+                                    unresolved_lineages.push(task);
+                                } else if next_event_time < level_time {
                                     slow_lineages.push_back(task);
                                 } else {
                                     fast_lineages.push_back(task);
@@ -250,6 +276,16 @@ pub fn simulate<
     // [Report all remaining events]
     proxy.finalise();
 
+    // All lineages which remain after the simulation stopped early are unresolved
+    unresolved_lineages.extend(slow_lineages);
+    unresolved_lineages.extend(fast_lineages);
+
+    for lineage in &unresolved_lineages {
+        if let Some(lineage) = UnresolvedLineage::from_active_lineage(lineage) {
+            local_partition.get_reporter().report_unresolved(&lineage);
+        }
+    }
+
     let (total_time_max, total_steps_sum) = {
         let mut total_time_max_result = 0_u64;
         let mut total_steps_sum_result = 0_u64;
@@ -264,7 +300,7 @@ pub fn simulate<
         )
     };
 
-    local_partition.report_progress_sync(0_u64);
+    local_partition.report_progress_sync(unresolved_lineages.len() as u64);
 
     Ok(local_partition.reduce_global_time_steps(total_time_max, total_steps_sum))
 }
@@ -1,33 +1,33 @@
 use std::num::{NonZeroU64, NonZeroUsize};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_state::DeserializeState;
 
 use necsim_core_bond::{ClosedUnitF64, Partition, PositiveF64};
 
 use necsim_impls_no_std::parallelisation::independent::{DedupCache, RelativeDedupCache};
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct MonolithicParallelismMode {
     pub event_slice: NonZeroUsize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct IsolatedParallelismMode {
     pub event_slice: NonZeroUsize,
     pub partition: Partition,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ProbabilisticParallelismMode {
     #[serde(alias = "communication")]
     pub communication_probability: ClosedUnitF64,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ParallelismMode {
     Monolithic(MonolithicParallelismMode),
     IsolatedIndividuals(IsolatedParallelismMode),
@@ -72,13 +72,24 @@ impl<'de> DeserializeState<'de, Partition> for ParallelismMode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum IndependentRng {
+    WyHash,
+    #[serde(alias = "Philox")]
+    Philox4x32,
+    #[serde(alias = "Threefry")]
+    Threefry4x64,
+}
+
+#[derive(Clone, Debug, Serialize)]
 #[allow(clippy::module_name_repetitions)]
 pub struct IndependentArguments {
     pub delta_t: PositiveF64,
     pub step_slice: NonZeroU64,
     pub dedup_cache: DedupCache,
     pub parallelism_mode: ParallelismMode,
+    pub skipping: bool,
+    pub rng: IndependentRng,
 }
 
 impl<'de> DeserializeState<'de, Partition> for IndependentArguments {
@@ -106,6 +117,8 @@ impl<'de> DeserializeState<'de, Partition> for IndependentArguments {
             step_slice: raw.step_slice,
             dedup_cache: raw.dedup_cache,
             parallelism_mode,
+            skipping: raw.skipping,
+            rng: raw.rng,
         })
     }
 }
@@ -119,6 +132,8 @@ struct IndependentArgumentsRaw {
     dedup_cache: DedupCache,
     #[serde(deserialize_state)]
     parallelism_mode: Option<ParallelismMode>,
+    skipping: bool,
+    rng: IndependentRng,
 }
 
 impl Default for IndependentArgumentsRaw {
@@ -130,6 +145,8 @@ impl Default for IndependentArgumentsRaw {
                 factor: PositiveF64::new(2.0_f64).unwrap(),
             }),
             parallelism_mode: None,
+            skipping: false,
+            rng: IndependentRng::WyHash,
         }
     }
 }
@@ -5,19 +5,28 @@
 #[macro_use]
 extern crate serde_derive_state;
 
-use std::{collections::VecDeque, hint::unreachable_unchecked};
+use std::{
+    collections::VecDeque,
+    hint::unreachable_unchecked,
+    marker::PhantomData,
+    num::{NonZeroU64, NonZeroUsize},
+};
 
 use arguments::{
     IndependentArguments, IsolatedParallelismMode, MonolithicParallelismMode, ParallelismMode,
     ProbabilisticParallelismMode,
 };
 use necsim_core::{
-    cogs::RngCore,
+    cogs::{
+        DispersalSampler, EmigrationExit, Habitat, PrimeableRng, SeparableDispersalSampler,
+        SpeciationProbability, TurnoverRate,
+    },
     lineage::{GlobalLineageReference, Lineage},
     reporter::Reporter,
     simulation::Simulation,
 };
-use necsim_core_bond::NonNegativeF64;
+
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 use necsim_impls_no_std::{
     cogs::{
@@ -25,17 +34,24 @@ use necsim_impls_no_std::{
             event_time_sampler::poisson::PoissonEventTimeSampler, IndependentActiveLineageSampler,
         },
         coalescence_sampler::independent::IndependentCoalescenceSampler,
-        dispersal_sampler::in_memory::alias::InMemoryAliasDispersalSampler,
+        dispersal_sampler::in_memory::{
+            alias::InMemoryAliasDispersalSampler,
+            separable_alias::InMemorySeparableAliasDispersalSampler,
+        },
         emigration_exit::{
             independent::{
                 choice::{
                     always::AlwaysEmigrationChoice, probabilistic::ProbabilisticEmigrationChoice,
+                    EmigrationChoice,
                 },
                 IndependentEmigrationExit,
             },
             never::NeverEmigrationExit,
         },
-        event_sampler::independent::IndependentEventSampler,
+        event_sampler::independent::{
+            skipping::SkippingIndependentEventSampler, IndependentEventRate,
+            IndependentEventSampler,
+        },
         immigration_entry::never::NeverImmigrationEntry,
         lineage_store::independent::IndependentLineageStore,
         origin_sampler::{
@@ -43,28 +59,35 @@ use necsim_impls_no_std::{
         },
         rng::wyhash::WyHash,
     },
-    parallelisation,
+    decomposition::Decomposition,
+    parallelisation::{self, independent::DedupCache, SimulationLimits},
 };
 use necsim_partitioning_core::LocalPartition;
 
-mod arguments;
+pub mod arguments;
 
 use rustcoalescence_algorithms::{Algorithm, AlgorithmArguments};
 use rustcoalescence_scenarios::Scenario;
 
-#[allow(clippy::module_name_repetitions, clippy::empty_enum)]
-pub enum IndependentAlgorithm {}
+/// The independent algorithm, which is generic over its counter-based or
+///  hash-based primeable RNG
+#[allow(clippy::module_name_repetitions)]
+pub struct IndependentAlgorithm<G: PrimeableRng = WyHash>(!, PhantomData<G>);
 
-impl AlgorithmArguments for IndependentAlgorithm {
+impl<G: PrimeableRng> AlgorithmArguments for IndependentAlgorithm<G> {
     type Arguments = IndependentArguments;
 }
 
 #[allow(clippy::type_complexity)]
-impl<O: Scenario<WyHash>> Algorithm<O> for IndependentAlgorithm {
+impl<G: PrimeableRng, O: Scenario<G>> Algorithm<O> for IndependentAlgorithm<G>
+where
+    O::DispersalSampler<InMemorySeparableAliasDispersalSampler<O::Habitat, G>>:
+        SeparableDispersalSampler<O::Habitat, G>,
+{
     type Error = !;
     type LineageReference = GlobalLineageReference;
     type LineageStore = IndependentLineageStore<O::Habitat>;
-    type Rng = WyHash;
+    type Rng = G;
 
     #[allow(clippy::too_many_lines)]
     fn initialise_and_simulate<I: Iterator<Item = u64>, R: Reporter, P: LocalPartition<R>>(
@@ -72,6 +95,7 @@ impl<O: Scenario<WyHash>> Algorithm<O> for IndependentAlgorithm {
         seed: u64,
         scenario: O,
         pre_sampler: OriginPreSampler<I>,
+        limits: SimulationLimits,
         local_partition: &mut P,
     ) -> Result<(NonNegativeF64, u64), Self::Error> {
         match args.parallelism_mode {
@@ -102,48 +126,30 @@ impl<O: Scenario<WyHash>> Algorithm<O> for IndependentAlgorithm {
                         ..
                     }) => DecompositionOriginSampler::new(
                         scenario.sample_habitat(pre_sampler),
-                        &O::decompose(scenario.habitat(), partition.rank(), partition.partitions()),
+                        &scenario.decompose(partition.rank(), partition.partitions()),
                     )
                     .map(|indexed_location| Lineage::new(indexed_location, scenario.habitat()))
                     .collect(),
                     _ => unsafe { unreachable_unchecked() },
                 };
 
-                let (habitat, dispersal_sampler, turnover_rate, speciation_probability) =
-                    scenario.build::<InMemoryAliasDispersalSampler<O::Habitat, WyHash>>();
-                let rng = WyHash::seed_from_u64(seed);
-                let lineage_store = IndependentLineageStore::default();
-                let coalescence_sampler = IndependentCoalescenceSampler::default();
-
                 let emigration_exit = NeverEmigrationExit::default();
-                let event_sampler = IndependentEventSampler::default();
-                let immigration_entry = NeverImmigrationEntry::default();
-                let active_lineage_sampler = IndependentActiveLineageSampler::empty(
-                    PoissonEventTimeSampler::new(args.delta_t),
-                );
 
-                let simulation = Simulation::builder()
-                    .habitat(habitat)
-                    .rng(rng)
-                    .speciation_probability(speciation_probability)
-                    .dispersal_sampler(dispersal_sampler)
-                    .lineage_reference(std::marker::PhantomData::<GlobalLineageReference>)
-                    .lineage_store(lineage_store)
-                    .emigration_exit(emigration_exit)
-                    .coalescence_sampler(coalescence_sampler)
-                    .turnover_rate(turnover_rate)
-                    .event_sampler(event_sampler)
-                    .immigration_entry(immigration_entry)
-                    .active_lineage_sampler(active_lineage_sampler)
-                    .build();
-
-                Ok(parallelisation::independent::monolithic::simulate(
-                    simulation,
-                    lineages,
-                    args.dedup_cache,
-                    args.step_slice,
-                    event_slice,
-                    local_partition,
+                Ok(simulate_with_event_sampler(
+                    args.skipping,
+                    seed,
+                    args.delta_t,
+                    scenario,
+                    emigration_exit,
+                    MonolithicStrategy {
+                        lineages,
+                        dedup_cache: args.dedup_cache,
+                        step_slice: args.step_slice,
+                        event_slice,
+                        limits,
+                        local_partition,
+                        marker: PhantomData::<R>,
+                    },
                 ))
             },
             ParallelismMode::Individuals => {
@@ -155,44 +161,26 @@ impl<O: Scenario<WyHash>> Algorithm<O> for IndependentAlgorithm {
                     .map(|indexed_location| Lineage::new(indexed_location, scenario.habitat()))
                     .collect();
 
-                let (habitat, dispersal_sampler, turnover_rate, speciation_probability) =
-                    scenario.build::<InMemoryAliasDispersalSampler<O::Habitat, WyHash>>();
-                let rng = WyHash::seed_from_u64(seed);
-                let lineage_store = IndependentLineageStore::default();
-                let coalescence_sampler = IndependentCoalescenceSampler::default();
                 let emigration_exit = NeverEmigrationExit::default();
-                let event_sampler = IndependentEventSampler::default();
-                let immigration_entry = NeverImmigrationEntry::default();
-                let active_lineage_sampler = IndependentActiveLineageSampler::empty(
-                    PoissonEventTimeSampler::new(args.delta_t),
-                );
 
-                let simulation = Simulation::builder()
-                    .habitat(habitat)
-                    .rng(rng)
-                    .speciation_probability(speciation_probability)
-                    .dispersal_sampler(dispersal_sampler)
-                    .lineage_reference(std::marker::PhantomData::<GlobalLineageReference>)
-                    .lineage_store(lineage_store)
-                    .emigration_exit(emigration_exit)
-                    .coalescence_sampler(coalescence_sampler)
-                    .turnover_rate(turnover_rate)
-                    .event_sampler(event_sampler)
-                    .immigration_entry(immigration_entry)
-                    .active_lineage_sampler(active_lineage_sampler)
-                    .build();
-
-                Ok(parallelisation::independent::individuals::simulate(
-                    simulation,
-                    lineages,
-                    args.dedup_cache,
-                    args.step_slice,
-                    local_partition,
+                Ok(simulate_with_event_sampler(
+                    args.skipping,
+                    seed,
+                    args.delta_t,
+                    scenario,
+                    emigration_exit,
+                    IndividualsStrategy {
+                        lineages,
+                        dedup_cache: args.dedup_cache,
+                        step_slice: args.step_slice,
+                        limits,
+                        local_partition,
+                        marker: PhantomData::<R>,
+                    },
                 ))
             },
             ParallelismMode::Landscape => {
-                let decomposition = O::decompose(
-                    scenario.habitat(),
+                let decomposition = scenario.decompose(
                     local_partition.get_partition_rank(),
                     local_partition.get_number_of_partitions(),
                 );
@@ -203,49 +191,31 @@ impl<O: Scenario<WyHash>> Algorithm<O> for IndependentAlgorithm {
                 .map(|indexed_location| Lineage::new(indexed_location, scenario.habitat()))
                 .collect();
 
-                let (habitat, dispersal_sampler, turnover_rate, speciation_probability) =
-                    scenario.build::<InMemoryAliasDispersalSampler<O::Habitat, WyHash>>();
-                let rng = WyHash::seed_from_u64(seed);
-                let lineage_store = IndependentLineageStore::default();
-                let coalescence_sampler = IndependentCoalescenceSampler::default();
                 let emigration_exit = IndependentEmigrationExit::new(
                     decomposition,
                     AlwaysEmigrationChoice::default(),
                 );
-                let event_sampler = IndependentEventSampler::default();
-                let immigration_entry = NeverImmigrationEntry::default();
-                let active_lineage_sampler = IndependentActiveLineageSampler::empty(
-                    PoissonEventTimeSampler::new(args.delta_t),
-                );
-
-                let simulation = Simulation::builder()
-                    .habitat(habitat)
-                    .rng(rng)
-                    .speciation_probability(speciation_probability)
-                    .dispersal_sampler(dispersal_sampler)
-                    .lineage_reference(std::marker::PhantomData::<GlobalLineageReference>)
-                    .lineage_store(lineage_store)
-                    .emigration_exit(emigration_exit)
-                    .coalescence_sampler(coalescence_sampler)
-                    .turnover_rate(turnover_rate)
-                    .event_sampler(event_sampler)
-                    .immigration_entry(immigration_entry)
-                    .active_lineage_sampler(active_lineage_sampler)
-                    .build();
 
-                Ok(parallelisation::independent::landscape::simulate(
-                    simulation,
-                    lineages,
-                    args.dedup_cache,
-                    args.step_slice,
-                    local_partition,
+                Ok(simulate_with_event_sampler(
+                    args.skipping,
+                    seed,
+                    args.delta_t,
+                    scenario,
+                    emigration_exit,
+                    LandscapeStrategy {
+                        lineages,
+                        dedup_cache: args.dedup_cache,
+                        step_slice: args.step_slice,
+                        limits,
+                        local_partition,
+                        marker: PhantomData::<R>,
+                    },
                 ))
             },
             ParallelismMode::Probabilistic(ProbabilisticParallelismMode {
                 communication_probability,
             }) => {
-                let decomposition = O::decompose(
-                    scenario.habitat(),
+                let decomposition = scenario.decompose(
                     local_partition.get_partition_rank(),
                     local_partition.get_number_of_partitions(),
                 );
@@ -256,44 +226,243 @@ impl<O: Scenario<WyHash>> Algorithm<O> for IndependentAlgorithm {
                 .map(|indexed_location| Lineage::new(indexed_location, scenario.habitat()))
                 .collect();
 
-                let (habitat, dispersal_sampler, turnover_rate, speciation_probability) =
-                    scenario.build::<InMemoryAliasDispersalSampler<O::Habitat, WyHash>>();
-                let rng = WyHash::seed_from_u64(seed);
-                let lineage_store = IndependentLineageStore::default();
-                let coalescence_sampler = IndependentCoalescenceSampler::default();
                 let emigration_exit = IndependentEmigrationExit::new(
                     decomposition,
                     ProbabilisticEmigrationChoice::new(communication_probability),
                 );
-                let event_sampler = IndependentEventSampler::default();
-                let immigration_entry = NeverImmigrationEntry::default();
-                let active_lineage_sampler = IndependentActiveLineageSampler::empty(
-                    PoissonEventTimeSampler::new(args.delta_t),
-                );
-
-                let simulation = Simulation::builder()
-                    .habitat(habitat)
-                    .rng(rng)
-                    .speciation_probability(speciation_probability)
-                    .dispersal_sampler(dispersal_sampler)
-                    .lineage_reference(std::marker::PhantomData::<GlobalLineageReference>)
-                    .lineage_store(lineage_store)
-                    .emigration_exit(emigration_exit)
-                    .coalescence_sampler(coalescence_sampler)
-                    .turnover_rate(turnover_rate)
-                    .event_sampler(event_sampler)
-                    .immigration_entry(immigration_entry)
-                    .active_lineage_sampler(active_lineage_sampler)
-                    .build();
 
-                Ok(parallelisation::independent::landscape::simulate(
-                    simulation,
-                    lineages,
-                    args.dedup_cache,
-                    args.step_slice,
-                    local_partition,
+                Ok(simulate_with_event_sampler(
+                    args.skipping,
+                    seed,
+                    args.delta_t,
+                    scenario,
+                    emigration_exit,
+                    LandscapeStrategy {
+                        lineages,
+                        dedup_cache: args.dedup_cache,
+                        step_slice: args.step_slice,
+                        limits,
+                        local_partition,
+                        marker: PhantomData::<R>,
+                    },
                 ))
             },
         }
     }
 }
+
+type IndependentSimulation<H, G, X, D, T, N, E> = Simulation<
+    H,
+    G,
+    GlobalLineageReference,
+    IndependentLineageStore<H>,
+    X,
+    D,
+    IndependentCoalescenceSampler<H>,
+    T,
+    N,
+    E,
+    NeverImmigrationEntry,
+    IndependentActiveLineageSampler<H, G, X, D, T, N, PoissonEventTimeSampler>,
+>;
+
+fn build_simulation<
+    H: Habitat,
+    G: PrimeableRng,
+    X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+    D: DispersalSampler<H, G>,
+    T: TurnoverRate<H>,
+    N: SpeciationProbability<H>,
+    E: IndependentEventRate<H, G, X, D, T, N>,
+>(
+    seed: u64,
+    delta_t: PositiveF64,
+    (habitat, dispersal_sampler, turnover_rate, speciation_probability): (H, D, T, N),
+    emigration_exit: X,
+    event_sampler: E,
+) -> IndependentSimulation<H, G, X, D, T, N, E> {
+    let rng = G::seed_from_u64(seed);
+    let lineage_store = IndependentLineageStore::default();
+    let coalescence_sampler = IndependentCoalescenceSampler::default();
+    let immigration_entry = NeverImmigrationEntry::default();
+    let active_lineage_sampler =
+        IndependentActiveLineageSampler::empty(PoissonEventTimeSampler::new(delta_t));
+
+    Simulation::builder()
+        .habitat(habitat)
+        .rng(rng)
+        .speciation_probability(speciation_probability)
+        .dispersal_sampler(dispersal_sampler)
+        .lineage_reference(std::marker::PhantomData::<GlobalLineageReference>)
+        .lineage_store(lineage_store)
+        .emigration_exit(emigration_exit)
+        .coalescence_sampler(coalescence_sampler)
+        .turnover_rate(turnover_rate)
+        .event_sampler(event_sampler)
+        .immigration_entry(immigration_entry)
+        .active_lineage_sampler(active_lineage_sampler)
+        .build()
+}
+
+/// Builds the simulation with the skipping or the non-skipping event sampler
+///  and simulates it using the parallelisation `strategy`
+fn simulate_with_event_sampler<
+    G: PrimeableRng,
+    O: Scenario<G>,
+    X: EmigrationExit<O::Habitat, G, GlobalLineageReference, IndependentLineageStore<O::Habitat>>,
+    S: IndependentStrategy<O::Habitat, G, X>,
+>(
+    skipping: bool,
+    seed: u64,
+    delta_t: PositiveF64,
+    scenario: O,
+    emigration_exit: X,
+    strategy: S,
+) -> (NonNegativeF64, u64)
+where
+    O::DispersalSampler<InMemorySeparableAliasDispersalSampler<O::Habitat, G>>:
+        SeparableDispersalSampler<O::Habitat, G>,
+{
+    if skipping {
+        strategy.simulate(build_simulation(
+            seed,
+            delta_t,
+            scenario.build::<InMemorySeparableAliasDispersalSampler<O::Habitat, G>>(),
+            emigration_exit,
+            SkippingIndependentEventSampler::default(),
+        ))
+    } else {
+        strategy.simulate(build_simulation(
+            seed,
+            delta_t,
+            scenario.build::<InMemoryAliasDispersalSampler<O::Habitat, G>>(),
+            emigration_exit,
+            IndependentEventSampler::default(),
+        ))
+    }
+}
+
+/// Parallelisation strategy which simulates an independent simulation with
+///  any dispersal sampler and event sampler
+trait IndependentStrategy<
+    H: Habitat,
+    G: PrimeableRng,
+    X: EmigrationExit<H, G, GlobalLineageReference, IndependentLineageStore<H>>,
+>
+{
+    fn simulate<
+        D: DispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+        E: IndependentEventRate<H, G, X, D, T, N>,
+    >(
+        self,
+        simulation: IndependentSimulation<H, G, X, D, T, N, E>,
+    ) -> (NonNegativeF64, u64);
+}
+
+struct MonolithicStrategy<'p, R: Reporter, P: LocalPartition<R>> {
+    lineages: VecDeque<Lineage>,
+    dedup_cache: DedupCache,
+    step_slice: NonZeroU64,
+    event_slice: NonZeroUsize,
+    limits: SimulationLimits,
+    local_partition: &'p mut P,
+    marker: PhantomData<R>,
+}
+
+impl<'p, H: Habitat, G: PrimeableRng, R: Reporter, P: LocalPartition<R>>
+    IndependentStrategy<H, G, NeverEmigrationExit> for MonolithicStrategy<'p, R, P>
+{
+    fn simulate<
+        D: DispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+        E: IndependentEventRate<H, G, NeverEmigrationExit, D, T, N>,
+    >(
+        self,
+        simulation: IndependentSimulation<H, G, NeverEmigrationExit, D, T, N, E>,
+    ) -> (NonNegativeF64, u64) {
+        parallelisation::independent::monolithic::simulate(
+            simulation,
+            self.lineages,
+            self.dedup_cache,
+            self.step_slice,
+            self.event_slice,
+            self.limits,
+            self.local_partition,
+        )
+    }
+}
+
+struct IndividualsStrategy<'p, R: Reporter, P: LocalPartition<R>> {
+    lineages: VecDeque<Lineage>,
+    dedup_cache: DedupCache,
+    step_slice: NonZeroU64,
+    limits: SimulationLimits,
+    local_partition: &'p mut P,
+    marker: PhantomData<R>,
+}
+
+impl<'p, H: Habitat, G: PrimeableRng, R: Reporter, P: LocalPartition<R>>
+    IndependentStrategy<H, G, NeverEmigrationExit> for IndividualsStrategy<'p, R, P>
+{
+    fn simulate<
+        D: DispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+        E: IndependentEventRate<H, G, NeverEmigrationExit, D, T, N>,
+    >(
+        self,
+        simulation: IndependentSimulation<H, G, NeverEmigrationExit, D, T, N, E>,
+    ) -> (NonNegativeF64, u64) {
+        parallelisation::independent::individuals::simulate(
+            simulation,
+            self.lineages,
+            self.dedup_cache,
+            self.step_slice,
+            self.limits,
+            self.local_partition,
+        )
+    }
+}
+
+struct LandscapeStrategy<'p, R: Reporter, P: LocalPartition<R>> {
+    lineages: VecDeque<Lineage>,
+    dedup_cache: DedupCache,
+    step_slice: NonZeroU64,
+    limits: SimulationLimits,
+    local_partition: &'p mut P,
+    marker: PhantomData<R>,
+}
+
+impl<
+        'p,
+        H: Habitat,
+        C: Decomposition<H>,
+        M: EmigrationChoice<H>,
+        G: PrimeableRng,
+        R: Reporter,
+        P: LocalPartition<R>,
+    > IndependentStrategy<H, G, IndependentEmigrationExit<H, C, M>>
+    for LandscapeStrategy<'p, R, P>
+{
+    fn simulate<
+        D: DispersalSampler<H, G>,
+        T: TurnoverRate<H>,
+        N: SpeciationProbability<H>,
+        E: IndependentEventRate<H, G, IndependentEmigrationExit<H, C, M>, D, T, N>,
+    >(
+        self,
+        simulation: IndependentSimulation<H, G, IndependentEmigrationExit<H, C, M>, D, T, N, E>,
+    ) -> (NonNegativeF64, u64) {
+        parallelisation::independent::landscape::simulate(
+            simulation,
+            self.lineages,
+            self.dedup_cache,
+            self.step_slice,
+            self.limits,
+            self.local_partition,
+        )
+    }
+}
@@ -22,7 +22,7 @@ use necsim_impls_no_std::{
         },
         turnover_rate::uniform::UniformTurnoverRate,
     },
-    parallelisation,
+    parallelisation::{self, SimulationLimits},
 };
 use necsim_impls_std::cogs::rng::pcg::Pcg;
 use necsim_partitioning_core::LocalPartition;
@@ -58,11 +58,13 @@ where
     type LineageStore = O::LineageStore<ClassicalLineageStore<O::Habitat>>;
     type Rng = Pcg;
 
+    #[allow(clippy::too_many_lines)]
     fn initialise_and_simulate<I: Iterator<Item = u64>, R: Reporter, P: LocalPartition<R>>(
         args: Self::Arguments,
         seed: u64,
         scenario: O,
         pre_sampler: OriginPreSampler<I>,
+        limits: SimulationLimits,
         local_partition: &mut P,
     ) -> Result<(NonNegativeF64, u64), Self::Error> {
         match args.parallelism_mode {
@@ -95,12 +97,12 @@ where
 
                 Ok(parallelisation::monolithic::monolithic::simulate(
                     simulation,
+                    limits,
                     local_partition,
                 ))
             },
             non_monolithic_parallelism_mode => {
-                let decomposition = O::decompose(
-                    scenario.habitat(),
+                let decomposition = scenario.decompose(
                     local_partition.get_partition_rank(),
                     local_partition.get_number_of_partitions(),
                 );
@@ -141,18 +143,21 @@ where
                         Ok(parallelisation::monolithic::optimistic::simulate(
                             simulation,
                             delta_sync,
+                            limits,
                             local_partition,
                         ))
                     },
                     ParallelismMode::Lockstep => {
                         Ok(parallelisation::monolithic::lockstep::simulate(
                             simulation,
+                            limits,
                             local_partition,
                         ))
                     },
                     ParallelismMode::OptimisticLockstep => {
                         Ok(parallelisation::monolithic::optimistic_lockstep::simulate(
                             simulation,
+                            limits,
                             local_partition,
                         ))
                     },
@@ -160,6 +165,7 @@ where
                         Ok(parallelisation::monolithic::averaging::simulate(
                             simulation,
                             delta_sync,
+                            limits,
                             local_partition,
                         ))
                     },
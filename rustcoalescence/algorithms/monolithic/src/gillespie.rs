@@ -22,7 +22,7 @@ use necsim_impls_no_std::{
             decomposition::DecompositionOriginSampler, pre_sampler::OriginPreSampler,
         },
     },
-    parallelisation,
+    parallelisation::{self, SimulationLimits},
 };
 use necsim_impls_std::cogs::{
     active_lineage_sampler::gillespie::GillespieActiveLineageSampler, rng::pcg::Pcg,
@@ -61,6 +61,7 @@ where
         seed: u64,
         scenario: O,
         pre_sampler: OriginPreSampler<I>,
+        limits: SimulationLimits,
         local_partition: &mut P,
     ) -> Result<(NonNegativeF64, u64), Self::Error> {
         match args.parallelism_mode {
@@ -122,12 +123,12 @@ where
 
                 Ok(parallelisation::monolithic::monolithic::simulate(
                     simulation,
+                    limits,
                     local_partition,
                 ))
             },
             non_monolithic_parallelism_mode => {
-                let decomposition = O::decompose(
-                    scenario.habitat(),
+                let decomposition = scenario.decompose(
                     local_partition.get_partition_rank(),
                     local_partition.get_number_of_partitions(),
                 );
@@ -197,18 +198,21 @@ where
                         Ok(parallelisation::monolithic::optimistic::simulate(
                             simulation,
                             delta_sync,
+                            limits,
                             local_partition,
                         ))
                     },
                     ParallelismMode::Lockstep => {
                         Ok(parallelisation::monolithic::lockstep::simulate(
                             simulation,
+                            limits,
                             local_partition,
                         ))
                     },
                     ParallelismMode::OptimisticLockstep => {
                         Ok(parallelisation::monolithic::optimistic_lockstep::simulate(
                             simulation,
+                            limits,
                             local_partition,
                         ))
                     },
@@ -216,6 +220,7 @@ where
                         Ok(parallelisation::monolithic::averaging::simulate(
                             simulation,
                             delta_sync,
+                            limits,
                             local_partition,
                         ))
                     },
@@ -4,7 +4,7 @@
 #[macro_use]
 extern crate serde_derive_state;
 
-mod arguments;
+pub mod arguments;
 
 pub mod classical;
 pub mod gillespie;
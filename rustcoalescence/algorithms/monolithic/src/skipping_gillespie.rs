@@ -25,7 +25,7 @@ use necsim_impls_no_std::{
             decomposition::DecompositionOriginSampler, pre_sampler::OriginPreSampler,
         },
     },
-    parallelisation,
+    parallelisation::{self, SimulationLimits},
 };
 use necsim_impls_std::cogs::{
     active_lineage_sampler::gillespie::GillespieActiveLineageSampler, rng::pcg::Pcg,
@@ -66,6 +66,7 @@ where
         seed: u64,
         scenario: O,
         pre_sampler: OriginPreSampler<I>,
+        limits: SimulationLimits,
         local_partition: &mut P,
     ) -> Result<(NonNegativeF64, u64), Self::Error> {
         match args.parallelism_mode {
@@ -127,12 +128,12 @@ where
 
                 Ok(parallelisation::monolithic::monolithic::simulate(
                     simulation,
+                    limits,
                     local_partition,
                 ))
             },
             non_monolithic_parallelism_mode => {
-                let decomposition = O::decompose(
-                    scenario.habitat(),
+                let decomposition = scenario.decompose(
                     local_partition.get_partition_rank(),
                     local_partition.get_number_of_partitions(),
                 );
@@ -202,18 +203,21 @@ where
                         Ok(parallelisation::monolithic::optimistic::simulate(
                             simulation,
                             delta_sync,
+                            limits,
                             local_partition,
                         ))
                     },
                     ParallelismMode::Lockstep => {
                         Ok(parallelisation::monolithic::lockstep::simulate(
                             simulation,
+                            limits,
                             local_partition,
                         ))
                     },
                     ParallelismMode::OptimisticLockstep => {
                         Ok(parallelisation::monolithic::optimistic_lockstep::simulate(
                             simulation,
+                            limits,
                             local_partition,
                         ))
                     },
@@ -221,6 +225,7 @@ where
                         Ok(parallelisation::monolithic::averaging::simulate(
                             simulation,
                             delta_sync,
+                            limits,
                             local_partition,
                         ))
                     },
@@ -6,7 +6,9 @@ use necsim_core::{
 };
 use necsim_core_bond::NonNegativeF64;
 
-use necsim_impls_no_std::cogs::origin_sampler::pre_sampler::OriginPreSampler;
+use necsim_impls_no_std::{
+    cogs::origin_sampler::pre_sampler::OriginPreSampler, parallelisation::SimulationLimits,
+};
 use necsim_partitioning_core::LocalPartition;
 
 use rustcoalescence_scenarios::Scenario;
@@ -30,6 +32,7 @@ pub trait Algorithm<O: Scenario<Self::Rng>>: Sized + AlgorithmArguments {
         seed: u64,
         scenario: O,
         pre_sampler: OriginPreSampler<I>,
+        limits: SimulationLimits,
         local_partition: &mut P,
     ) -> Result<(NonNegativeF64, u64), Self::Error>;
 }
@@ -0,0 +1,250 @@
+use anyhow::Result;
+
+use rustcoalescence_algorithms::Algorithm;
+
+#[cfg(feature = "rustcoalescence-algorithms-cuda")]
+use rustcoalescence_algorithms_cuda::{
+    arguments::{CudaArguments, CudaRngChoice},
+    CudaAlgorithm,
+};
+#[cfg(feature = "rustcoalescence-algorithms-independent")]
+use rustcoalescence_algorithms_independent::{
+    arguments::{IndependentArguments, IndependentRng},
+    IndependentAlgorithm,
+};
+#[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+use rustcoalescence_algorithms_monolithic::{
+    classical::ClassicalAlgorithm, gillespie::GillespieAlgorithm,
+    skipping_gillespie::SkippingGillespieAlgorithm,
+};
+
+use necsim_core::reporter::Reporter;
+use necsim_core_bond::NonNegativeF64;
+use necsim_impls_no_std::cogs::origin_sampler::pre_sampler::OriginPreSampler;
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+use necsim_impls_no_std::cogs::rng::{philox::Philox4x32, threefry::Threefry4x64, wyhash::WyHash};
+use necsim_partitioning_core::LocalPartition;
+
+use rustcoalescence_scenarios::{
+    almost_infinite::AlmostInfiniteScenario, islands::IslandsScenario,
+    non_spatial::NonSpatialScenario, spatially_explicit::SpatiallyExplicitScenario,
+    spatially_implicit::SpatiallyImplicitScenario, torus::TorusScenario, Scenario,
+};
+
+use crate::{AlgorithmConfig, ScenarioConfig, SimulationConfig, SimulationHooks};
+
+#[allow(clippy::too_many_lines)]
+pub fn simulate_with_local_partition<
+    R: Reporter,
+    P: LocalPartition<R>,
+    K: SimulationHooks<R, P>,
+>(
+    config: SimulationConfig,
+    local_partition: &mut P,
+    hooks: &mut K,
+) -> Result<(NonNegativeF64, u64)> {
+    let pre_sampler = OriginPreSampler::all().percentage(config.sample_percentage.get());
+
+    let (time, steps): (NonNegativeF64, u64) = crate::match_scenario_algorithm!(
+        (config.algorithm, config.scenario => scenario)
+    {
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmConfig::Classical(algorithm_args) => {
+            ClassicalAlgorithm::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )
+            .into_ok()
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmConfig::Gillespie(algorithm_args) => {
+            GillespieAlgorithm::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )
+            .into_ok()
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmConfig::SkippingGillespie(algorithm_args) => {
+            SkippingGillespieAlgorithm::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )
+            .into_ok()
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-independent")]
+        AlgorithmConfig::Independent(
+            algorithm_args @ IndependentArguments { rng: IndependentRng::WyHash, .. },
+        ) => {
+            IndependentAlgorithm::<WyHash>::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )
+            .into_ok()
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-independent")]
+        AlgorithmConfig::Independent(
+            algorithm_args @ IndependentArguments { rng: IndependentRng::Philox4x32, .. },
+        ) => {
+            IndependentAlgorithm::<Philox4x32>::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )
+            .into_ok()
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-independent")]
+        AlgorithmConfig::Independent(
+            algorithm_args @ IndependentArguments { rng: IndependentRng::Threefry4x64, .. },
+        ) => {
+            IndependentAlgorithm::<Threefry4x64>::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )
+            .into_ok()
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+        AlgorithmConfig::Cuda(
+            algorithm_args @ CudaArguments { rng: CudaRngChoice::WyHash, .. },
+        ) => {
+            CudaAlgorithm::<WyHash>::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )?
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+        AlgorithmConfig::Cuda(
+            algorithm_args @ CudaArguments { rng: CudaRngChoice::Philox4x32, .. },
+        ) => {
+            CudaAlgorithm::<Philox4x32>::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )?
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+        AlgorithmConfig::Cuda(
+            algorithm_args @ CudaArguments { rng: CudaRngChoice::Threefry4x64, .. },
+        ) => {
+            CudaAlgorithm::<Threefry4x64>::initialise_and_simulate(
+                algorithm_args,
+                config.seed,
+                scenario,
+                pre_sampler,
+                config.limits,
+                local_partition,
+            )?
+        }
+        <=>
+        ScenarioConfig::SpatiallyExplicit(scenario_args) => {
+            let scenario = SpatiallyExplicitScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )?;
+
+            hooks.initialised_bounded_scenario(&scenario, &*local_partition)?;
+
+            scenario
+        },
+        ScenarioConfig::NonSpatial(scenario_args) => {
+            NonSpatialScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )
+            .into_ok()
+        },
+        ScenarioConfig::AlmostInfiniteNormal(scenario_args) => {
+            AlmostInfiniteScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )?
+        },
+        ScenarioConfig::AlmostInfiniteClark2Dt(scenario_args) => {
+            AlmostInfiniteScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )?
+        },
+        ScenarioConfig::AlmostInfiniteCauchy(scenario_args) => {
+            AlmostInfiniteScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )?
+        },
+        ScenarioConfig::AlmostInfiniteExpPower(scenario_args) => {
+            AlmostInfiniteScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )?
+        },
+        ScenarioConfig::AlmostInfiniteLaplace(scenario_args) => {
+            AlmostInfiniteScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )?
+        },
+        ScenarioConfig::SpatiallyImplicit(scenario_args) => {
+            SpatiallyImplicitScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )
+            .into_ok()
+        },
+        ScenarioConfig::Islands(scenario_args) => {
+            let scenario = IslandsScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )?;
+
+            hooks.initialised_bounded_scenario(&scenario, &*local_partition)?;
+
+            scenario
+        },
+        ScenarioConfig::Torus(scenario_args) => {
+            let scenario = TorusScenario::initialise(
+                scenario_args,
+                config.speciation_probability_per_generation,
+            )
+            .into_ok();
+
+            hooks.initialised_bounded_scenario(&scenario, &*local_partition)?;
+
+            scenario
+        }
+    });
+
+    Ok((time, steps))
+}
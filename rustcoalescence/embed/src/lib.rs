@@ -0,0 +1,218 @@
+#![deny(clippy::pedantic)]
+#![feature(bindings_after_at)]
+#![feature(unwrap_infallible)]
+
+//! `rustcoalescence-embed` runs the built-in scenarios and algorithms
+//!  in-process, e.g. to drive necsim-rust from other Rust tools without
+//!  going through the `rustcoalescence` command-line interface.
+
+use anyhow::Result;
+
+use necsim_core::{
+    cogs::RngCore,
+    reporter::{FilteredReporter, Reporter, ReporterSummary},
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveUnitF64};
+use necsim_impls_no_std::parallelisation::SimulationLimits;
+use necsim_partitioning_core::LocalPartition;
+use necsim_partitioning_monolithic::live::LiveMonolithicLocalPartition;
+
+use rustcoalescence_scenarios::{
+    almost_infinite::{
+        AlmostInfiniteArguments, CauchyDispersal, Clark2DtDispersal, ExpPowerDispersal,
+        LaplaceDispersal, NormalDispersal,
+    },
+    islands::IslandsArguments,
+    non_spatial::NonSpatialArguments,
+    spatially_explicit::InMemoryArguments,
+    spatially_implicit::SpatiallyImplicitArguments,
+    torus::TorusArguments,
+    Scenario,
+};
+
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+use rustcoalescence_algorithms::AlgorithmArguments;
+
+mod macros;
+
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+mod dispatch;
+
+/// Configuration of a single simulation
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SimulationConfig {
+    pub speciation_probability_per_generation: PositiveUnitF64,
+    pub sample_percentage: ClosedUnitF64,
+    pub seed: u64,
+    pub limits: SimulationLimits,
+    pub algorithm: AlgorithmConfig,
+    pub scenario: ScenarioConfig,
+}
+
+/// The algorithm, and its arguments, with which the simulation is run.
+///
+/// The variants are only available if the corresponding algorithm feature
+///  of this crate is enabled.
+#[derive(Debug)]
+pub enum AlgorithmConfig {
+    #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+    Classical(
+        <rustcoalescence_algorithms_monolithic::classical::ClassicalAlgorithm as AlgorithmArguments>::Arguments,
+    ),
+    #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+    Gillespie(
+        <rustcoalescence_algorithms_monolithic::gillespie::GillespieAlgorithm as AlgorithmArguments>::Arguments,
+    ),
+    #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+    SkippingGillespie(
+        <rustcoalescence_algorithms_monolithic::skipping_gillespie::SkippingGillespieAlgorithm as AlgorithmArguments>::Arguments,
+    ),
+    #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+    Cuda(<rustcoalescence_algorithms_cuda::CudaAlgorithm as AlgorithmArguments>::Arguments),
+    #[cfg(feature = "rustcoalescence-algorithms-independent")]
+    Independent(
+        <rustcoalescence_algorithms_independent::IndependentAlgorithm as AlgorithmArguments>::Arguments,
+    ),
+}
+
+/// The built-in scenario, and its arguments, which is simulated
+#[derive(Debug)]
+pub enum ScenarioConfig {
+    SpatiallyExplicit(InMemoryArguments),
+    NonSpatial(NonSpatialArguments),
+    SpatiallyImplicit(SpatiallyImplicitArguments),
+    AlmostInfiniteNormal(AlmostInfiniteArguments<NormalDispersal>),
+    AlmostInfiniteClark2Dt(AlmostInfiniteArguments<Clark2DtDispersal>),
+    AlmostInfiniteCauchy(AlmostInfiniteArguments<CauchyDispersal>),
+    AlmostInfiniteExpPower(AlmostInfiniteArguments<ExpPowerDispersal>),
+    AlmostInfiniteLaplace(AlmostInfiniteArguments<LaplaceDispersal>),
+    Islands(IslandsArguments),
+    Torus(TorusArguments),
+}
+
+/// Outcome of a simulation which has run to completion
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SimulationResults {
+    pub time: NonNegativeF64,
+    pub steps: u64,
+    pub reporter: ReporterSummary,
+}
+
+/// Runs the simulation described by the `config` in monolithic mode and
+///  reports all events to the `reporter`.
+///
+/// # Errors
+///
+/// Returns an error if the `reporter` fails to initialise, the scenario
+///  cannot be initialised from its arguments, or the algorithm fails.
+pub fn simulate<R: Reporter>(
+    config: SimulationConfig,
+    mut reporter: R,
+) -> Result<SimulationResults> {
+    reporter.initialise().map_err(anyhow::Error::msg)?;
+
+    let mut local_partition =
+        LiveMonolithicLocalPartition::from_reporter(FilteredReporter::from(reporter));
+
+    let (time, steps) = simulate_with_local_partition(config, &mut local_partition)?;
+
+    Ok(SimulationResults {
+        time,
+        steps,
+        reporter: local_partition.finalise_reporting()?,
+    })
+}
+
+/// Hooks into the setup of a simulation, e.g. to inspect the initialised
+///  scenario before it is simulated
+pub trait SimulationHooks<R: Reporter, P: LocalPartition<R>> {
+    /// Called with the initialised scenario iff its habitat is bounded, i.e.
+    ///  for the spatially explicit, islands, and torus scenarios
+    ///
+    /// # Errors
+    ///
+    /// Returns an error to abort the simulation before it is run.
+    fn initialised_bounded_scenario<G: RngCore, O: Scenario<G>>(
+        &mut self,
+        scenario: &O,
+        local_partition: &P,
+    ) -> Result<()>;
+}
+
+impl<R: Reporter, P: LocalPartition<R>> SimulationHooks<R, P> for () {
+    fn initialised_bounded_scenario<G: RngCore, O: Scenario<G>>(
+        &mut self,
+        _scenario: &O,
+        _local_partition: &P,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the simulation described by the `config` on the `local_partition`,
+///  which must already have been initialised, and returns the final time
+///  and number of steps of this partition.
+///
+/// # Errors
+///
+/// Returns an error if the scenario cannot be initialised from its
+///  arguments, or the algorithm fails.
+#[allow(clippy::module_name_repetitions)]
+pub fn simulate_with_local_partition<R: Reporter, P: LocalPartition<R>>(
+    config: SimulationConfig,
+    local_partition: &mut P,
+) -> Result<(NonNegativeF64, u64)> {
+    simulate_with_local_partition_and_hooks(config, local_partition, &mut ())
+}
+
+/// Runs the simulation described by the `config` on the `local_partition`,
+///  like [`simulate_with_local_partition`], and calls the `hooks` during
+///  its setup.
+///
+/// # Errors
+///
+/// Returns an error if the scenario cannot be initialised from its
+///  arguments, a hook fails, or the algorithm fails.
+#[allow(clippy::module_name_repetitions)]
+pub fn simulate_with_local_partition_and_hooks<
+    R: Reporter,
+    P: LocalPartition<R>,
+    K: SimulationHooks<R, P>,
+>(
+    config: SimulationConfig,
+    local_partition: &mut P,
+    hooks: &mut K,
+) -> Result<(NonNegativeF64, u64)> {
+    #[cfg(any(
+        feature = "rustcoalescence-algorithms-monolithic",
+        feature = "rustcoalescence-algorithms-independent",
+        feature = "rustcoalescence-algorithms-cuda"
+    ))]
+    {
+        dispatch::simulate_with_local_partition(config, local_partition, hooks)
+    }
+
+    #[cfg(not(any(
+        feature = "rustcoalescence-algorithms-monolithic",
+        feature = "rustcoalescence-algorithms-independent",
+        feature = "rustcoalescence-algorithms-cuda"
+    )))]
+    {
+        std::mem::drop(config);
+        let _ = (local_partition, hooks);
+
+        Err(anyhow::anyhow!(
+            "rustcoalescence-embed must be compiled to support at least one algorithm."
+        ))
+    }
+}
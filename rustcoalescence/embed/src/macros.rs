@@ -1,3 +1,8 @@
+/// Matches an algorithm against a scenario and evaluates the algorithm
+///  arm with the initialised scenario bound to `$algscen`.
+///
+/// Every algorithm arm is crossed with every scenario arm, such that the
+///  algorithm code is monomorphised for each scenario.
 #[macro_export]
 macro_rules! match_scenario_algorithm {
     (
@@ -7,7 +12,7 @@ macro_rules! match_scenario_algorithm {
             $($scenpat:pat => $scencode:block),*
         }
     ) => {
-        match_scenario_algorithm! {
+        $crate::match_scenario_algorithm! {
             impl ($algorithm, $scenario => $algscen) {
                 $($(#[$meta])* $algpat => $algcode),*
                 <=>
@@ -26,7 +31,7 @@ macro_rules! match_scenario_algorithm {
             $($tail:tt)*
         }
     ) => {
-        match_scenario_algorithm! {
+        $crate::match_scenario_algorithm! {
             impl ($algorithm, $scenario => $algscen) {
                 $($(#[$metarem])* $algpatrem => $algcoderem),+
                 <=>
@@ -0,0 +1,80 @@
+#![cfg(feature = "rustcoalescence-algorithms-monolithic")]
+
+use necsim_core::{
+    event::LineageInteraction,
+    impl_finalise, impl_report,
+    reporter::{Reporter, ReporterSummary, SummaryValue},
+};
+use necsim_core_bond::{ClosedUnitF64, PositiveUnitF64};
+use necsim_impls_no_std::parallelisation::SimulationLimits;
+
+use rustcoalescence_algorithms_monolithic::arguments::{MonolithicArguments, ParallelismMode};
+use rustcoalescence_embed::{simulate, AlgorithmConfig, ScenarioConfig, SimulationConfig};
+use rustcoalescence_scenarios::non_spatial::NonSpatialArguments;
+
+#[derive(Debug, Default)]
+struct CountingReporter {
+    speciation: u64,
+    dispersal: u64,
+    coalescence: u64,
+}
+
+impl Reporter for CountingReporter {
+    impl_report!(speciation(&mut self, _speciation: Used) {
+        self.speciation += 1;
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.dispersal += 1;
+
+        if let LineageInteraction::Coalescence(_) = dispersal.interaction {
+            self.coalescence += 1;
+        }
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((self) {
+        ReporterSummary::new()
+            .with("speciation", self.speciation)
+            .with("dispersal", self.dispersal)
+            .with("coalescence", self.coalescence)
+    });
+}
+
+fn summary_count(summary: &ReporterSummary, key: &str) -> u64 {
+    match summary.get(key) {
+        Some(SummaryValue::Unsigned(count)) => *count,
+        other => panic!("unexpected {} count {:?}", key, other),
+    }
+}
+
+#[test]
+fn test_simulate_non_spatial() {
+    let config = SimulationConfig {
+        speciation_probability_per_generation: PositiveUnitF64::new(0.1).unwrap(),
+        sample_percentage: ClosedUnitF64::one(),
+        seed: 42,
+        limits: SimulationLimits::default(),
+        algorithm: AlgorithmConfig::Classical(MonolithicArguments {
+            parallelism_mode: ParallelismMode::Monolithic,
+        }),
+        scenario: ScenarioConfig::NonSpatial(NonSpatialArguments {
+            area: (4, 4),
+            deme: 4,
+        }),
+    };
+
+    let results = simulate(config, CountingReporter::default()).unwrap();
+
+    let speciation = summary_count(&results.reporter, "speciation");
+    let dispersal = summary_count(&results.reporter, "dispersal");
+    let coalescence = summary_count(&results.reporter, "coalescence");
+
+    // Every one of the 64 sampled individuals either speciates or coalesces
+    assert!(speciation > 0);
+    assert_eq!(speciation + coalescence, 4 * 4 * 4);
+    // Every step of the simulation reports exactly one event
+    assert_eq!(results.steps, speciation + dispersal);
+    assert!(results.time.get() > 0.0);
+}
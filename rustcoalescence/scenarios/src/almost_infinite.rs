@@ -1,18 +1,25 @@
-use std::num::NonZeroU32;
+use std::{marker::PhantomData, num::NonZeroU32};
 
-use serde::Deserialize;
+use thiserror::Error;
 
-use necsim_core::cogs::{DispersalSampler, LineageStore, RngCore};
-use necsim_core_bond::{NonNegativeF64, PositiveUnitF64};
+use necsim_core::cogs::{DispersalSampler, LineageStore, RngCore, SeparableDispersalSampler};
+use necsim_core_bond::{NonNegativeF64, PositiveF64, PositiveUnitF64};
 
 use necsim_impls_no_std::{
     cogs::{
-        dispersal_sampler::almost_infinite_normal::AlmostInfiniteNormalDispersalSampler,
+        dispersal_sampler::almost_infinite::{
+            cauchy::AlmostInfiniteCauchyDispersalSampler,
+            clark2dt::AlmostInfiniteClark2DtDispersalSampler,
+            exp_power::AlmostInfiniteExpPowerDispersalSampler,
+            laplace::AlmostInfiniteLaplaceDispersalSampler,
+            normal::AlmostInfiniteNormalDispersalSampler,
+        },
         habitat::almost_infinite::AlmostInfiniteHabitat,
         lineage_reference::in_memory::InMemoryLineageReference,
         lineage_store::coherent::globally::almost_infinite::AlmostInfiniteLineageStore,
         origin_sampler::{
-            almost_infinite::AlmostInfiniteOriginSampler, pre_sampler::OriginPreSampler,
+            almost_infinite::{AlmostInfiniteOriginSampler, AlmostInfiniteSampleShape},
+            pre_sampler::OriginPreSampler,
         },
         speciation_probability::uniform::UniformSpeciationProbability,
         turnover_rate::uniform::UniformTurnoverRate,
@@ -23,32 +30,119 @@ use necsim_impls_no_std::{
 use crate::{Scenario, ScenarioArguments};
 
 #[allow(clippy::module_name_repetitions)]
-pub struct AlmostInfiniteScenario<G: RngCore> {
-    radius: u32,
+pub struct AlmostInfiniteScenario<G: RngCore, K: AlmostInfiniteDispersalKernel<G>> {
+    sample: Vec<AlmostInfiniteSampleShape>,
 
     habitat: AlmostInfiniteHabitat,
-    dispersal_sampler: AlmostInfiniteNormalDispersalSampler<G>,
+    dispersal_sampler: K::DispersalSampler,
     turnover_rate: UniformTurnoverRate,
     speciation_probability: UniformSpeciationProbability,
+    _marker: PhantomData<G>,
+}
+
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct AlmostInfiniteArguments<K> {
+    pub sample: Vec<AlmostInfiniteSampleShape>,
+    pub dispersal: K,
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum AlmostInfiniteError {
+    #[error("The sample shape {0} does not fit into the almost infinite habitat.")]
+    SampleOutOfBounds(usize),
 }
 
-#[derive(Debug, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
-#[serde(rename = "AlmostInfinite")]
-pub struct AlmostInfiniteArguments {
-    pub radius: u32,
+pub trait AlmostInfiniteDispersalKernel<G: RngCore> {
+    type DispersalSampler: SeparableDispersalSampler<AlmostInfiniteHabitat, G>;
+
+    fn into_dispersal_sampler(self) -> Self::DispersalSampler;
+}
+
+/// Gaussian dispersal N(0, sigma^2)
+#[derive(Debug)]
+pub struct NormalDispersal {
     pub sigma: NonNegativeF64,
 }
 
-impl<G: RngCore> ScenarioArguments for AlmostInfiniteScenario<G> {
-    type Arguments = AlmostInfiniteArguments;
+impl<G: RngCore> AlmostInfiniteDispersalKernel<G> for NormalDispersal {
+    type DispersalSampler = AlmostInfiniteNormalDispersalSampler<G>;
+
+    fn into_dispersal_sampler(self) -> Self::DispersalSampler {
+        AlmostInfiniteNormalDispersalSampler::new(self.sigma)
+    }
+}
+
+/// Clark's 2Dt dispersal with scale `u` and shape `p`
+#[derive(Debug)]
+pub struct Clark2DtDispersal {
+    pub scale: PositiveF64,
+    pub shape: PositiveF64,
+}
+
+impl<G: RngCore> AlmostInfiniteDispersalKernel<G> for Clark2DtDispersal {
+    type DispersalSampler = AlmostInfiniteClark2DtDispersalSampler<G>;
+
+    fn into_dispersal_sampler(self) -> Self::DispersalSampler {
+        AlmostInfiniteClark2DtDispersalSampler::new(self.scale, self.shape)
+    }
+}
+
+/// Two-dimensional Cauchy dispersal with scale `gamma`
+#[derive(Debug)]
+pub struct CauchyDispersal {
+    pub scale: PositiveF64,
 }
 
-impl<G: RngCore> Scenario<G> for AlmostInfiniteScenario<G> {
+impl<G: RngCore> AlmostInfiniteDispersalKernel<G> for CauchyDispersal {
+    type DispersalSampler = AlmostInfiniteCauchyDispersalSampler<G>;
+
+    fn into_dispersal_sampler(self) -> Self::DispersalSampler {
+        AlmostInfiniteCauchyDispersalSampler::new(self.scale)
+    }
+}
+
+/// Exponential-power dispersal with scale `a` and shape `b`
+#[derive(Debug)]
+pub struct ExpPowerDispersal {
+    pub scale: PositiveF64,
+    pub shape: PositiveF64,
+}
+
+impl<G: RngCore> AlmostInfiniteDispersalKernel<G> for ExpPowerDispersal {
+    type DispersalSampler = AlmostInfiniteExpPowerDispersalSampler<G>;
+
+    fn into_dispersal_sampler(self) -> Self::DispersalSampler {
+        AlmostInfiniteExpPowerDispersalSampler::new(self.scale, self.shape)
+    }
+}
+
+/// Laplace dispersal with scale `b` along each axis
+#[derive(Debug)]
+pub struct LaplaceDispersal {
+    pub scale: PositiveF64,
+}
+
+impl<G: RngCore> AlmostInfiniteDispersalKernel<G> for LaplaceDispersal {
+    type DispersalSampler = AlmostInfiniteLaplaceDispersalSampler<G>;
+
+    fn into_dispersal_sampler(self) -> Self::DispersalSampler {
+        AlmostInfiniteLaplaceDispersalSampler::new(self.scale)
+    }
+}
+
+impl<G: RngCore, K: AlmostInfiniteDispersalKernel<G>> ScenarioArguments
+    for AlmostInfiniteScenario<G, K>
+{
+    type Arguments = AlmostInfiniteArguments<K>;
+}
+
+impl<G: RngCore, K: AlmostInfiniteDispersalKernel<G>> Scenario<G> for AlmostInfiniteScenario<G, K> {
     type Decomposition = RadialDecomposition;
-    type DispersalSampler<D: DispersalSampler<Self::Habitat, G>> =
-        AlmostInfiniteNormalDispersalSampler<G>;
-    type Error = !;
+    type DispersalSampler<D: DispersalSampler<Self::Habitat, G>> = K::DispersalSampler;
+    type Error = AlmostInfiniteError;
     type Habitat = AlmostInfiniteHabitat;
     type LineageReference = InMemoryLineageReference;
     type LineageStore<L: LineageStore<Self::Habitat, Self::LineageReference>> =
@@ -61,19 +155,28 @@ impl<G: RngCore> Scenario<G> for AlmostInfiniteScenario<G> {
         args: Self::Arguments,
         speciation_probability_per_generation: PositiveUnitF64,
     ) -> Result<Self, Self::Error> {
+        if let Some(i) = args
+            .sample
+            .iter()
+            .position(|shape| !shape.fits_into_habitat())
+        {
+            return Err(AlmostInfiniteError::SampleOutOfBounds(i));
+        }
+
         let habitat = AlmostInfiniteHabitat::default();
-        let dispersal_sampler = AlmostInfiniteNormalDispersalSampler::new(args.sigma);
+        let dispersal_sampler = args.dispersal.into_dispersal_sampler();
         let turnover_rate = UniformTurnoverRate::default();
         let speciation_probability =
             UniformSpeciationProbability::new(speciation_probability_per_generation.into());
 
         Ok(Self {
-            radius: args.radius,
+            sample: args.sample,
 
             habitat,
             dispersal_sampler,
             turnover_rate,
             speciation_probability,
+            _marker: PhantomData::<G>,
         })
     }
 
@@ -97,14 +200,10 @@ impl<G: RngCore> Scenario<G> for AlmostInfiniteScenario<G> {
         &self,
         pre_sampler: OriginPreSampler<I>,
     ) -> Self::OriginSampler<'_, I> {
-        AlmostInfiniteOriginSampler::new(pre_sampler, &self.habitat, self.radius)
+        AlmostInfiniteOriginSampler::new(pre_sampler, &self.habitat, &self.sample)
     }
 
-    fn decompose(
-        _habitat: &Self::Habitat,
-        rank: u32,
-        partitions: NonZeroU32,
-    ) -> Self::Decomposition {
+    fn decompose(&self, rank: u32, partitions: NonZeroU32) -> Self::Decomposition {
         RadialDecomposition::new(rank, partitions)
     }
 
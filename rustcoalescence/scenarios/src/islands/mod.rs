@@ -0,0 +1,204 @@
+use std::{marker::PhantomData, num::NonZeroU32};
+
+use array2d::Array2D;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use necsim_core::cogs::{DispersalSampler, Habitat, LineageStore, RngCore};
+use necsim_core_bond::{NonNegativeF64, PositiveUnitF64};
+
+use necsim_impls_no_std::{
+    cogs::{
+        dispersal_sampler::in_memory::InMemoryDispersalSampler,
+        habitat::in_memory::InMemoryHabitat,
+        lineage_reference::in_memory::InMemoryLineageReference,
+        origin_sampler::{in_memory::InMemoryOriginSampler, pre_sampler::OriginPreSampler},
+        speciation_probability::uniform::UniformSpeciationProbability,
+        turnover_rate::uniform::UniformTurnoverRate,
+    },
+    decomposition::geometric::{GeometricDecomposition, GeometricDecompositionMethod},
+};
+
+use crate::{Scenario, ScenarioArguments};
+
+#[cfg(test)]
+mod test;
+
+#[allow(clippy::module_name_repetitions)]
+pub struct IslandsScenario<G: RngCore> {
+    habitat: InMemoryHabitat,
+    dispersal_map: Array2D<f64>,
+    decomposition: GeometricDecompositionMethod,
+    turnover_rate: UniformTurnoverRate,
+    speciation_probability: UniformSpeciationProbability,
+    _marker: PhantomData<G>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(rename = "Islands")]
+pub struct IslandsArguments {
+    pub demes: Vec<u32>,
+
+    #[serde(alias = "migration")]
+    pub migration_probabilities_per_generation: Vec<Vec<NonNegativeF64>>,
+
+    #[serde(default)]
+    pub decomposition: GeometricDecompositionMethod,
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum IslandsError {
+    #[error("The Islands scenario requires at least one island.")]
+    NoIslands,
+    #[error("The migration matrix must have {0} rows of {0} columns, one for each island.")]
+    InconsistentMigrationMatrixSize(usize),
+    #[error("The total migration probability into island {0} exceeds 1.0.")]
+    ExcessiveMigration(usize),
+    #[error("Island {0} cannot receive migrants from the empty island {1}.")]
+    MigrationFromEmptyIsland(usize, usize),
+}
+
+impl<G: RngCore> ScenarioArguments for IslandsScenario<G> {
+    type Arguments = IslandsArguments;
+}
+
+impl<G: RngCore> Scenario<G> for IslandsScenario<G> {
+    type Decomposition = GeometricDecomposition<Self::Habitat>;
+    type DispersalSampler<D: DispersalSampler<Self::Habitat, G>> = D;
+    type Error = IslandsError;
+    type Habitat = InMemoryHabitat;
+    type LineageReference = InMemoryLineageReference;
+    type LineageStore<L: LineageStore<Self::Habitat, Self::LineageReference>> = L;
+    type OriginSampler<'h, I: Iterator<Item = u64>> = InMemoryOriginSampler<'h, I>;
+    type SpeciationProbability = UniformSpeciationProbability;
+    type TurnoverRate = UniformTurnoverRate;
+
+    fn initialise(
+        args: Self::Arguments,
+        speciation_probability_per_generation: PositiveUnitF64,
+    ) -> Result<Self, Self::Error> {
+        let islands = args.demes.len();
+
+        if islands == 0 {
+            return Err(IslandsError::NoIslands);
+        }
+
+        if args.migration_probabilities_per_generation.len() != islands
+            || args
+                .migration_probabilities_per_generation
+                .iter()
+                .any(|row| row.len() != islands)
+        {
+            return Err(IslandsError::InconsistentMigrationMatrixSize(islands));
+        }
+
+        // The islands are laid out next to each other in a compact 1 x K habitat
+        let mut habitat_map = Array2D::filled_with(0_u32, 1, islands);
+
+        for (i, deme) in args.demes.iter().enumerate() {
+            habitat_map[(0, i)] = *deme;
+        }
+
+        let habitat = InMemoryHabitat::new(habitat_map);
+
+        let mut dispersal_map = Array2D::filled_with(0.0_f64, islands, islands);
+
+        // Row i contains the dispersal from island i, i.e. the migration into
+        //  island i from every other island j
+        for (i, (deme, row)) in args
+            .demes
+            .iter()
+            .zip(args.migration_probabilities_per_generation.iter())
+            .enumerate()
+        {
+            // Empty islands do not disperse anywhere
+            if *deme == 0 {
+                continue;
+            }
+
+            let mut total_migration = 0.0_f64;
+
+            for (j, (target_deme, migration)) in args.demes.iter().zip(row.iter()).enumerate() {
+                // The diagonal is implied by the total migration probability
+                if i == j || migration.get() == 0.0_f64 {
+                    continue;
+                }
+
+                if *target_deme == 0 {
+                    return Err(IslandsError::MigrationFromEmptyIsland(i, j));
+                }
+
+                // The dispersal samplers weight each target by its habitat
+                dispersal_map[(i, j)] = migration.get() / f64::from(*target_deme);
+                total_migration += migration.get();
+            }
+
+            if total_migration > 1.0_f64 {
+                return Err(IslandsError::ExcessiveMigration(i));
+            }
+
+            dispersal_map[(i, i)] = (1.0_f64 - total_migration) / f64::from(*deme);
+        }
+
+        let turnover_rate = UniformTurnoverRate::default();
+        let speciation_probability =
+            UniformSpeciationProbability::new(speciation_probability_per_generation.into());
+
+        Ok(Self {
+            habitat,
+            dispersal_map,
+            decomposition: args.decomposition,
+            turnover_rate,
+            speciation_probability,
+            _marker: PhantomData::<G>,
+        })
+    }
+
+    fn build<D: InMemoryDispersalSampler<Self::Habitat, G>>(
+        self,
+    ) -> (
+        Self::Habitat,
+        Self::DispersalSampler<D>,
+        Self::TurnoverRate,
+        Self::SpeciationProbability,
+    ) {
+        let dispersal_sampler = D::unchecked_new(&self.dispersal_map, &self.habitat);
+
+        (
+            self.habitat,
+            dispersal_sampler,
+            self.turnover_rate,
+            self.speciation_probability,
+        )
+    }
+
+    fn sample_habitat<I: Iterator<Item = u64>>(
+        &self,
+        pre_sampler: OriginPreSampler<I>,
+    ) -> Self::OriginSampler<'_, I> {
+        InMemoryOriginSampler::new(pre_sampler, &self.habitat)
+    }
+
+    fn decompose(&self, rank: u32, partitions: NonZeroU32) -> Self::Decomposition {
+        match self.decomposition.weight(&self.habitat, rank, partitions) {
+            Ok(decomposition) => decomposition,
+            Err(decomposition) => {
+                warn!(
+                    "Islands habitat with {} island(s) could not be partitioned into {} \
+                     partition(s) using the {:?} decomposition.",
+                    self.habitat.get_extent().width(),
+                    partitions.get(),
+                    self.decomposition,
+                );
+
+                decomposition
+            },
+        }
+    }
+
+    fn habitat(&self) -> &Self::Habitat {
+        &self.habitat
+    }
+}
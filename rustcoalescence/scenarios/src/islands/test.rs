@@ -0,0 +1,91 @@
+use necsim_core::{
+    cogs::{DispersalSampler, RngCore},
+    landscape::Location,
+};
+use necsim_core_bond::{NonNegativeF64, PositiveUnitF64};
+
+use necsim_impls_no_std::{
+    cogs::{
+        dispersal_sampler::in_memory::alias::InMemoryAliasDispersalSampler, rng::wyhash::WyHash,
+    },
+    decomposition::geometric::GeometricDecompositionMethod,
+};
+
+use crate::Scenario;
+
+use super::{IslandsArguments, IslandsScenario};
+
+#[test]
+fn test_islands_migration_probabilities() {
+    const SAMPLES: usize = 100_000;
+
+    let demes = vec![1_u32, 10, 100, 0];
+    let migration = vec![
+        vec![0.0_f64, 0.2, 0.3, 0.0],
+        vec![0.05, 0.0, 0.0, 0.0],
+        vec![0.01, 0.1, 0.0, 0.0],
+        vec![0.0, 0.0, 0.0, 0.0],
+    ];
+
+    let scenario = IslandsScenario::<WyHash>::initialise(
+        IslandsArguments {
+            demes: demes.clone(),
+            migration_probabilities_per_generation: migration
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|probability| NonNegativeF64::new(*probability).unwrap())
+                        .collect()
+                })
+                .collect(),
+            decomposition: GeometricDecompositionMethod::default(),
+        },
+        PositiveUnitF64::new(0.1_f64).unwrap(),
+    )
+    .unwrap();
+
+    let (habitat, dispersal_sampler, _turnover_rate, _speciation_probability) =
+        scenario.build::<InMemoryAliasDispersalSampler<_, WyHash>>();
+
+    let mut rng = WyHash::seed_from_u64(42);
+
+    #[allow(clippy::cast_possible_truncation)]
+    for (i, row) in migration.iter().enumerate() {
+        if demes[i] == 0 {
+            continue;
+        }
+
+        let mut frequencies = vec![0_usize; demes.len()];
+
+        for _ in 0..SAMPLES {
+            let target = dispersal_sampler.sample_dispersal_from_location(
+                &Location::new(i as u32, 0),
+                &habitat,
+                &mut rng,
+            );
+
+            frequencies[target.x() as usize] += 1;
+        }
+
+        for (j, frequency) in frequencies.into_iter().enumerate() {
+            let expected = if i == j {
+                1.0_f64 - row.iter().sum::<f64>()
+            } else {
+                row[j]
+            };
+
+            #[allow(clippy::cast_precision_loss)]
+            let sampled = (frequency as f64) / (SAMPLES as f64);
+
+            assert!(
+                (sampled - expected).abs() < 0.01_f64,
+                "migration into island {} from island {} was sampled with probability {} instead \
+                 of {}",
+                i,
+                j,
+                sampled,
+                expected,
+            );
+        }
+    }
+}
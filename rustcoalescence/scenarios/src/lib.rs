@@ -3,6 +3,9 @@
 #![feature(generic_associated_types)]
 #![feature(never_type)]
 
+#[macro_use]
+extern crate contracts;
+
 #[macro_use]
 extern crate log;
 
@@ -23,9 +26,11 @@ use necsim_impls_no_std::{
 };
 
 pub mod almost_infinite;
+pub mod islands;
 pub mod non_spatial;
 pub mod spatially_explicit;
 pub mod spatially_implicit;
+pub mod torus;
 
 pub trait ScenarioArguments {
     type Arguments;
@@ -72,6 +77,5 @@ pub trait Scenario<G: RngCore>: Sized + ScenarioArguments {
 
     fn habitat(&self) -> &Self::Habitat;
 
-    fn decompose(habitat: &Self::Habitat, rank: u32, partitions: NonZeroU32)
-        -> Self::Decomposition;
+    fn decompose(&self, rank: u32, partitions: NonZeroU32) -> Self::Decomposition;
 }
@@ -91,11 +91,7 @@ impl<G: RngCore> Scenario<G> for NonSpatialScenario<G> {
         NonSpatialOriginSampler::new(pre_sampler, &self.habitat)
     }
 
-    fn decompose(
-        _habitat: &Self::Habitat,
-        rank: u32,
-        partitions: NonZeroU32,
-    ) -> Self::Decomposition {
+    fn decompose(&self, rank: u32, partitions: NonZeroU32) -> Self::Decomposition {
         ModuloDecomposition::new(rank, partitions)
     }
 
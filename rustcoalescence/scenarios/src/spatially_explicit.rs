@@ -1,9 +1,14 @@
 use std::{marker::PhantomData, num::NonZeroU32};
 
 use array2d::Array2D;
-use necsim_core::cogs::{DispersalSampler, Habitat, LineageStore, RngCore};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use necsim_core::{
+    cogs::{Backup, DispersalSampler, Habitat, LineageStore, RngCore},
+    landscape::Location,
+};
+
 use necsim_core_bond::PositiveUnitF64;
 
 use necsim_impls_no_std::{
@@ -17,7 +22,11 @@ use necsim_impls_no_std::{
         speciation_probability::uniform::UniformSpeciationProbability,
         turnover_rate::uniform::UniformTurnoverRate,
     },
-    decomposition::equal::EqualDecomposition,
+    decomposition::{
+        geometric::{GeometricDecomposition, GeometricDecompositionMethod},
+        min_cut::MinCutDecomposition,
+        Decomposition,
+    },
 };
 
 use necsim_impls_std::cogs::dispersal_sampler::in_memory::error::InMemoryDispersalSamplerError;
@@ -28,6 +37,8 @@ use crate::{Scenario, ScenarioArguments};
 pub struct SpatiallyExplicitScenario<G: RngCore> {
     habitat: InMemoryHabitat,
     dispersal_map: Array2D<f64>,
+    sample_map: Option<Array2D<f64>>,
+    decomposition: SpatiallyExplicitDecompositionMethod,
     turnover_rate: UniformTurnoverRate,
     speciation_probability: UniformSpeciationProbability,
     _marker: PhantomData<G>,
@@ -38,6 +49,45 @@ pub struct SpatiallyExplicitScenario<G: RngCore> {
 pub struct InMemoryArguments {
     pub habitat_map: Array2D<u32>,
     pub dispersal_map: Array2D<f64>,
+    pub sample_map: Option<Array2D<f64>>,
+    pub decomposition: SpatiallyExplicitDecompositionMethod,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum SpatiallyExplicitDecompositionMethod {
+    /// Split the Morton curve over the habitat by weight
+    Equal,
+    /// Recursively bisect the habitat weight along alternating axes
+    KdTree,
+    /// Split the Hilbert curve over the habitat by weight
+    Hilbert,
+    /// Minimise the expected dispersal between balanced partitions
+    MinCut,
+}
+
+impl Default for SpatiallyExplicitDecompositionMethod {
+    fn default() -> Self {
+        Self::Equal
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub enum SpatiallyExplicitDecomposition {
+    Geometric(GeometricDecomposition<InMemoryHabitat>),
+    MinCut(MinCutDecomposition<InMemoryHabitat>),
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum SpatiallyExplicitError {
+    #[error(transparent)]
+    DispersalMap(#[from] InMemoryDispersalSamplerError),
+    #[error("The size of the sample map was inconsistent with the size of the habitat map.")]
+    InconsistentSampleMapSize,
+    #[error("The sample map must only contain sampling fractions in [0.0, 1.0].")]
+    InvalidSampleFraction,
 }
 
 #[derive(Debug, Error)]
@@ -50,9 +100,9 @@ impl<G: RngCore> ScenarioArguments for SpatiallyExplicitScenario<G> {
 }
 
 impl<G: RngCore> Scenario<G> for SpatiallyExplicitScenario<G> {
-    type Decomposition = EqualDecomposition<Self::Habitat>;
+    type Decomposition = SpatiallyExplicitDecomposition;
     type DispersalSampler<D: DispersalSampler<Self::Habitat, G>> = D;
-    type Error = InMemoryDispersalSamplerError;
+    type Error = SpatiallyExplicitError;
     type Habitat = InMemoryHabitat;
     type LineageReference = InMemoryLineageReference;
     type LineageStore<L: LineageStore<Self::Habitat, Self::LineageReference>> = L;
@@ -75,16 +125,33 @@ impl<G: RngCore> Scenario<G> for SpatiallyExplicitScenario<G> {
         if args.dispersal_map.num_rows() != habitat_area
             || args.dispersal_map.num_columns() != habitat_area
         {
-            return Err(InMemoryDispersalSamplerError::InconsistentDispersalMapSize);
+            return Err(InMemoryDispersalSamplerError::InconsistentDispersalMapSize.into());
         }
 
         if !explicit_in_memory_dispersal_check_contract(&args.dispersal_map, &habitat) {
-            return Err(InMemoryDispersalSamplerError::InconsistentDispersalProbabilities);
+            return Err(InMemoryDispersalSamplerError::InconsistentDispersalProbabilities.into());
+        }
+
+        if let Some(sample_map) = &args.sample_map {
+            if sample_map.num_rows() != (habitat_extent.height() as usize)
+                || sample_map.num_columns() != (habitat_extent.width() as usize)
+            {
+                return Err(SpatiallyExplicitError::InconsistentSampleMapSize);
+            }
+
+            if !sample_map
+                .elements_row_major_iter()
+                .all(|fraction| (0.0_f64..=1.0_f64).contains(fraction))
+            {
+                return Err(SpatiallyExplicitError::InvalidSampleFraction);
+            }
         }
 
         Ok(Self {
             habitat,
             dispersal_map: args.dispersal_map,
+            sample_map: args.sample_map,
+            decomposition: args.decomposition,
             turnover_rate,
             speciation_probability,
             _marker: PhantomData::<G>,
@@ -113,23 +180,54 @@ impl<G: RngCore> Scenario<G> for SpatiallyExplicitScenario<G> {
         &self,
         pre_sampler: OriginPreSampler<I>,
     ) -> Self::OriginSampler<'_, I> {
-        InMemoryOriginSampler::new(pre_sampler, &self.habitat)
+        match &self.sample_map {
+            Some(sample_map) => {
+                InMemoryOriginSampler::with_sample_map(pre_sampler, &self.habitat, sample_map)
+            },
+            None => InMemoryOriginSampler::new(pre_sampler, &self.habitat),
+        }
     }
 
-    fn decompose(
-        habitat: &Self::Habitat,
-        rank: u32,
-        partitions: NonZeroU32,
-    ) -> Self::Decomposition {
-        match EqualDecomposition::weight(habitat, rank, partitions) {
+    fn decompose(&self, rank: u32, partitions: NonZeroU32) -> Self::Decomposition {
+        let geometric = match self.decomposition {
+            SpatiallyExplicitDecompositionMethod::Equal => {
+                Some(GeometricDecompositionMethod::Equal)
+            },
+            SpatiallyExplicitDecompositionMethod::KdTree => {
+                Some(GeometricDecompositionMethod::KdTree)
+            },
+            SpatiallyExplicitDecompositionMethod::Hilbert => {
+                Some(GeometricDecompositionMethod::Hilbert)
+            },
+            SpatiallyExplicitDecompositionMethod::MinCut => None,
+        };
+
+        let decomposition = match geometric {
+            Some(method) => method
+                .weight(&self.habitat, rank, partitions)
+                .map(SpatiallyExplicitDecomposition::Geometric)
+                .map_err(SpatiallyExplicitDecomposition::Geometric),
+            None => MinCutDecomposition::new(
+                &self.habitat,
+                &self.dispersal_map,
+                &self.turnover_rate,
+                rank,
+                partitions,
+            )
+            .map(SpatiallyExplicitDecomposition::MinCut)
+            .map_err(SpatiallyExplicitDecomposition::MinCut),
+        };
+
+        match decomposition {
             Ok(decomposition) => decomposition,
             Err(decomposition) => {
                 warn!(
                     "Spatially explicit habitat of size {}x{} could not be partitioned into {} \
-                     partition(s).",
-                    habitat.get_extent().width(),
-                    habitat.get_extent().height(),
+                     partition(s) using the {:?} decomposition.",
+                    self.habitat.get_extent().width(),
+                    self.habitat.get_extent().height(),
                     partitions.get(),
+                    self.decomposition,
                 );
 
                 decomposition
@@ -141,3 +239,45 @@ impl<G: RngCore> Scenario<G> for SpatiallyExplicitScenario<G> {
         &self.habitat
     }
 }
+
+#[contract_trait]
+impl Backup for SpatiallyExplicitDecomposition {
+    unsafe fn backup_unchecked(&self) -> Self {
+        match self {
+            Self::Geometric(decomposition) => Self::Geometric(decomposition.backup_unchecked()),
+            Self::MinCut(decomposition) => Self::MinCut(decomposition.backup_unchecked()),
+        }
+    }
+}
+
+#[contract_trait]
+impl Decomposition<InMemoryHabitat> for SpatiallyExplicitDecomposition {
+    fn get_subdomain_rank(&self) -> u32 {
+        match self {
+            Self::Geometric(decomposition) => decomposition.get_subdomain_rank(),
+            Self::MinCut(decomposition) => decomposition.get_subdomain_rank(),
+        }
+    }
+
+    fn get_number_of_subdomains(&self) -> NonZeroU32 {
+        match self {
+            Self::Geometric(decomposition) => decomposition.get_number_of_subdomains(),
+            Self::MinCut(decomposition) => decomposition.get_number_of_subdomains(),
+        }
+    }
+
+    fn map_location_to_subdomain_rank(
+        &self,
+        location: &Location,
+        habitat: &InMemoryHabitat,
+    ) -> u32 {
+        match self {
+            Self::Geometric(decomposition) => {
+                decomposition.map_location_to_subdomain_rank(location, habitat)
+            },
+            Self::MinCut(decomposition) => {
+                decomposition.map_location_to_subdomain_rank(location, habitat)
+            },
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::num::NonZeroU32;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use necsim_core::cogs::{DispersalSampler, LineageStore, RngCore};
 use necsim_core_bond::PositiveUnitF64;
@@ -29,7 +29,7 @@ pub struct SpatiallyImplicitScenario<G: RngCore> {
     speciation_probability: SpatiallyImplicitSpeciationProbability,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 #[serde(rename = "SpatiallyImplicit")]
 pub struct SpatiallyImplicitArguments {
@@ -105,11 +105,7 @@ impl<G: RngCore> Scenario<G> for SpatiallyImplicitScenario<G> {
         SpatiallyImplicitOriginSampler::new(pre_sampler, &self.habitat)
     }
 
-    fn decompose(
-        _habitat: &Self::Habitat,
-        rank: u32,
-        partitions: NonZeroU32,
-    ) -> Self::Decomposition {
+    fn decompose(&self, rank: u32, partitions: NonZeroU32) -> Self::Decomposition {
         ModuloDecomposition::new(rank, partitions)
     }
 
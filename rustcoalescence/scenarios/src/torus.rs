@@ -0,0 +1,121 @@
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::cogs::{DispersalSampler, Habitat, LineageStore, RngCore};
+use necsim_core_bond::PositiveUnitF64;
+
+use necsim_impls_no_std::{
+    cogs::{
+        dispersal_sampler::torus::{TorusDispersalKernel, TorusDispersalSampler},
+        habitat::torus::TorusHabitat,
+        lineage_reference::in_memory::InMemoryLineageReference,
+        origin_sampler::{pre_sampler::OriginPreSampler, torus::TorusOriginSampler},
+        speciation_probability::uniform::UniformSpeciationProbability,
+        turnover_rate::uniform::UniformTurnoverRate,
+    },
+    decomposition::geometric::{GeometricDecomposition, GeometricDecompositionMethod},
+};
+
+use crate::{Scenario, ScenarioArguments};
+
+#[allow(clippy::module_name_repetitions)]
+pub struct TorusScenario<G: RngCore> {
+    habitat: TorusHabitat,
+    dispersal_sampler: TorusDispersalSampler<G>,
+    decomposition: GeometricDecompositionMethod,
+    turnover_rate: UniformTurnoverRate,
+    speciation_probability: UniformSpeciationProbability,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(rename = "Torus")]
+pub struct TorusArguments {
+    pub width: u32,
+    pub height: u32,
+    pub deme: u32,
+    pub dispersal: TorusDispersalKernel,
+    #[serde(default)]
+    pub decomposition: GeometricDecompositionMethod,
+}
+
+impl<G: RngCore> ScenarioArguments for TorusScenario<G> {
+    type Arguments = TorusArguments;
+}
+
+impl<G: RngCore> Scenario<G> for TorusScenario<G> {
+    type Decomposition = GeometricDecomposition<Self::Habitat>;
+    type DispersalSampler<D: DispersalSampler<Self::Habitat, G>> = TorusDispersalSampler<G>;
+    type Error = !;
+    type Habitat = TorusHabitat;
+    type LineageReference = InMemoryLineageReference;
+    type LineageStore<L: LineageStore<Self::Habitat, Self::LineageReference>> = L;
+    type OriginSampler<'h, I: Iterator<Item = u64>> = TorusOriginSampler<'h, I>;
+    type SpeciationProbability = UniformSpeciationProbability;
+    type TurnoverRate = UniformTurnoverRate;
+
+    fn initialise(
+        args: Self::Arguments,
+        speciation_probability_per_generation: PositiveUnitF64,
+    ) -> Result<Self, Self::Error> {
+        let habitat = TorusHabitat::new(args.width, args.height, args.deme);
+        let dispersal_sampler = TorusDispersalSampler::new(&habitat, args.dispersal);
+        let turnover_rate = UniformTurnoverRate::default();
+        let speciation_probability =
+            UniformSpeciationProbability::new(speciation_probability_per_generation.into());
+
+        Ok(Self {
+            habitat,
+            dispersal_sampler,
+            decomposition: args.decomposition,
+            turnover_rate,
+            speciation_probability,
+        })
+    }
+
+    fn build<D: DispersalSampler<Self::Habitat, G>>(
+        self,
+    ) -> (
+        Self::Habitat,
+        Self::DispersalSampler<D>,
+        Self::TurnoverRate,
+        Self::SpeciationProbability,
+    ) {
+        (
+            self.habitat,
+            self.dispersal_sampler,
+            self.turnover_rate,
+            self.speciation_probability,
+        )
+    }
+
+    fn sample_habitat<I: Iterator<Item = u64>>(
+        &self,
+        pre_sampler: OriginPreSampler<I>,
+    ) -> Self::OriginSampler<'_, I> {
+        TorusOriginSampler::new(pre_sampler, &self.habitat)
+    }
+
+    fn decompose(&self, rank: u32, partitions: NonZeroU32) -> Self::Decomposition {
+        match self.decomposition.area(&self.habitat, rank, partitions) {
+            Ok(decomposition) => decomposition,
+            Err(decomposition) => {
+                warn!(
+                    "Torus habitat of size {}x{} could not be partitioned into {} partition(s) \
+                     using the {:?} decomposition.",
+                    self.habitat.get_extent().width(),
+                    self.habitat.get_extent().height(),
+                    partitions.get(),
+                    self.decomposition,
+                );
+
+                decomposition
+            },
+        }
+    }
+
+    fn habitat(&self) -> &Self::Habitat {
+        &self.habitat
+    }
+}
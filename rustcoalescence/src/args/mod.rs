@@ -2,20 +2,33 @@ use std::{convert::TryFrom, fmt, path::PathBuf};
 
 use array2d::Array2D;
 
-use serde::{de::Deserializer, Deserialize};
+use serde::{de::Deserializer, Deserialize, Serialize, Serializer};
 use serde_state::DeserializeState;
 
 use structopt::StructOpt;
 
 mod parse;
+mod provenance;
+pub mod sweep;
 
-use necsim_core_bond::{ClosedUnitF64, Partition, PositiveUnitF64};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, Partition, PositiveF64, PositiveUnitF64};
 
+use necsim_impls_no_std::{
+    cogs::origin_sampler::almost_infinite::AlmostInfiniteSampleShape,
+    parallelisation::SimulationLimits,
+};
 use necsim_impls_std::event_log::{recorder::EventLogRecorder, replay::EventLogReplay};
 
 use rustcoalescence_scenarios::{
-    almost_infinite::AlmostInfiniteArguments, non_spatial::NonSpatialArguments,
-    spatially_explicit::InMemoryArguments, spatially_implicit::SpatiallyImplicitArguments,
+    almost_infinite::{
+        AlmostInfiniteArguments, CauchyDispersal, Clark2DtDispersal, ExpPowerDispersal,
+        LaplaceDispersal, NormalDispersal,
+    },
+    islands::IslandsArguments,
+    non_spatial::NonSpatialArguments,
+    spatially_explicit::{InMemoryArguments, SpatiallyExplicitDecompositionMethod},
+    spatially_implicit::SpatiallyImplicitArguments,
+    torus::TorusArguments,
 };
 
 #[cfg(any(
@@ -32,6 +45,9 @@ use necsim_plugins_core::import::{AnyReporterPluginVec, ReporterPluginLibrary};
 pub enum RustcoalescenceArgs {
     Simulate(CommandArgs),
     Replay(CommandArgs),
+    Check(CommandArgs),
+    CompareLogs(CommandArgs),
+    Sweep(CommandArgs),
 }
 
 #[derive(Debug, StructOpt)]
@@ -48,6 +64,7 @@ pub struct CommandArgs {
 pub struct SimulateArgs {
     pub common: CommonArgs,
     pub event_log: Option<EventLogRecorder>,
+    pub results: Option<PathBuf>,
     pub scenario: Scenario,
     pub reporters: AnyReporterPluginVec,
 }
@@ -57,17 +74,77 @@ impl<'de> DeserializeState<'de, Partition> for SimulateArgs {
     where
         D: Deserializer<'de>,
     {
+        use serde::de::Error;
+
         let raw = SimulateArgsRaw::deserialize_state(seed, deserializer)?;
+        raw.check_limits()?;
+
+        let common = CommonArgs {
+            speciation_probability_per_generation: raw.speciation_probability_per_generation,
+            sample_percentage: raw.sample_percentage,
+            seed: raw.seed,
+            limits: raw.limits,
+            decomposition_map: raw.decomposition_map,
+            algorithm: raw.algorithm,
+        };
+        let reporters = raw.reporters.into_iter().flatten().collect();
+
+        let event_log = match raw.event_log {
+            Some(mut event_log_path) => {
+                event_log_path.push(seed.rank().to_string());
+
+                let event_log =
+                    EventLogRecorder::try_new(&event_log_path).map_err(D::Error::custom)?;
+
+                // Record the provenance of the event log alongside its segments
+                provenance::event_log_metadata(*seed, &common, &raw.scenario, &reporters)
+                    .and_then(|metadata| metadata.write_to_directory(event_log.directory()))
+                    .map_err(D::Error::custom)?;
+
+                Some(event_log)
+            },
+            None => None,
+        };
 
+        Ok(Self {
+            common,
+            event_log,
+            results: raw.results,
+            scenario: raw.scenario.into(),
+            reporters,
+        })
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct CheckArgs {
+    pub common: CommonArgs,
+    pub event_log: Option<PathBuf>,
+    pub scenario: Scenario,
+    pub reporters: AnyReporterPluginVec,
+}
+
+impl<'de> DeserializeState<'de, Partition> for CheckArgs {
+    fn deserialize_state<D>(seed: &mut Partition, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = SimulateArgsRaw::deserialize_state(seed, deserializer)?;
+        raw.check_limits()?;
+
+        // The event log directory is not created during a check
         Ok(Self {
             common: CommonArgs {
                 speciation_probability_per_generation: raw.speciation_probability_per_generation,
                 sample_percentage: raw.sample_percentage,
                 seed: raw.seed,
+                limits: raw.limits,
+                decomposition_map: raw.decomposition_map,
                 algorithm: raw.algorithm,
             },
             event_log: raw.event_log,
-            scenario: raw.scenario,
+            scenario: raw.scenario.into(),
             reporters: raw.reporters.into_iter().flatten().collect(),
         })
     }
@@ -86,39 +163,38 @@ struct SimulateArgsRaw {
 
     seed: u64,
 
+    #[serde(default)]
+    limits: Limits,
+
+    #[serde(default)]
+    decomposition_map: Option<PathBuf>,
+
     #[serde(deserialize_state)]
     algorithm: Algorithm,
 
     #[serde(alias = "log")]
     #[serde(default)]
-    #[serde(deserialize_state_with = "deserialize_state_event_log")]
-    event_log: Option<EventLogRecorder>,
+    event_log: Option<PathBuf>,
 
-    scenario: Scenario,
+    #[serde(default)]
+    results: Option<PathBuf>,
+
+    scenario: ScenarioRaw,
 
     reporters: Vec<ReporterPluginLibrary>,
 }
 
-fn deserialize_state_event_log<'de, D>(
-    partition: &mut Partition,
-    deserializer: D,
-) -> Result<Option<EventLogRecorder>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    use serde::de::Error;
-
-    let event_log_path = <Option<PathBuf>>::deserialize(deserializer)?;
-
-    match event_log_path {
-        Some(mut event_log_path) => {
-            event_log_path.push(partition.rank().to_string());
-
-            let event_log = EventLogRecorder::try_new(&event_log_path).map_err(D::Error::custom)?;
+impl SimulateArgsRaw {
+    fn check_limits<E: serde::de::Error>(&self) -> Result<(), E> {
+        if self.limits.max_wall_time.is_some() && !self.algorithm.is_interruptible() {
+            return Err(E::custom(format!(
+                "The {} algorithm cannot be interrupted in its parallelism mode and is thus \
+                 incompatible with the max_wall_time limit.",
+                self.algorithm
+            )));
+        }
 
-            Ok(Some(event_log))
-        },
-        None => Ok(None),
+        Ok(())
     }
 }
 
@@ -128,9 +204,33 @@ pub struct CommonArgs {
     pub speciation_probability_per_generation: PositiveUnitF64,
     pub sample_percentage: ClosedUnitF64,
     pub seed: u64,
+    pub limits: Limits,
+    /// Output path for the TIFF map of the partition of each location
+    pub decomposition_map: Option<PathBuf>,
     pub algorithm: Algorithm,
 }
 
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Limits {
+    #[serde(default)]
+    pub max_time: Option<PositiveF64>,
+    #[serde(default)]
+    pub max_steps: Option<u64>,
+    /// Maximum wall-clock time in seconds
+    #[serde(default)]
+    pub max_wall_time: Option<PositiveF64>,
+}
+
+impl From<Limits> for SimulationLimits {
+    fn from(limits: Limits) -> Self {
+        Self {
+            max_time: limits.max_time,
+            max_steps: limits.max_steps,
+        }
+    }
+}
+
 #[derive(Debug, DeserializeState)]
 #[serde(deserialize_state = "Partition")]
 pub enum Algorithm {
@@ -177,13 +277,70 @@ impl fmt::Display for Algorithm {
     }
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(from = "ScenarioRaw")]
+impl Serialize for Algorithm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[allow(unreachable_patterns)]
+        match self {
+            #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+            Algorithm::Classical(args) => {
+                serializer.serialize_newtype_variant("Algorithm", 0, "Classical", args)
+            },
+            #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+            Algorithm::Gillespie(args) => {
+                serializer.serialize_newtype_variant("Algorithm", 1, "Gillespie", args)
+            },
+            #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+            Algorithm::SkippingGillespie(args) => {
+                serializer.serialize_newtype_variant("Algorithm", 2, "SkippingGillespie", args)
+            },
+            #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+            Algorithm::Cuda(args) => {
+                serializer.serialize_newtype_variant("Algorithm", 3, "Cuda", args)
+            },
+            #[cfg(feature = "rustcoalescence-algorithms-independent")]
+            Algorithm::Independent(args) => {
+                serializer.serialize_newtype_variant("Algorithm", 4, "Independent", args)
+            },
+            _ => serializer.serialize_unit_variant("Algorithm", 5, "Unknown"),
+        }
+    }
+}
+
+impl Algorithm {
+    /// Checks if a simulation with this algorithm can be interrupted, which
+    ///  requires all partitions to agree on when to stop
+    #[must_use]
+    pub fn is_interruptible(&self) -> bool {
+        #[allow(unreachable_patterns)]
+        match self {
+            // Landscape partitions only synchronise once they have all
+            //  finished, and thus cannot vote on an interrupt
+            #[cfg(feature = "rustcoalescence-algorithms-independent")]
+            Algorithm::Independent(args) => {
+                use rustcoalescence_algorithms_independent::arguments::ParallelismMode;
+
+                !matches!(
+                    args.parallelism_mode,
+                    ParallelismMode::Landscape | ParallelismMode::Probabilistic(_)
+                )
+            },
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug)]
 pub enum Scenario {
     SpatiallyExplicit(InMemoryArguments),
     NonSpatial(NonSpatialArguments),
     SpatiallyImplicit(SpatiallyImplicitArguments),
-    AlmostInfinite(AlmostInfiniteArguments),
+    AlmostInfiniteNormal(AlmostInfiniteArguments<NormalDispersal>),
+    AlmostInfiniteClark2Dt(AlmostInfiniteArguments<Clark2DtDispersal>),
+    AlmostInfiniteCauchy(AlmostInfiniteArguments<CauchyDispersal>),
+    AlmostInfiniteExpPower(AlmostInfiniteArguments<ExpPowerDispersal>),
+    AlmostInfiniteLaplace(AlmostInfiniteArguments<LaplaceDispersal>),
+    Islands(IslandsArguments),
+    Torus(TorusArguments),
 }
 
 impl From<ScenarioRaw> for Scenario {
@@ -193,6 +350,8 @@ impl From<ScenarioRaw> for Scenario {
                 Scenario::SpatiallyExplicit(InMemoryArguments {
                     habitat_map: args.habitat_map,
                     dispersal_map: args.dispersal_map,
+                    sample_map: args.sample_map,
+                    decomposition: args.decomposition,
                 })
             },
             ScenarioRaw::NonSpatial(args) => {
@@ -207,6 +366,8 @@ impl From<ScenarioRaw> for Scenario {
                     Scenario::SpatiallyExplicit(InMemoryArguments {
                         habitat_map,
                         dispersal_map,
+                        sample_map: None,
+                        decomposition: SpatiallyExplicitDecompositionMethod::default(),
                     })
                 } else {
                     Scenario::NonSpatial(NonSpatialArguments {
@@ -216,24 +377,65 @@ impl From<ScenarioRaw> for Scenario {
                 }
             },
             ScenarioRaw::SpatiallyImplicit(args) => Scenario::SpatiallyImplicit(args),
-            ScenarioRaw::AlmostInfinite(args) => Scenario::AlmostInfinite(args),
+            ScenarioRaw::AlmostInfinite(args) => match args.dispersal_spec {
+                AlmostInfiniteDispersalRaw::Normal { sigma } => {
+                    Scenario::AlmostInfiniteNormal(AlmostInfiniteArguments {
+                        sample: args.sample,
+                        dispersal: NormalDispersal { sigma },
+                    })
+                },
+                AlmostInfiniteDispersalRaw::Clark2Dt { scale, shape } => {
+                    Scenario::AlmostInfiniteClark2Dt(AlmostInfiniteArguments {
+                        sample: args.sample,
+                        dispersal: Clark2DtDispersal { scale, shape },
+                    })
+                },
+                AlmostInfiniteDispersalRaw::Cauchy { scale } => {
+                    Scenario::AlmostInfiniteCauchy(AlmostInfiniteArguments {
+                        sample: args.sample,
+                        dispersal: CauchyDispersal { scale },
+                    })
+                },
+                AlmostInfiniteDispersalRaw::ExpPower { scale, shape } => {
+                    Scenario::AlmostInfiniteExpPower(AlmostInfiniteArguments {
+                        sample: args.sample,
+                        dispersal: ExpPowerDispersal { scale, shape },
+                    })
+                },
+                AlmostInfiniteDispersalRaw::Laplace { scale } => {
+                    Scenario::AlmostInfiniteLaplace(AlmostInfiniteArguments {
+                        sample: args.sample,
+                        dispersal: LaplaceDispersal { scale },
+                    })
+                },
+            },
+            ScenarioRaw::Islands(args) => Scenario::Islands(args),
+            ScenarioRaw::Torus(args) => Scenario::Torus(args),
         }
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 enum ScenarioRaw {
     SpatiallyExplicit(InMemoryArgs),
     NonSpatial(NonSpatialArgsRaw),
     SpatiallyImplicit(SpatiallyImplicitArguments),
-    AlmostInfinite(AlmostInfiniteArguments),
+    AlmostInfinite(AlmostInfiniteArgs),
+    Islands(IslandsArguments),
+    Torus(TorusArguments),
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 #[serde(try_from = "InMemoryArgsRaw")]
 struct InMemoryArgs {
     habitat_map: Array2D<u32>,
     dispersal_map: Array2D<f64>,
+    sample_map: Option<Array2D<f64>>,
+    habitat_path: PathBuf,
+    dispersal_path: PathBuf,
+    sample_path: Option<PathBuf>,
+    loading_mode: MapLoadingMode,
+    decomposition: SpatiallyExplicitDecompositionMethod,
 }
 
 impl TryFrom<InMemoryArgsRaw> for InMemoryArgs {
@@ -272,14 +474,39 @@ impl TryFrom<InMemoryArgsRaw> for InMemoryArgs {
             habitat_map.num_rows()
         );
 
+        let sample_map = match &raw.sample_map {
+            Some(sample_path) => {
+                info!("Starting to load the sample map {:?} ...", sample_path);
+
+                let sample_map = crate::maps::load_sample_map(sample_path, raw.loading_mode)
+                    .map_err(|err| format!("{:?}", err))?;
+
+                info!(
+                    "Successfully loaded the sample map {:?} with dimensions {}x{} [cols x rows].",
+                    sample_path,
+                    sample_map.num_columns(),
+                    sample_map.num_rows()
+                );
+
+                Some(sample_map)
+            },
+            None => None,
+        };
+
         Ok(InMemoryArgs {
             habitat_map,
             dispersal_map,
+            sample_map,
+            habitat_path: raw.habitat_map,
+            dispersal_path: raw.dispersal_map,
+            sample_path: raw.sample_map,
+            loading_mode: raw.loading_mode,
+            decomposition: raw.decomposition,
         })
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub enum MapLoadingMode {
     FixMe,
     OffByOne,
@@ -303,12 +530,18 @@ struct InMemoryArgsRaw {
     #[serde(alias = "dispersal")]
     dispersal_map: PathBuf,
 
+    #[serde(default)]
+    sample_map: Option<PathBuf>,
+
     #[serde(default)]
     #[serde(alias = "mode")]
     loading_mode: MapLoadingMode,
+
+    #[serde(default)]
+    decomposition: SpatiallyExplicitDecompositionMethod,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 #[serde(deny_unknown_fields)]
 #[serde(rename = "NonSpatial")]
@@ -320,6 +553,130 @@ struct NonSpatialArgsRaw {
     pub spatial: bool,
 }
 
+#[derive(Deserialize)]
+#[serde(try_from = "AlmostInfiniteArgsRaw")]
+struct AlmostInfiniteArgs {
+    sample: Vec<AlmostInfiniteSampleShape>,
+    radius: Option<u32>,
+    sample_spec: Option<Vec<AlmostInfiniteSampleRaw>>,
+    dispersal_spec: AlmostInfiniteDispersalRaw,
+}
+
+impl TryFrom<AlmostInfiniteArgsRaw> for AlmostInfiniteArgs {
+    type Error = String;
+
+    fn try_from(raw: AlmostInfiniteArgsRaw) -> Result<Self, Self::Error> {
+        let sample = match (raw.radius, &raw.sample) {
+            (Some(radius), None) => vec![AlmostInfiniteSampleShape::Circle {
+                centre: (0, 0),
+                radius,
+            }],
+            (None, Some(sample)) => sample
+                .iter()
+                .map(|shape| match shape {
+                    AlmostInfiniteSampleRaw::Circle { centre, radius } => {
+                        Ok(AlmostInfiniteSampleShape::Circle {
+                            centre: *centre,
+                            radius: *radius,
+                        })
+                    },
+                    AlmostInfiniteSampleRaw::Rectangle { origin, size } => {
+                        Ok(AlmostInfiniteSampleShape::Rectangle {
+                            origin: *origin,
+                            size: *size,
+                        })
+                    },
+                    AlmostInfiniteSampleRaw::Mask { origin, path } => {
+                        info!("Starting to load the sample mask {:?} ...", path);
+
+                        let mask = crate::maps::load_sample_mask(path)
+                            .map_err(|err| format!("{:?}", err))?;
+
+                        info!(
+                            "Successfully loaded the sample mask {:?} with dimensions {}x{} [cols \
+                             x rows].",
+                            path,
+                            mask.num_columns(),
+                            mask.num_rows()
+                        );
+
+                        Ok(AlmostInfiniteSampleShape::Mask {
+                            origin: *origin,
+                            mask,
+                        })
+                    },
+                })
+                .collect::<Result<Vec<_>, String>>()?,
+            _ => {
+                return Err(String::from(
+                    "The AlmostInfinite scenario requires either a sampling radius or a sample.",
+                ))
+            },
+        };
+
+        Ok(AlmostInfiniteArgs {
+            sample,
+            radius: raw.radius,
+            sample_spec: raw.sample,
+            dispersal_spec: raw.dispersal,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "AlmostInfinite")]
+struct AlmostInfiniteArgsRaw {
+    #[serde(default)]
+    radius: Option<u32>,
+    #[serde(default)]
+    sample: Option<Vec<AlmostInfiniteSampleRaw>>,
+    dispersal: AlmostInfiniteDispersalRaw,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+enum AlmostInfiniteSampleRaw {
+    Circle {
+        #[serde(default)]
+        centre: (i32, i32),
+        radius: u32,
+    },
+    Rectangle {
+        #[serde(default)]
+        origin: (i32, i32),
+        size: (u32, u32),
+    },
+    Mask {
+        #[serde(default)]
+        origin: (i32, i32),
+        path: PathBuf,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+enum AlmostInfiniteDispersalRaw {
+    Normal {
+        sigma: NonNegativeF64,
+    },
+    Clark2Dt {
+        scale: PositiveF64,
+        shape: PositiveF64,
+    },
+    Cauchy {
+        scale: PositiveF64,
+    },
+    ExpPower {
+        scale: PositiveF64,
+        shape: PositiveF64,
+    },
+    Laplace {
+        scale: PositiveF64,
+    },
+}
+
 #[derive(Debug)]
 #[allow(clippy::module_name_repetitions)]
 pub struct ReplayArgs {
@@ -338,7 +695,14 @@ impl<'de> DeserializeState<'de, Partition> for ReplayArgs {
             ));
         }
 
-        let raw = ReplayArgsRaw::deserialize(deserializer)?;
+        let mut raw = ReplayArgsRaw::deserialize(deserializer)?;
+
+        // Salvaging corrupted segments requires them to be validated
+        if raw.validate || raw.salvage {
+            raw.logs
+                .validate(raw.salvage)
+                .map_err(serde::de::Error::custom)?;
+        }
 
         let log = raw.logs;
         let reporters = raw.reporters.into_iter().flatten().collect();
@@ -391,6 +755,71 @@ impl<'de> DeserializeState<'de, Partition> for ReplayArgs {
     }
 }
 
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct CompareLogsArgs {
+    pub left: EventLogReplay,
+    pub right: EventLogReplay,
+    pub tolerance: EventTimeTolerance,
+    pub context: usize,
+}
+
+impl<'de> DeserializeState<'de, Partition> for CompareLogsArgs {
+    fn deserialize_state<D>(partition: &mut Partition, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if partition.partitions().get() > 1 {
+            return Err(serde::de::Error::custom(
+                "Event log comparison mode is incompatible with external parallelisation",
+            ));
+        }
+
+        let mut raw = CompareLogsArgsRaw::deserialize(deserializer)?;
+
+        if raw.validate {
+            raw.left.validate(false).map_err(serde::de::Error::custom)?;
+            raw.right
+                .validate(false)
+                .map_err(serde::de::Error::custom)?;
+        }
+
+        Ok(Self {
+            left: raw.left,
+            right: raw.right,
+            tolerance: raw.tolerance,
+            context: raw.context.unwrap_or(5),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum EventTimeTolerance {
+    Exact,
+    Absolute(NonNegativeF64),
+}
+
+impl Default for EventTimeTolerance {
+    fn default() -> Self {
+        Self::Exact
+    }
+}
+
+#[derive(Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+struct CompareLogsArgsRaw {
+    left: EventLogReplay,
+    right: EventLogReplay,
+    #[serde(default)]
+    validate: bool,
+    #[serde(default)]
+    tolerance: EventTimeTolerance,
+    #[serde(default)]
+    context: Option<usize>,
+}
+
 #[derive(Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 #[serde(deny_unknown_fields)]
@@ -412,5 +841,9 @@ struct ReplayArgsRaw {
     logs: EventLogReplay,
     #[serde(default)]
     mode: ReplayMode,
+    #[serde(default)]
+    validate: bool,
+    #[serde(default)]
+    salvage: bool,
     reporters: Vec<ReporterPluginLibrary>,
 }
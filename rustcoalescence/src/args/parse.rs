@@ -5,7 +5,7 @@ use necsim_core_bond::Partition;
 
 use necsim_partitioning_core::Partitioning;
 
-use super::{CommandArgs, ReplayArgs, SimulateArgs};
+use super::{sweep::SweepArgs, CheckArgs, CommandArgs, CompareLogsArgs, ReplayArgs, SimulateArgs};
 
 /// Transform the `command_args` into a RON `String`
 fn into_ron_args(command_args: CommandArgs) -> String {
@@ -87,3 +87,24 @@ impl ReplayArgs {
         try_parse_subcommand_arguments("replay", &into_ron_args(command_args), partitioning)
     }
 }
+
+impl CheckArgs {
+    pub fn try_parse<P: Partitioning>(command_args: CommandArgs, partitioning: &P) -> Result<Self> {
+        // Parse and validate all command line arguments for a subcommand
+        try_parse_subcommand_arguments("check", &into_ron_args(command_args), partitioning)
+    }
+}
+
+impl CompareLogsArgs {
+    pub fn try_parse<P: Partitioning>(command_args: CommandArgs, partitioning: &P) -> Result<Self> {
+        // Parse and validate all command line arguments for a subcommand
+        try_parse_subcommand_arguments("compare-logs", &into_ron_args(command_args), partitioning)
+    }
+}
+
+impl SweepArgs {
+    pub fn try_parse<P: Partitioning>(command_args: CommandArgs, partitioning: &P) -> Result<Self> {
+        // Parse and validate all command line arguments for a subcommand
+        try_parse_subcommand_arguments("sweep", &into_ron_args(command_args), partitioning)
+    }
+}
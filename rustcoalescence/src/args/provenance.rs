@@ -0,0 +1,227 @@
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+
+use serde::{Serialize, Serializer};
+
+use necsim_core_bond::{ClosedUnitF64, Partition, PositiveUnitF64};
+use necsim_impls_std::event_log::metadata::{EventLogInput, EventLogMetadata};
+use necsim_plugins_core::import::AnyReporterPluginVec;
+
+use rustcoalescence_scenarios::{
+    islands::IslandsArguments, spatially_explicit::SpatiallyExplicitDecompositionMethod,
+    spatially_implicit::SpatiallyImplicitArguments, torus::TorusArguments,
+};
+
+use super::{
+    Algorithm, AlmostInfiniteDispersalRaw, AlmostInfiniteSampleRaw, CommonArgs, Limits,
+    MapLoadingMode, NonSpatialArgsRaw, ScenarioRaw,
+};
+
+pub(super) fn event_log_metadata(
+    partition: Partition,
+    common: &CommonArgs,
+    scenario: &ScenarioRaw,
+    reporters: &AnyReporterPluginVec,
+) -> Result<EventLogMetadata> {
+    let mut inputs = Vec::new();
+
+    let scenario = match scenario {
+        ScenarioRaw::SpatiallyExplicit(args) => {
+            for path in [&args.habitat_path, &args.dispersal_path]
+                .iter()
+                .copied()
+                .chain(args.sample_path.as_ref())
+            {
+                inputs.push(EventLogInput {
+                    path: path.clone(),
+                    sha256: sha256_file_checksum(path)?,
+                });
+            }
+
+            // The maps themselves are identified by their checksums
+            ResolvedScenario::SpatiallyExplicit {
+                habitat_map: &args.habitat_path,
+                dispersal_map: &args.dispersal_path,
+                sample_map: args.sample_path.as_deref(),
+                loading_mode: args.loading_mode,
+                decomposition: args.decomposition,
+            }
+        },
+        ScenarioRaw::NonSpatial(args) => ResolvedScenario::NonSpatial(args),
+        ScenarioRaw::SpatiallyImplicit(args) => ResolvedScenario::SpatiallyImplicit(args),
+        ScenarioRaw::AlmostInfinite(args) => {
+            for shape in args.sample_spec.iter().flatten() {
+                if let AlmostInfiniteSampleRaw::Mask { path, .. } = shape {
+                    inputs.push(EventLogInput {
+                        path: path.clone(),
+                        sha256: sha256_file_checksum(path)?,
+                    });
+                }
+            }
+
+            // The sample masks themselves are identified by their checksums
+            ResolvedScenario::AlmostInfinite {
+                radius: args.radius,
+                sample: args.sample_spec.as_deref(),
+                dispersal: &args.dispersal_spec,
+            }
+        },
+        ScenarioRaw::Islands(args) => ResolvedScenario::Islands(args),
+        ScenarioRaw::Torus(args) => ResolvedScenario::Torus(args),
+    };
+
+    // Isolated simulations record the isolated partition which they simulate
+    //  as their partition, such that the event logs of all isolated partitions
+    //  share the same configuration
+    let partition = isolated_partition(&common.algorithm).unwrap_or(partition);
+
+    let configuration = ron::ser::to_string_pretty(
+        &ResolvedConfiguration {
+            speciation_probability_per_generation: common.speciation_probability_per_generation,
+            sample_percentage: common.sample_percentage,
+            seed: common.seed,
+            limits: common.limits,
+            algorithm: NormalisedAlgorithm(&common.algorithm),
+            scenario,
+            // Reporter plugins are opaque and only describe themselves
+            reporters: format!("{:?}", reporters),
+        },
+        ron::ser::PrettyConfig::default(),
+    )?;
+
+    Ok(EventLogMetadata {
+        version: format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+        partition,
+        configuration,
+        inputs,
+    })
+}
+
+/// Returns the isolated partition which the `algorithm` simulates, if any
+fn isolated_partition(algorithm: &Algorithm) -> Option<Partition> {
+    match *algorithm {
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        Algorithm::Classical(_) | Algorithm::Gillespie(_) | Algorithm::SkippingGillespie(_) => None,
+        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+        Algorithm::Cuda(ref args) => {
+            use rustcoalescence_algorithms_cuda::arguments::ParallelismMode;
+
+            match &args.parallelism_mode {
+                ParallelismMode::IsolatedIndividuals(mode)
+                | ParallelismMode::IsolatedLandscape(mode) => Some(mode.partition),
+                ParallelismMode::Monolithic(_) => None,
+            }
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-independent")]
+        Algorithm::Independent(ref args) => {
+            use rustcoalescence_algorithms_independent::arguments::ParallelismMode;
+
+            match &args.parallelism_mode {
+                ParallelismMode::IsolatedIndividuals(mode)
+                | ParallelismMode::IsolatedLandscape(mode) => Some(mode.partition),
+                ParallelismMode::Monolithic(_)
+                | ParallelismMode::Individuals
+                | ParallelismMode::Landscape
+                | ParallelismMode::Probabilistic(_) => None,
+            }
+        },
+    }
+}
+
+/// Serialises a clone of the algorithm arguments in which the isolated
+///  partition, if any, is replaced by the first partition
+struct NormalisedAlgorithm<'a>(&'a Algorithm);
+
+impl<'a> Serialize for NormalisedAlgorithm<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+            Algorithm::Cuda(args) => {
+                use rustcoalescence_algorithms_cuda::arguments::ParallelismMode;
+                use serde::ser::Error;
+
+                let mut args = args.clone();
+
+                if let ParallelismMode::IsolatedIndividuals(ref mut mode)
+                | ParallelismMode::IsolatedLandscape(ref mut mode) = args.parallelism_mode
+                {
+                    mode.partition = Partition::try_new(0, mode.partition.partitions())
+                        .map_err(S::Error::custom)?;
+                }
+
+                Algorithm::Cuda(args).serialize(serializer)
+            },
+            #[cfg(feature = "rustcoalescence-algorithms-independent")]
+            Algorithm::Independent(args) => {
+                use rustcoalescence_algorithms_independent::arguments::ParallelismMode;
+                use serde::ser::Error;
+
+                let mut args = args.clone();
+
+                if let ParallelismMode::IsolatedIndividuals(ref mut mode)
+                | ParallelismMode::IsolatedLandscape(ref mut mode) = args.parallelism_mode
+                {
+                    mode.partition = Partition::try_new(0, mode.partition.partitions())
+                        .map_err(S::Error::custom)?;
+                }
+
+                Algorithm::Independent(args).serialize(serializer)
+            },
+            algorithm => algorithm.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ResolvedConfiguration<'a> {
+    speciation_probability_per_generation: PositiveUnitF64,
+    sample_percentage: ClosedUnitF64,
+    seed: u64,
+    limits: Limits,
+    algorithm: NormalisedAlgorithm<'a>,
+    scenario: ResolvedScenario<'a>,
+    reporters: String,
+}
+
+#[derive(Serialize)]
+enum ResolvedScenario<'a> {
+    SpatiallyExplicit {
+        habitat_map: &'a Path,
+        dispersal_map: &'a Path,
+        sample_map: Option<&'a Path>,
+        loading_mode: MapLoadingMode,
+        decomposition: SpatiallyExplicitDecompositionMethod,
+    },
+    NonSpatial(&'a NonSpatialArgsRaw),
+    SpatiallyImplicit(&'a SpatiallyImplicitArguments),
+    AlmostInfinite {
+        radius: Option<u32>,
+        sample: Option<&'a [AlmostInfiniteSampleRaw]>,
+        dispersal: &'a AlmostInfiniteDispersalRaw,
+    },
+    Islands(&'a IslandsArguments),
+    Torus(&'a TorusArguments),
+}
+
+fn sha256_file_checksum(path: &Path) -> Result<String> {
+    let mut file = BufReader::new(
+        File::open(path).with_context(|| format!("Failed to open the input file {:?}.", path))?,
+    );
+
+    let mut hasher = Sha256::new();
+
+    io::copy(&mut file, &mut hasher)
+        .with_context(|| format!("Failed to read the input file {:?}.", path))?;
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
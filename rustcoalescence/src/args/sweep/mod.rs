@@ -0,0 +1,557 @@
+use std::{
+    collections::HashSet,
+    fmt,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::Deserializer, Deserialize};
+use serde_state::DeserializeState;
+
+use necsim_core_bond::Partition;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SweepArgs {
+    pub base: PathBuf,
+    pub template: String,
+    pub parameters: Vec<String>,
+    pub combinations: Vec<Vec<String>>,
+    pub output: PathBuf,
+    pub jobs: NonZeroUsize,
+}
+
+impl<'de> DeserializeState<'de, Partition> for SweepArgs {
+    fn deserialize_state<D>(partition: &mut Partition, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if partition.partitions().get() > 1 {
+            return Err(serde::de::Error::custom(
+                "Parameter sweep mode is incompatible with external parallelisation",
+            ));
+        }
+
+        let raw = SweepArgsRaw::deserialize(deserializer)?;
+
+        let template = std::fs::read_to_string(&raw.base).map_err(|err| {
+            serde::de::Error::custom(format!(
+                "Failed to read the base configuration {:?}: {}",
+                raw.base, err
+            ))
+        })?;
+
+        let (parameters, combinations) =
+            raw.parameters.expand().map_err(serde::de::Error::custom)?;
+
+        let mut unique_parameters = HashSet::new();
+
+        for parameter in &parameters {
+            if !unique_parameters.insert(parameter.as_str()) {
+                return Err(serde::de::Error::custom(format!(
+                    "The sweep parameter ${} is specified more than once.",
+                    parameter
+                )));
+            }
+        }
+
+        // Every sweep job logs its events and results into its own directory
+        for field in top_level_fields(config_fields(&template)) {
+            if matches!(field, "log" | "event_log" | "results") {
+                return Err(serde::de::Error::custom(format!(
+                    "The base configuration must not specify the {} field, which is set to the \
+                     directory of each sweep job.",
+                    field
+                )));
+            }
+        }
+
+        let mut used_parameters = HashSet::new();
+
+        for placeholder in placeholders(&template) {
+            if !unique_parameters.contains(placeholder) {
+                return Err(serde::de::Error::custom(format!(
+                    "The base configuration uses ${}, which is not a sweep parameter.",
+                    placeholder
+                )));
+            }
+
+            used_parameters.insert(placeholder);
+        }
+
+        if let Some(unused) = parameters
+            .iter()
+            .find(|parameter| !used_parameters.contains(parameter.as_str()))
+        {
+            return Err(serde::de::Error::custom(format!(
+                "The sweep parameter ${} is not used in the base configuration.",
+                unused
+            )));
+        }
+
+        let jobs = match raw.jobs {
+            Some(jobs) => jobs,
+            // Fall back to running one job at a time, 1 is trivially non-zero
+            None => std::thread::available_concurrency()
+                .unwrap_or_else(|_| NonZeroUsize::new(1).unwrap()),
+        };
+
+        Ok(Self {
+            base: raw.base,
+            template,
+            parameters,
+            combinations,
+            output: raw.output,
+            jobs,
+        })
+    }
+}
+
+impl SweepArgs {
+    /// Substitutes the values of one parameter `combination` into the base
+    ///  configuration template
+    #[must_use]
+    pub fn substitute(&self, combination: &[String]) -> String {
+        let mut config = String::with_capacity(self.template.len());
+        let mut remainder = self.template.as_str();
+
+        while let Some((before, placeholder, after)) = next_placeholder(remainder) {
+            config.push_str(before);
+
+            if let Some(index) = self
+                .parameters
+                .iter()
+                .position(|parameter| parameter == placeholder)
+            {
+                config.push_str(&combination[index]);
+            } else {
+                config.push('$');
+                config.push_str(placeholder);
+            }
+
+            remainder = after;
+        }
+
+        config.push_str(remainder);
+
+        config
+    }
+}
+
+#[derive(Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+struct SweepArgsRaw {
+    base: PathBuf,
+    parameters: SweepParameters,
+    output: PathBuf,
+    #[serde(default)]
+    jobs: Option<NonZeroUsize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+enum SweepParameters {
+    Parameter(SweepParameter),
+    Product(Vec<SweepParameters>),
+    Zip(Vec<SweepParameters>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SweepParameter {
+    name: String,
+    values: SweepValues,
+}
+
+impl SweepParameters {
+    /// Expands the sweep specification into the parameter names and the
+    ///  formatted values of all of their combinations
+    fn expand(self) -> Result<(Vec<String>, Vec<Vec<String>>), String> {
+        match self {
+            Self::Parameter(SweepParameter { name, values }) => {
+                if name.is_empty() || !name.bytes().all(is_placeholder_byte) {
+                    return Err(format!(
+                        "The sweep parameter {:?} must be a non-empty alphanumeric identifier.",
+                        name
+                    ));
+                }
+
+                let values = values
+                    .expand()
+                    .map_err(|err| format!("The sweep parameter ${} {}", name, err))?;
+
+                Ok((
+                    vec![name],
+                    values.into_iter().map(|value| vec![value]).collect(),
+                ))
+            },
+            Self::Product(children) => {
+                if children.is_empty() {
+                    return Err(String::from("A sweep Product must not be empty."));
+                }
+
+                let mut parameters = Vec::new();
+                let mut combinations = vec![Vec::new()];
+
+                for child in children {
+                    let (child_parameters, child_combinations) = child.expand()?;
+
+                    parameters.extend(child_parameters);
+
+                    combinations = combinations
+                        .iter()
+                        .flat_map(|combination| {
+                            child_combinations.iter().map(move |child_combination| {
+                                let mut combination = combination.clone();
+                                combination.extend(child_combination.iter().cloned());
+                                combination
+                            })
+                        })
+                        .collect();
+                }
+
+                Ok((parameters, combinations))
+            },
+            Self::Zip(children) => {
+                let mut parameters = Vec::new();
+                let mut combinations: Option<Vec<Vec<String>>> = None;
+
+                for child in children {
+                    let (child_parameters, child_combinations) = child.expand()?;
+
+                    combinations = Some(match combinations {
+                        None => child_combinations,
+                        Some(combinations) if combinations.len() == child_combinations.len() => {
+                            combinations
+                                .into_iter()
+                                .zip(child_combinations)
+                                .map(|(mut combination, child_combination)| {
+                                    combination.extend(child_combination);
+                                    combination
+                                })
+                                .collect()
+                        },
+                        Some(combinations) => {
+                            return Err(format!(
+                                "A sweep Zip must combine parameters with the same number of \
+                                 values, but {:?} have {} values and {:?} have {} values.",
+                                parameters,
+                                combinations.len(),
+                                child_parameters,
+                                child_combinations.len()
+                            ))
+                        },
+                    });
+
+                    parameters.extend(child_parameters);
+                }
+
+                match combinations {
+                    Some(combinations) => Ok((parameters, combinations)),
+                    None => Err(String::from("A sweep Zip must not be empty.")),
+                }
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+enum SweepValues {
+    List(Vec<SweepValue>),
+    Range {
+        start: SweepNumber,
+        end: SweepNumber,
+        #[serde(default)]
+        step: Option<SweepNumber>,
+    },
+}
+
+impl SweepValues {
+    fn expand(self) -> Result<Vec<String>, String> {
+        let values: Vec<String> = match self {
+            Self::List(values) => values.iter().map(ToString::to_string).collect(),
+            Self::Range {
+                start: SweepNumber::Integer(start),
+                end: SweepNumber::Integer(end),
+                step,
+            } if matches!(step, None | Some(SweepNumber::Integer(_))) => {
+                let step = match step {
+                    Some(SweepNumber::Integer(step)) => step,
+                    _ => 1,
+                };
+
+                if step <= 0 {
+                    return Err(format!("must have a positive Range step, not {}.", step));
+                }
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                (start..end)
+                    .step_by(step as usize)
+                    .map(|value| value.to_string())
+                    .collect()
+            },
+            Self::Range { start, end, step } => {
+                let (start, end) = (start.get(), end.get());
+                let step = step.map_or(1.0_f64, SweepNumber::get);
+
+                if step.is_nan() || step <= 0.0_f64 || !start.is_finite() || !end.is_finite() {
+                    return Err(format!(
+                        "must have a finite Range with a positive step, not {:?}..{:?} by {:?}.",
+                        start, end, step
+                    ));
+                }
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let length = ((end - start) / step).ceil().max(0.0_f64) as u64;
+
+                // Rounding errors are hidden by only printing as many decimals
+                //  as the start and step were specified with
+                let decimals = decimals(start).zip(decimals(step)).map(|(a, b)| a.max(b));
+
+                #[allow(clippy::cast_precision_loss)]
+                (0..length)
+                    .map(|index| {
+                        let value = start + (index as f64) * step;
+
+                        decimals.map_or_else(
+                            || format!("{:?}", value),
+                            |decimals| format!("{:.*}", decimals.max(1), value),
+                        )
+                    })
+                    .collect()
+            },
+        };
+
+        if values.is_empty() {
+            return Err(String::from("must have at least one value."));
+        }
+
+        Ok(values)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SweepValue {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl fmt::Display for SweepValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(fmt, "{}", value),
+            Self::Integer(value) => write!(fmt, "{}", value),
+            Self::Float(value) => write!(fmt, "{:?}", value),
+            Self::String(value) => fmt.write_str(&ron::to_string(value).map_err(|_| fmt::Error)?),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(untagged)]
+enum SweepNumber {
+    Integer(i64),
+    Float(f64),
+}
+
+impl SweepNumber {
+    #[allow(clippy::cast_precision_loss)]
+    fn get(self) -> f64 {
+        match self {
+            Self::Integer(value) => value as f64,
+            Self::Float(value) => value,
+        }
+    }
+}
+
+/// Counts the decimals of the shortest representation of the `value`,
+///  unless it requires an exponent
+fn decimals(value: f64) -> Option<usize> {
+    let representation = format!("{:?}", value);
+
+    if representation.contains(&['e', 'E'][..]) {
+        return None;
+    }
+
+    Some(
+        representation
+            .split_once('.')
+            .map_or(0, |(_, decimals)| decimals.len()),
+    )
+}
+
+const fn is_placeholder_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// Splits the `template` around its next `$name` placeholder
+fn next_placeholder(template: &str) -> Option<(&str, &str, &str)> {
+    let mut offset = 0;
+
+    while let Some(dollar) = template[offset..].find('$') {
+        let start = offset + dollar + 1;
+        let length = template[start..]
+            .bytes()
+            .take_while(|byte| is_placeholder_byte(*byte))
+            .count();
+
+        if length > 0 {
+            return Some((
+                &template[..(start - 1)],
+                &template[start..(start + length)],
+                &template[(start + length)..],
+            ));
+        }
+
+        offset = start;
+    }
+
+    None
+}
+
+fn placeholders(template: &str) -> impl Iterator<Item = &str> {
+    let mut remainder = template;
+
+    std::iter::from_fn(move || {
+        let (_, placeholder, after) = next_placeholder(remainder)?;
+
+        remainder = after;
+
+        Some(placeholder)
+    })
+}
+
+/// Turns the substituted base configuration into a self-contained job
+///  configuration which logs its events and results into the `directory`
+pub(crate) fn job_config(config: &str, directory: &Path) -> anyhow::Result<String> {
+    let fields = config_fields(config);
+
+    let separator = match ron_code_chars(fields).last() {
+        None | Some((_, ',')) => "",
+        Some(_) => ", ",
+    };
+
+    // The new fields are placed on new lines in case the base configuration
+    //  ends with a line comment
+    Ok(format!(
+        "({}\n    {}log: {},\n    results: {},\n)\n",
+        fields,
+        separator,
+        ron::to_string(&directory.join("events"))?,
+        ron::to_string(&directory.join("results.json"))?
+    ))
+}
+
+/// Returns the fields of a RON struct `config`, which are either enclosed in
+///  parentheses or given bare
+fn config_fields(config: &str) -> &str {
+    let code = ron_code_chars(config).collect::<Vec<_>>();
+
+    match (code.first(), code.last()) {
+        (Some((open, '(')), Some((close, ')')))
+            if matching_parenthesis(&code) == Some(code.len() - 1) =>
+        {
+            &config[(open + 1)..*close]
+        },
+        _ => config,
+    }
+}
+
+/// Iterates over the names of the top-level `fields` of a RON struct
+fn top_level_fields(fields: &str) -> impl Iterator<Item = &str> {
+    let mut code = ron_code_chars(fields);
+    let mut depth = 0_usize;
+    let mut expects_field = true;
+
+    std::iter::from_fn(move || loop {
+        let (index, c) = code.next()?;
+
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => expects_field = true,
+            c if depth == 0 && expects_field && c.is_ascii() && is_placeholder_byte(c as u8) => {
+                expects_field = false;
+
+                let length = fields[index..]
+                    .bytes()
+                    .take_while(|byte| is_placeholder_byte(*byte))
+                    .count();
+
+                return Some(&fields[index..(index + length)]);
+            },
+            _ => expects_field = false,
+        }
+    })
+}
+
+/// Finds the index of the code character which closes the opening
+///  parenthesis at the start of the `code`
+fn matching_parenthesis(code: &[(usize, char)]) -> Option<usize> {
+    let mut depth = 0_usize;
+
+    for (index, (_, c)) in code.iter().enumerate() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth = depth.checked_sub(1)?;
+
+                if depth == 0 {
+                    return Some(index);
+                }
+            },
+            _ => (),
+        }
+    }
+
+    None
+}
+
+/// Iterates over the non-whitespace characters of a RON `text` which are
+///  neither inside comments nor inside string literals, which are instead
+///  represented by their closing quote
+fn ron_code_chars(text: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    let mut chars = text.char_indices().peekable();
+
+    std::iter::from_fn(move || loop {
+        let (index, c) = chars.next()?;
+
+        match c {
+            '/' if chars.next_if(|(_, next)| *next == '/').is_some() => {
+                while chars.next_if(|(_, next)| *next != '\n').is_some() {}
+            },
+            '/' if chars.next_if(|(_, next)| *next == '*').is_some() => {
+                let mut previous = ' ';
+
+                for (_, next) in &mut chars {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+
+                    previous = next;
+                }
+            },
+            '"' => {
+                while let Some((index, next)) = chars.next() {
+                    match next {
+                        '\\' => {
+                            chars.next();
+                        },
+                        '"' => return Some((index, '"')),
+                        _ => (),
+                    }
+                }
+            },
+            c if c.is_whitespace() => (),
+            c => return Some((index, c)),
+        }
+    })
+}
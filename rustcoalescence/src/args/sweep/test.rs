@@ -0,0 +1,78 @@
+use std::path::Path;
+
+use super::{job_config, ron_code_chars, top_level_fields};
+
+fn code(text: &str) -> String {
+    ron_code_chars(text).map(|(_, c)| c).collect()
+}
+
+#[test]
+fn test_ron_code_chars_skip_comments_and_whitespace() {
+    assert_eq!(
+        code("( // line comment )\n  seed: /* block ) comment */ 42 , )"),
+        "(seed:42,)"
+    );
+    assert_eq!(code("/* a ** b */ x"), "x");
+}
+
+#[test]
+fn test_ron_code_chars_collapse_strings() {
+    assert_eq!(code(r#"(path: "a) b, // c", x: 1)"#), r#"(path:",x:1)"#);
+    assert_eq!(code(r#"(path: "a \" ) b")"#), r#"(path:")"#);
+}
+
+#[test]
+fn test_top_level_fields() {
+    let fields = top_level_fields(
+        r#"seed: 42, /* log: "x" */ scenario: NonSpatial(area: (1, 1), deme: 1),
+        reporters: [Plugin(library: "a, log: b", reporters: [])], // results: c"#,
+    )
+    .collect::<Vec<_>>();
+
+    assert_eq!(fields, vec!["seed", "scenario", "reporters"]);
+}
+
+#[test]
+fn test_job_config_with_parentheses() {
+    let config = job_config(
+        "(\n    speciation: 0.1, // comment )\n    seed: 42,\n)\n",
+        Path::new("/sweep/0"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        config,
+        "(\n    speciation: 0.1, // comment )\n    seed: 42,\n\n    log: \"/sweep/0/events\",\n    \
+         results: \"/sweep/0/results.json\",\n)\n"
+    );
+    assert_eq!(
+        top_level_fields(&config[1..(config.len() - 2)]).collect::<Vec<_>>(),
+        vec!["speciation", "seed", "log", "results"]
+    );
+}
+
+#[test]
+fn test_job_config_without_trailing_comma() {
+    let config = job_config(
+        r#"(seed: 42, reporters: [Plugin(library: "lib)", reporters: [])]) // end"#,
+        Path::new("/sweep/1"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        config,
+        "(seed: 42, reporters: [Plugin(library: \"lib)\", reporters: [])]\n    , log: \
+         \"/sweep/1/events\",\n    results: \"/sweep/1/results.json\",\n)\n"
+    );
+}
+
+#[test]
+fn test_job_config_bare_fields() {
+    let config = job_config("seed: 42 // no parentheses", Path::new("/sweep/2")).unwrap();
+
+    assert_eq!(
+        config,
+        "(seed: 42 // no parentheses\n    , log: \"/sweep/2/events\",\n    results: \
+         \"/sweep/2/results.json\",\n)\n"
+    );
+}
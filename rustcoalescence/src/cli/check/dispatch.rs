@@ -0,0 +1,552 @@
+use std::{fs, mem::size_of, path::Path};
+
+use anyhow::{Context, Result};
+use array2d::Array2D;
+
+use necsim_core::{
+    cogs::{Habitat, OriginSampler},
+    lineage::Lineage,
+};
+use necsim_core_bond::{Partition, PositiveUnitF64};
+use necsim_impls_no_std::cogs::{
+    origin_sampler::pre_sampler::OriginPreSampler, rng::wyhash::WyHash,
+};
+use necsim_partitioning_core::Partitioning;
+
+use rustcoalescence_scenarios::{
+    almost_infinite::AlmostInfiniteScenario, islands::IslandsScenario,
+    non_spatial::NonSpatialScenario, spatially_explicit::SpatiallyExplicitScenario,
+    spatially_implicit::SpatiallyImplicitScenario, torus::TorusScenario, Scenario,
+};
+
+use crate::args::{Algorithm as AlgorithmArgs, CheckArgs, Scenario as ScenarioArgs};
+
+#[derive(Clone, Copy, Debug)]
+enum DispersalSamplerKind {
+    #[cfg(any(
+        feature = "rustcoalescence-algorithms-monolithic",
+        feature = "rustcoalescence-algorithms-independent"
+    ))]
+    Alias,
+    #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+    SeparableAlias,
+    #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+    PackedAlias,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum LineageStoreKind {
+    #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+    Classical,
+    #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+    Gillespie,
+    #[cfg(any(
+        feature = "rustcoalescence-algorithms-independent",
+        feature = "rustcoalescence-algorithms-cuda"
+    ))]
+    Independent,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn check_with_logger<P: Partitioning>(check_args: CheckArgs, partitioning: P) -> Result<()> {
+    let partition = Partition::try_new(
+        partitioning.get_rank(),
+        partitioning.get_number_of_partitions(),
+    )
+    .map_err(anyhow::Error::msg)?;
+
+    let (algorithm, dispersal_sampler, lineage_store) =
+        algorithm_resources(&check_args.common.algorithm);
+
+    if partition.partitions().get() <= 1 {
+        info!(
+            "The simulation will be run with the {} algorithm in monolithic mode.",
+            algorithm
+        );
+    } else {
+        info!(
+            "The simulation will be run with the {} algorithm distributed across {} partitions.",
+            algorithm,
+            partition.partitions().get()
+        );
+    }
+
+    let valid = check_event_log(check_args.event_log.as_deref(), partition);
+    check_parallelism_mode(
+        &check_args.common.algorithm,
+        check_args.event_log.as_deref(),
+    );
+
+    let pre_sampler = OriginPreSampler::all().percentage(check_args.common.sample_percentage.get());
+    let speciation_probability_per_generation =
+        check_args.common.speciation_probability_per_generation;
+
+    // Initialising the scenario validates it without building the simulation
+    check_scenario_args(
+        check_args.scenario,
+        pre_sampler,
+        speciation_probability_per_generation,
+        (dispersal_sampler, lineage_store),
+    )?;
+
+    if valid {
+        info!("The simulation configuration is valid.");
+
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "The simulation configuration is invalid, see the errors above."
+        ))
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn check_scenario_args(
+    scenario: ScenarioArgs,
+    pre_sampler: OriginPreSampler<impl Iterator<Item = u64>>,
+    speciation_probability_per_generation: PositiveUnitF64,
+    (dispersal_sampler, lineage_store): (DispersalSamplerKind, LineageStoreKind),
+) -> Result<()> {
+    match scenario {
+        ScenarioArgs::SpatiallyExplicit(scenario_args) => {
+            let dispersal_map_bytes = scenario_args.dispersal_map.num_elements() * size_of::<f64>();
+            let dispersal_sampler_bytes = estimate_dispersal_sampler_bytes(
+                &scenario_args.habitat_map,
+                &scenario_args.dispersal_map,
+                dispersal_sampler,
+            );
+
+            let scenario = SpatiallyExplicitScenario::<WyHash>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .context("Failed to initialise the spatially explicit scenario.")?;
+
+            check_scenario(&scenario, pre_sampler, lineage_store);
+
+            info!(
+                "The dispersal map occupies {}, the {:?} dispersal sampler will require about {}.",
+                format_bytes(dispersal_map_bytes),
+                dispersal_sampler,
+                format_bytes(dispersal_sampler_bytes),
+            );
+        },
+        ScenarioArgs::NonSpatial(scenario_args) => check_scenario(
+            &NonSpatialScenario::<WyHash>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .into_ok(),
+            pre_sampler,
+            lineage_store,
+        ),
+        ScenarioArgs::AlmostInfiniteNormal(scenario_args) => check_scenario(
+            &AlmostInfiniteScenario::<WyHash, _>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .context("Failed to initialise the almost infinite scenario.")?,
+            pre_sampler,
+            lineage_store,
+        ),
+        ScenarioArgs::AlmostInfiniteClark2Dt(scenario_args) => check_scenario(
+            &AlmostInfiniteScenario::<WyHash, _>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .context("Failed to initialise the almost infinite scenario.")?,
+            pre_sampler,
+            lineage_store,
+        ),
+        ScenarioArgs::AlmostInfiniteCauchy(scenario_args) => check_scenario(
+            &AlmostInfiniteScenario::<WyHash, _>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .context("Failed to initialise the almost infinite scenario.")?,
+            pre_sampler,
+            lineage_store,
+        ),
+        ScenarioArgs::AlmostInfiniteExpPower(scenario_args) => check_scenario(
+            &AlmostInfiniteScenario::<WyHash, _>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .context("Failed to initialise the almost infinite scenario.")?,
+            pre_sampler,
+            lineage_store,
+        ),
+        ScenarioArgs::AlmostInfiniteLaplace(scenario_args) => check_scenario(
+            &AlmostInfiniteScenario::<WyHash, _>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .context("Failed to initialise the almost infinite scenario.")?,
+            pre_sampler,
+            lineage_store,
+        ),
+        ScenarioArgs::SpatiallyImplicit(scenario_args) => check_scenario(
+            &SpatiallyImplicitScenario::<WyHash>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .into_ok(),
+            pre_sampler,
+            lineage_store,
+        ),
+        ScenarioArgs::Islands(scenario_args) => check_scenario(
+            &IslandsScenario::<WyHash>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .context("Failed to initialise the islands scenario.")?,
+            pre_sampler,
+            lineage_store,
+        ),
+        ScenarioArgs::Torus(scenario_args) => check_scenario(
+            &TorusScenario::<WyHash>::initialise(
+                scenario_args,
+                speciation_probability_per_generation,
+            )
+            .into_ok(),
+            pre_sampler,
+            lineage_store,
+        ),
+    }
+
+    Ok(())
+}
+
+fn check_scenario<S: Scenario<WyHash>>(
+    scenario: &S,
+    pre_sampler: OriginPreSampler<impl Iterator<Item = u64>>,
+    lineage_store: LineageStoreKind,
+) {
+    let habitat = scenario.habitat();
+
+    let total_habitat = habitat.get_total_habitat();
+    let individuals = scenario
+        .sample_habitat(pre_sampler)
+        .full_upper_bound_size_hint();
+
+    info!(
+        "The habitat has an extent of {}x{} and a total size of {}.",
+        habitat.get_extent().width(),
+        habitat.get_extent().height(),
+        total_habitat,
+    );
+    info!(
+        "The simulation will sample up to {} individuals.",
+        individuals
+    );
+
+    if individuals == 0 {
+        warn!("The simulation will not sample any individuals.");
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let lineage_store_bytes = estimate_lineage_store_bytes(
+        individuals as usize,
+        (habitat.get_extent().width() as usize) * (habitat.get_extent().height() as usize),
+        lineage_store,
+    );
+
+    info!(
+        "The {:?} lineage store will require about {}.",
+        lineage_store,
+        format_bytes(lineage_store_bytes)
+    );
+}
+
+fn check_event_log(event_log: Option<&Path>, partition: Partition) -> bool {
+    let event_log = match event_log {
+        Some(event_log) => event_log.join(partition.rank().to_string()),
+        None if partition.partitions().get() > 1 => {
+            error!(
+                "The simulation is distributed across {} partitions and therefore requires an \
+                 event log.",
+                partition.partitions().get()
+            );
+
+            return false;
+        },
+        None => return true,
+    };
+
+    let metadata = if let Ok(metadata) = fs::metadata(&event_log) {
+        metadata
+    } else {
+        info!(
+            "The simulation will create the event log directory {:?}.",
+            event_log
+        );
+
+        return true;
+    };
+
+    if !metadata.is_dir() {
+        error!("The event log {:?} is not a directory.", event_log);
+
+        return false;
+    }
+
+    if metadata.permissions().readonly() {
+        error!("The event log directory {:?} is read-only.", event_log);
+
+        return false;
+    }
+
+    if fs::read_dir(&event_log).map_or(false, |mut entries| entries.next().is_some()) {
+        warn!(
+            "The event log directory {:?} is not empty, its segments may be overwritten.",
+            event_log
+        );
+    } else {
+        info!("The simulation will log its events to {:?}.", event_log);
+    }
+
+    true
+}
+
+fn algorithm_resources(
+    algorithm: &AlgorithmArgs,
+) -> (&'static str, DispersalSamplerKind, LineageStoreKind) {
+    match *algorithm {
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmArgs::Classical(_) => (
+            "Classical",
+            DispersalSamplerKind::Alias,
+            LineageStoreKind::Classical,
+        ),
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmArgs::Gillespie(_) => (
+            "Gillespie",
+            DispersalSamplerKind::Alias,
+            LineageStoreKind::Gillespie,
+        ),
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmArgs::SkippingGillespie(_) => (
+            "Skipping-Gillespie",
+            DispersalSamplerKind::SeparableAlias,
+            LineageStoreKind::Gillespie,
+        ),
+        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+        AlgorithmArgs::Cuda(_) => (
+            "CUDA",
+            DispersalSamplerKind::PackedAlias,
+            LineageStoreKind::Independent,
+        ),
+        #[cfg(feature = "rustcoalescence-algorithms-independent")]
+        AlgorithmArgs::Independent(_) => (
+            "Independent",
+            DispersalSamplerKind::Alias,
+            LineageStoreKind::Independent,
+        ),
+    }
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "rustcoalescence-algorithms-independent",
+        feature = "rustcoalescence-algorithms-cuda"
+    )),
+    allow(unused_variables)
+)]
+fn check_parallelism_mode(algorithm: &AlgorithmArgs, event_log: Option<&Path>) {
+    match *algorithm {
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmArgs::Classical(ref args)
+        | AlgorithmArgs::Gillespie(ref args)
+        | AlgorithmArgs::SkippingGillespie(ref args) => {
+            use rustcoalescence_algorithms_monolithic::arguments::ParallelismMode;
+
+            if let ParallelismMode::Averaging(_) = args.parallelism_mode {
+                warn!(
+                    "The Averaging parallelism mode only approximates the results of a monolithic \
+                     simulation."
+                );
+            }
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+        AlgorithmArgs::Cuda(ref args) => {
+            use rustcoalescence_algorithms_cuda::arguments::ParallelismMode;
+
+            match args.parallelism_mode {
+                ParallelismMode::IsolatedIndividuals(ref mode)
+                | ParallelismMode::IsolatedLandscape(ref mode) => {
+                    check_isolated_partition(mode.partition, event_log);
+                },
+                ParallelismMode::Monolithic(_) => (),
+            }
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-independent")]
+        AlgorithmArgs::Independent(ref args) => {
+            use rustcoalescence_algorithms_independent::arguments::ParallelismMode;
+
+            match args.parallelism_mode {
+                ParallelismMode::IsolatedIndividuals(ref mode)
+                | ParallelismMode::IsolatedLandscape(ref mode) => {
+                    check_isolated_partition(mode.partition, event_log);
+                },
+                ParallelismMode::Probabilistic(_) => warn!(
+                    "The Probabilistic parallelism mode only approximates the results of a \
+                     monolithic simulation."
+                ),
+                ParallelismMode::Monolithic(_)
+                | ParallelismMode::Individuals
+                | ParallelismMode::Landscape => (),
+            }
+        },
+    }
+}
+
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+fn check_isolated_partition(partition: Partition, event_log: Option<&Path>) {
+    if event_log.is_none() {
+        warn!(
+            "The simulation only simulates the isolated partition {}/{} without recording an \
+             event log, which is required to later replay all partitions together.",
+            partition.rank(),
+            partition.partitions().get(),
+        );
+    }
+}
+
+fn estimate_dispersal_sampler_bytes(
+    habitat: &Array2D<u32>,
+    dispersal: &Array2D<f64>,
+    dispersal_sampler: DispersalSamplerKind,
+) -> usize {
+    let locations = habitat.num_elements();
+
+    match dispersal_sampler {
+        #[cfg(any(
+            feature = "rustcoalescence-algorithms-monolithic",
+            feature = "rustcoalescence-algorithms-independent"
+        ))]
+        DispersalSamplerKind::Alias => {
+            use necsim_impls_no_std::alias::AliasMethodSampler;
+
+            locations * size_of::<Option<AliasMethodSampler<usize>>>()
+                + count_non_zero_targets(habitat, dispersal, true)
+                    * (size_of::<f64>() + size_of::<usize>() * 2)
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        DispersalSamplerKind::SeparableAlias => {
+            use necsim_core_bond::ClosedUnitF64;
+            use necsim_impls_no_std::alias::AliasMethodSampler;
+
+            // Self-dispersal is stored separately from the alias samplers
+            locations
+                * (size_of::<Option<AliasMethodSampler<usize>>>() + size_of::<ClosedUnitF64>())
+                + count_non_zero_targets(habitat, dispersal, false)
+                    * (size_of::<f64>() + size_of::<usize>() * 2)
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+        DispersalSamplerKind::PackedAlias => {
+            use necsim_impls_no_std::alias::packed::AliasMethodSamplerAtom;
+            use std::ops::Range;
+
+            locations * size_of::<Range<usize>>()
+                + count_non_zero_targets(habitat, dispersal, true)
+                    * size_of::<AliasMethodSamplerAtom<usize>>()
+        },
+    }
+}
+
+/// Counts the dispersal targets with a non-zero probability, which are stored
+///  in the dispersal samplers, optionally `with_self_dispersal`
+fn count_non_zero_targets(
+    habitat: &Array2D<u32>,
+    dispersal: &Array2D<f64>,
+    with_self_dispersal: bool,
+) -> usize {
+    let mut non_zero_targets = 0_usize;
+
+    // Only dispersal to habitable target locations is stored in the samplers
+    for (row_index, row) in dispersal.rows_iter().enumerate() {
+        for ((col_index, dispersal_probability), target_habitat) in
+            row.enumerate().zip(habitat.elements_row_major_iter())
+        {
+            if (*dispersal_probability * f64::from(*target_habitat) > 0.0_f64)
+                && (with_self_dispersal || row_index != col_index)
+            {
+                non_zero_targets += 1;
+            }
+        }
+    }
+
+    non_zero_targets
+}
+
+#[cfg_attr(
+    not(feature = "rustcoalescence-algorithms-monolithic"),
+    allow(unused_variables)
+)]
+fn estimate_lineage_store_bytes(
+    individuals: usize,
+    locations: usize,
+    lineage_store: LineageStoreKind,
+) -> usize {
+    match lineage_store {
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        LineageStoreKind::Classical => {
+            use necsim_core::landscape::IndexedLocation;
+            use necsim_impls_no_std::cogs::lineage_reference::in_memory::InMemoryLineageReference;
+
+            estimate_slab_bytes(individuals)
+                + estimate_hash_map_bytes(
+                    individuals,
+                    size_of::<(IndexedLocation, InMemoryLineageReference)>(),
+                )
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        LineageStoreKind::Gillespie => {
+            use necsim_core::{landscape::IndexedLocation, lineage::GlobalLineageReference};
+            use necsim_impls_no_std::cogs::lineage_reference::in_memory::InMemoryLineageReference;
+
+            estimate_slab_bytes(individuals)
+                + locations * size_of::<Vec<InMemoryLineageReference>>()
+                + individuals * size_of::<InMemoryLineageReference>()
+                + estimate_hash_map_bytes(
+                    individuals,
+                    size_of::<(IndexedLocation, (GlobalLineageReference, usize))>(),
+                )
+        },
+        #[cfg(any(
+            feature = "rustcoalescence-algorithms-independent",
+            feature = "rustcoalescence-algorithms-cuda"
+        ))]
+        LineageStoreKind::Independent => individuals * size_of::<Lineage>(),
+    }
+}
+
+#[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+fn estimate_slab_bytes(individuals: usize) -> usize {
+    // Slab entries additionally store their vacancy tag
+    individuals * (size_of::<Lineage>() + size_of::<usize>())
+}
+
+#[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+fn estimate_hash_map_bytes(individuals: usize, entry_bytes: usize) -> usize {
+    // Hash maps store one control byte per bucket and are at most 7/8 full
+    (individuals * 8 / 7) * (entry_bytes + 1)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+
+    while size >= 1024.0_f64 && unit < (UNITS.len() - 1) {
+        size /= 1024.0_f64;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", size, UNITS[unit])
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+use log::LevelFilter;
+
+use necsim_partitioning_core::Partitioning;
+
+use crate::args::{CheckArgs, CommandArgs};
+
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+mod dispatch;
+
+#[allow(clippy::module_name_repetitions, clippy::needless_pass_by_value)]
+pub fn check_with_logger<P: Partitioning>(check_args: CommandArgs, partitioning: P) -> Result<()> {
+    // Only log to stdout/stderr if the partition is the root partition
+    log::set_max_level(if partitioning.is_root() {
+        LevelFilter::Info
+    } else {
+        LevelFilter::Off
+    });
+
+    let check_args = CheckArgs::try_parse(check_args, &partitioning)?;
+    info!("Parsed simulation arguments:\n{:#?}", check_args);
+
+    #[cfg(any(
+        feature = "rustcoalescence-algorithms-monolithic",
+        feature = "rustcoalescence-algorithms-independent",
+        feature = "rustcoalescence-algorithms-cuda"
+    ))]
+    {
+        dispatch::check_with_logger(check_args, partitioning)
+    }
+
+    #[cfg(not(any(
+        feature = "rustcoalescence-algorithms-monolithic",
+        feature = "rustcoalescence-algorithms-independent",
+        feature = "rustcoalescence-algorithms-cuda"
+    )))]
+    {
+        std::mem::drop(check_args);
+        std::mem::drop(partitioning);
+
+        Err(anyhow::anyhow!(
+            "rustcoalescence must be compiled to support at least one algorithm."
+        ))
+    }
+}
@@ -0,0 +1,280 @@
+use std::{collections::VecDeque, iter::Peekable};
+
+use anyhow::Result;
+use log::LevelFilter;
+
+use necsim_core::event::{Dispersal, EventType, LineageInteraction, PackedEvent};
+use necsim_impls_std::event_log::replay::EventLogReplay;
+
+use necsim_partitioning_core::Partitioning;
+
+use crate::args::{CommandArgs, CompareLogsArgs, EventTimeTolerance};
+
+#[allow(clippy::module_name_repetitions, clippy::needless_pass_by_value)]
+pub fn compare_logs_with_logger<P: Partitioning>(
+    compare_args: CommandArgs,
+    partitioning: P,
+) -> Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    let compare_args = CompareLogsArgs::try_parse(compare_args, &partitioning)?;
+    info!("Parsed compare-logs arguments:\n{:#?}", compare_args);
+
+    super::replay::report_event_log_metadata(compare_args.left.metadata());
+    super::replay::report_event_log_metadata(compare_args.right.metadata());
+
+    if compare_args.left.with_speciation() != compare_args.right.with_speciation() {
+        warn!("Only one of the event logs contains speciation events.");
+    }
+
+    if compare_args.left.with_dispersal() != compare_args.right.with_dispersal() {
+        warn!("Only one of the event logs contains dispersal events.");
+    }
+
+    info!(
+        "Starting to compare the event logs with {} and {} events ...",
+        compare_args.left.length(),
+        compare_args.right.length()
+    );
+
+    let epsilon = match compare_args.tolerance {
+        EventTimeTolerance::Exact => 0.0_f64,
+        EventTimeTolerance::Absolute(epsilon) => epsilon.get(),
+    };
+
+    let mut comparison = EventLogComparison::new(compare_args.left, compare_args.right, epsilon);
+
+    let mut preceding: VecDeque<PackedEvent> = VecDeque::with_capacity(compare_args.context);
+    let mut equivalent = 0_u64;
+    let mut summary = DifferenceSummary::default();
+
+    while let Some(outcome) = comparison.next() {
+        if let EventComparison::Equivalent(event) = outcome {
+            equivalent += 1;
+
+            if preceding.len() >= compare_args.context {
+                preceding.pop_front();
+            }
+
+            if compare_args.context > 0 {
+                preceding.push_back(event);
+            }
+
+            continue;
+        }
+
+        if summary.total() == 0 {
+            equivalent += report_first_divergence(
+                &preceding,
+                &outcome,
+                &mut comparison,
+                &mut summary,
+                compare_args.context,
+            );
+        }
+
+        summary.record(&outcome);
+    }
+
+    info!("{} events are equivalent in both event logs.", equivalent);
+
+    if summary.total() == 0 {
+        info!("The event logs are equivalent.");
+
+        return Ok(());
+    }
+
+    info!("The event logs differ in the following events:");
+
+    for (event_type, (only_left, only_right)) in &[
+        ("speciation", summary.speciation),
+        ("dispersal", summary.dispersal),
+        ("coalescence", summary.coalescence),
+    ] {
+        info!(
+            "- {} {} event(s), {} only in the left and {} only in the right event log",
+            only_left + only_right,
+            event_type,
+            only_left,
+            only_right,
+        );
+    }
+
+    Err(anyhow::anyhow!(
+        "The event logs differ in {} event(s).",
+        summary.total()
+    ))
+}
+
+fn report_first_divergence(
+    preceding: &VecDeque<PackedEvent>,
+    divergence: &EventComparison,
+    comparison: &mut EventLogComparison,
+    summary: &mut DifferenceSummary,
+    context: usize,
+) -> u64 {
+    let mut equivalent = 0_u64;
+    let mut report = String::new();
+
+    for event in preceding {
+        report.push_str(&format!(
+            "    {:?}\n",
+            EventComparison::Equivalent(event.clone())
+        ));
+    }
+
+    report.push_str(&format!(">>> {:?}\n", divergence));
+
+    // The following outcomes are only consumed to provide context
+    for outcome in comparison.take(context) {
+        report.push_str(&format!("    {:?}\n", outcome));
+
+        if let EventComparison::Equivalent(_) = outcome {
+            equivalent += 1;
+        }
+
+        summary.record(&outcome);
+    }
+
+    error!(
+        "The event logs first diverge at time {}:\n{}",
+        divergence.event().event_time.get(),
+        report
+    );
+
+    equivalent
+}
+
+#[derive(Debug)]
+enum EventComparison {
+    Equivalent(PackedEvent),
+    OnlyLeft(PackedEvent),
+    OnlyRight(PackedEvent),
+}
+
+impl EventComparison {
+    fn event(&self) -> &PackedEvent {
+        match self {
+            Self::Equivalent(event) | Self::OnlyLeft(event) | Self::OnlyRight(event) => event,
+        }
+    }
+}
+
+#[derive(Default)]
+struct DifferenceSummary {
+    speciation: (u64, u64),
+    dispersal: (u64, u64),
+    coalescence: (u64, u64),
+}
+
+impl DifferenceSummary {
+    fn record(&mut self, outcome: &EventComparison) {
+        let counter = match &outcome.event().r#type {
+            EventType::Speciation => &mut self.speciation,
+            EventType::Dispersal(Dispersal {
+                interaction: LineageInteraction::Coalescence(_),
+                ..
+            }) => &mut self.coalescence,
+            EventType::Dispersal(_) => &mut self.dispersal,
+        };
+
+        match outcome {
+            EventComparison::Equivalent(_) => (),
+            EventComparison::OnlyLeft(_) => counter.0 += 1,
+            EventComparison::OnlyRight(_) => counter.1 += 1,
+        }
+    }
+
+    fn total(&self) -> u64 {
+        self.speciation.0
+            + self.speciation.1
+            + self.dispersal.0
+            + self.dispersal.1
+            + self.coalescence.0
+            + self.coalescence.1
+    }
+}
+
+/// Merges two sorted event logs and matches up equivalent events, whose
+///  event times may differ by up to `epsilon`
+struct EventLogComparison {
+    left: Peekable<EventLogReplay>,
+    right: Peekable<EventLogReplay>,
+    left_window: VecDeque<PackedEvent>,
+    right_window: VecDeque<PackedEvent>,
+    epsilon: f64,
+}
+
+impl EventLogComparison {
+    fn new(left: EventLogReplay, right: EventLogReplay, epsilon: f64) -> Self {
+        Self {
+            left: left.peekable(),
+            right: right.peekable(),
+            left_window: VecDeque::new(),
+            right_window: VecDeque::new(),
+            epsilon,
+        }
+    }
+}
+
+impl Iterator for EventLogComparison {
+    type Item = EventComparison;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left_window.is_empty() {
+            self.left_window.extend(self.left.next());
+        }
+
+        if self.right_window.is_empty() {
+            self.right_window.extend(self.right.next());
+        }
+
+        let left_first = match (self.left_window.front(), self.right_window.front()) {
+            (None, None) => return None,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (Some(left), Some(right)) => left.event_time <= right.event_time,
+        };
+
+        let (window, other_window, other) = if left_first {
+            (
+                &mut self.left_window,
+                &mut self.right_window,
+                &mut self.right,
+            )
+        } else {
+            (
+                &mut self.right_window,
+                &mut self.left_window,
+                &mut self.left,
+            )
+        };
+
+        let event = window.pop_front()?;
+
+        // All equivalent candidates must be within the tolerance window
+        let horizon = event.event_time.get() + self.epsilon;
+
+        while let Some(candidate) = other.next_if(|next| next.event_time.get() <= horizon) {
+            other_window.push_back(candidate);
+        }
+
+        let epsilon = self.epsilon;
+
+        let equivalent = other_window.iter().position(|candidate| {
+            candidate.origin == event.origin
+                && candidate.r#type == event.r#type
+                && (candidate.event_time.get() - event.event_time.get()).abs() <= epsilon
+        });
+
+        Some(match (equivalent, left_first) {
+            (Some(index), _) => {
+                other_window.remove(index);
+
+                EventComparison::Equivalent(event)
+            },
+            (None, true) => EventComparison::OnlyLeft(event),
+            (None, false) => EventComparison::OnlyRight(event),
+        })
+    }
+}
@@ -1,2 +1,5 @@
+pub mod check;
+pub mod compare_logs;
 pub mod replay;
 pub mod simulate;
+pub mod sweep;
@@ -0,0 +1,229 @@
+use anyhow::Result;
+use log::LevelFilter;
+
+use std::{
+    path::PathBuf,
+    sync::{
+        mpsc::{self, Receiver, SyncSender},
+        Arc,
+    },
+    thread,
+};
+
+use necsim_core::{
+    event::{PackedEvent, TypedEvent},
+    lineage::UnresolvedLineage,
+    reporter::Reporter,
+};
+use necsim_impls_std::event_log::metadata::EventLogMetadata;
+
+use necsim_partitioning_core::Partitioning;
+use necsim_plugins_core::{import::AnyReporterPluginVec, match_any_reporter_plugin_vec};
+
+use crate::args::{CommandArgs, ReplayArgs};
+
+#[cfg(test)]
+mod test;
+
+/// Number of events which are decoded into one batch for the reporters
+const REPLAY_BATCH_SIZE: usize = 1 << 14;
+/// Number of decoded batches which are buffered for every reporter
+const REPLAY_BATCH_QUEUE_LENGTH: usize = 4;
+
+#[allow(clippy::module_name_repetitions, clippy::needless_pass_by_value)]
+pub fn replay_with_logger<P: Partitioning>(
+    replay_args: CommandArgs,
+    partitioning: P,
+) -> Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    let replay_args = ReplayArgs::try_parse(replay_args, &partitioning)?;
+    info!("Parsed replay arguments:\n{:#?}", replay_args);
+
+    report_event_log_metadata(replay_args.log.metadata());
+
+    info!("Starting event replay ...");
+
+    let mut event_log = replay_args.log;
+    let unresolved: Arc<[UnresolvedLineage]> = event_log.take_unresolved().into();
+    let length = event_log.length() as u64;
+
+    let mut batch_senders = Vec::new();
+    let mut reporter_threads = Vec::new();
+
+    // Every reporter replays all events on its own thread
+    for plugin in replay_args.reporters {
+        let (sender, receiver) = mpsc::sync_channel(REPLAY_BATCH_QUEUE_LENGTH);
+        let unresolved = unresolved.clone();
+
+        batch_senders.push(sender);
+        reporter_threads.push(thread::spawn(move || {
+            replay_to_reporter(
+                std::iter::once(plugin).collect(),
+                length,
+                &receiver,
+                &unresolved,
+            )
+        }));
+    }
+
+    let decoder_thread = thread::spawn(move || decode_event_log(event_log, batch_senders));
+
+    decoder_thread
+        .join()
+        .map_err(|_| anyhow::anyhow!("The event log decoder panicked during the event replay."))?;
+
+    // The reporters are finalised together in their original order
+    let reporter = reporter_threads
+        .into_iter()
+        .map(|thread| {
+            thread
+                .join()
+                .map_err(|_| anyhow::anyhow!("A reporter panicked during the event replay."))
+                .and_then(|result| result)
+        })
+        .collect::<Result<Vec<AnyReporterPluginVec>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<AnyReporterPluginVec>();
+
+    match_any_reporter_plugin_vec!(reporter => |reporter| {
+        if log::log_enabled!(log::Level::Info) {
+            println!("\n");
+            println!("{:=^80}", " Reporter Summary ");
+            println!();
+        }
+        reporter.finalise();
+        if log::log_enabled!(log::Level::Info) {
+            println!();
+            println!("{:=^80}", " Reporter Summary ");
+            println!();
+        }
+    });
+
+    info!("The event replay has completed.");
+
+    Ok(())
+}
+
+/// Decodes the events of the `event_log` in batches and sends every batch to
+///  all `reporters` which are still receiving
+fn decode_event_log<I: Iterator<Item = PackedEvent>>(
+    mut event_log: I,
+    mut reporters: Vec<SyncSender<Arc<[PackedEvent]>>>,
+) {
+    while !reporters.is_empty() {
+        let batch: Arc<[PackedEvent]> = event_log.by_ref().take(REPLAY_BATCH_SIZE).collect();
+
+        if batch.is_empty() {
+            break;
+        }
+
+        reporters.retain(|reporter| reporter.send(batch.clone()).is_ok());
+    }
+}
+
+/// Initialises the `reporter`, replays the `batches` and `unresolved` lineages
+///  to it, and returns the not yet finalised reporter
+fn replay_to_reporter(
+    mut reporter: AnyReporterPluginVec,
+    length: u64,
+    batches: &Receiver<Arc<[PackedEvent]>>,
+    unresolved: &[UnresolvedLineage],
+) -> Result<AnyReporterPluginVec> {
+    match_any_reporter_plugin_vec!(&mut reporter => |reporter| {
+        reporter.initialise().map_err(anyhow::Error::msg)?;
+
+        replay_batches(reporter, length, batches, unresolved);
+    });
+
+    Ok(reporter)
+}
+
+/// Reports all events from the `batches` in order to the `reporter`, followed
+///  by the `unresolved` lineages
+fn replay_batches<R: Reporter>(
+    reporter: &mut R,
+    length: u64,
+    batches: &Receiver<Arc<[PackedEvent]>>,
+    unresolved: &[UnresolvedLineage],
+) {
+    let mut remaining = length;
+
+    reporter.report_progress(&remaining.into());
+
+    for batch in batches.iter() {
+        for event in batch.iter() {
+            // Do not underflow if the log yields more events than its reported length
+            remaining = remaining.saturating_sub(1);
+            reporter.report_progress(&remaining.into());
+
+            match event.clone().into() {
+                TypedEvent::Speciation(event) => {
+                    reporter.report_speciation(&event.into());
+                },
+                TypedEvent::Dispersal(event) => {
+                    reporter.report_dispersal(&event.into());
+                },
+            }
+        }
+    }
+
+    // Lineages which the simulation did not resolve before it stopped
+    for lineage in unresolved {
+        reporter.report_unresolved(lineage);
+    }
+}
+
+pub(super) fn report_event_log_metadata(metadata: &[(PathBuf, Option<EventLogMetadata>)]) {
+    let mut reference: Option<(&PathBuf, &EventLogMetadata)> = None;
+    let mut ranks: Vec<(u32, &PathBuf)> = Vec::new();
+
+    for (directory, directory_metadata) in metadata {
+        let directory_metadata = if let Some(directory_metadata) = directory_metadata {
+            directory_metadata
+        } else {
+            warn!(
+                "The event log {:?} does not contain any metadata, its provenance is unknown.",
+                directory
+            );
+
+            continue;
+        };
+
+        match reference {
+            None => {
+                info!(
+                    "The event log {:?} was recorded by {} with the configuration:\n{}\nand the \
+                     input files:\n{:#?}",
+                    directory,
+                    directory_metadata.version,
+                    directory_metadata.configuration,
+                    directory_metadata.inputs,
+                );
+
+                reference = Some((directory, directory_metadata));
+            },
+            Some((reference_directory, reference_metadata)) => {
+                if !directory_metadata.is_consistent_with(reference_metadata) {
+                    warn!(
+                        "The event log {:?} was recorded by a different simulation than the event \
+                         log {:?}:\n{:#?}",
+                        directory, reference_directory, directory_metadata,
+                    );
+                }
+            },
+        }
+
+        let rank = directory_metadata.partition.rank();
+
+        if let Some((_, other_directory)) = ranks.iter().find(|(other, _)| *other == rank) {
+            warn!(
+                "The event logs {:?} and {:?} were both recorded by partition {}.",
+                other_directory, directory, rank,
+            );
+        } else {
+            ranks.push((rank, directory));
+        }
+    }
+}
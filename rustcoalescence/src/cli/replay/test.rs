@@ -0,0 +1,129 @@
+use std::{
+    sync::{mpsc, Arc},
+    thread,
+};
+
+use serde::{
+    de::{value::Error, IntoDeserializer},
+    Deserialize,
+};
+
+use necsim_core::{
+    event::{DispersalEvent, LineageInteraction, PackedEvent, SpeciationEvent},
+    impl_finalise, impl_report,
+    landscape::{IndexedLocation, Location},
+    lineage::{GlobalLineageReference, UnresolvedLineage},
+    reporter::Reporter,
+};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use super::{decode_event_log, replay_batches, REPLAY_BATCH_QUEUE_LENGTH, REPLAY_BATCH_SIZE};
+
+fn lineage(id: u64) -> GlobalLineageReference {
+    GlobalLineageReference::deserialize(IntoDeserializer::<Error>::into_deserializer(id)).unwrap()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn event(i: u64) -> PackedEvent {
+    let origin = IndexedLocation::new(Location::new(i as u32, 0), 0);
+    let prior_time = NonNegativeF64::new(i as f64).unwrap();
+    let event_time = PositiveF64::new((i + 1) as f64).unwrap();
+
+    if i % 3 == 0 {
+        SpeciationEvent {
+            origin,
+            prior_time,
+            event_time,
+            global_lineage_reference: lineage(i),
+        }
+        .into()
+    } else {
+        DispersalEvent {
+            origin,
+            prior_time,
+            event_time,
+            global_lineage_reference: lineage(i),
+            target: IndexedLocation::new(Location::new(0, i as u32), 0),
+            interaction: LineageInteraction::Coalescence(lineage(i / 2)),
+        }
+        .into()
+    }
+}
+
+#[derive(Debug, Default)]
+struct RecordingReporter {
+    events: Vec<PackedEvent>,
+    progress: Vec<u64>,
+    unresolved: Vec<GlobalLineageReference>,
+}
+
+impl Reporter for RecordingReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.events.push(speciation.clone().into());
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.events.push(dispersal.clone().into());
+    });
+
+    impl_report!(progress(&mut self, remaining: Used) {
+        self.progress.push(*remaining);
+    });
+
+    impl_finalise!((self) {});
+
+    fn report_unresolved(&mut self, lineage: &UnresolvedLineage) {
+        self.unresolved.push(lineage.global_reference.clone());
+    }
+}
+
+#[test]
+fn test_replay_reports_identical_order_to_all_reporters() {
+    const REPORTERS: usize = 4;
+
+    let events = (0..(REPLAY_BATCH_SIZE as u64 * 3 + 7))
+        .map(event)
+        .collect::<Vec<_>>();
+    let unresolved: Arc<[UnresolvedLineage]> = (0..3)
+        .map(|id| UnresolvedLineage {
+            global_reference: lineage(id),
+            location: Location::new(0, 0),
+            last_event_time: NonNegativeF64::zero(),
+        })
+        .collect();
+    // The reported length is too short, so the remaining progress must saturate
+    let length = events.len() as u64 - 2;
+
+    let mut batch_senders = Vec::new();
+    let mut reporter_threads = Vec::new();
+
+    for _ in 0..REPORTERS {
+        let (sender, receiver) = mpsc::sync_channel(REPLAY_BATCH_QUEUE_LENGTH);
+        let unresolved = unresolved.clone();
+
+        batch_senders.push(sender);
+        reporter_threads.push(thread::spawn(move || {
+            let mut reporter = RecordingReporter::default();
+
+            replay_batches(&mut reporter, length, &receiver, &unresolved);
+
+            reporter
+        }));
+    }
+
+    let decoder_events = events.clone();
+    thread::spawn(move || decode_event_log(decoder_events.into_iter(), batch_senders))
+        .join()
+        .unwrap();
+
+    for reporter_thread in reporter_threads {
+        let reporter = reporter_thread.join().unwrap();
+
+        assert_eq!(reporter.events, events);
+        assert_eq!(reporter.unresolved, (0..3).map(lineage).collect::<Vec<_>>());
+
+        assert_eq!(reporter.progress.len(), events.len() + 1);
+        assert_eq!(reporter.progress.first(), Some(&length));
+        assert_eq!(reporter.progress.last(), Some(&0));
+    }
+}
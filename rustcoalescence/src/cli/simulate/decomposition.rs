@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use array2d::Array2D;
+
+use necsim_core::{
+    cogs::{Habitat, RngCore},
+    reporter::Reporter,
+};
+use necsim_impls_no_std::decomposition::Decomposition;
+use necsim_partitioning_core::LocalPartition;
+
+use rustcoalescence_embed::SimulationHooks;
+use rustcoalescence_scenarios::Scenario;
+
+/// Writes the partition map of a bounded scenario to the `path`, if any
+pub struct PartitionMapWriter<'p> {
+    pub path: Option<&'p Path>,
+}
+
+impl<'p, R: Reporter, P: LocalPartition<R>> SimulationHooks<R, P> for PartitionMapWriter<'p> {
+    fn initialised_bounded_scenario<G: RngCore, O: Scenario<G>>(
+        &mut self,
+        scenario: &O,
+        local_partition: &P,
+    ) -> Result<()> {
+        match self.path {
+            Some(path) => write_partition_map(scenario, local_partition, path),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes the rank of the partition which each location of the `scenario`'s
+///  habitat is assigned to into a TIFF map at `path`. Locations without
+///  habitat are recorded as `u32::MAX`, the map's no data value.
+///
+/// Only the root partition writes the map.
+fn write_partition_map<G: RngCore, O: Scenario<G>, R: Reporter, P: LocalPartition<R>>(
+    scenario: &O,
+    local_partition: &P,
+    path: &Path,
+) -> Result<()> {
+    if !local_partition.is_root() {
+        return Ok(());
+    }
+
+    let habitat = scenario.habitat();
+    let extent = habitat.get_extent();
+
+    let decomposition = scenario.decompose(
+        local_partition.get_partition_rank(),
+        local_partition.get_number_of_partitions(),
+    );
+
+    let map = Array2D::from_iter_row_major(
+        extent.iter().map(|location| {
+            if habitat.get_habitat_at_location(&location) > 0 {
+                decomposition.map_location_to_subdomain_rank(&location, habitat)
+            } else {
+                u32::MAX
+            }
+        }),
+        extent.height() as usize,
+        extent.width() as usize,
+    )
+    .map_err(|err| anyhow::anyhow!("Could not construct the partition map: {:?}.", err))?;
+
+    crate::tiff::write_map_to_tiff(path, &map, Some(u32::MAX))
+        .with_context(|| format!("Failed to write the partition map to {:?}.", path))?;
+
+    info!("The partition map has been written to {:?}.", path);
+
+    Ok(())
+}
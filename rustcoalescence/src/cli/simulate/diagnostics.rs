@@ -0,0 +1,37 @@
+use std::fmt::Write;
+
+use necsim_partitioning_core::diagnostics::PartitionDiagnostics;
+
+/// Logs how many lineages migrated between every pair of partitions, and how
+///  often each partition rolled back, voted, and waited for the others
+pub fn log_migrations(diagnostics: &[PartitionDiagnostics]) {
+    let mut migrations = format!("{:>10} |", "from \\ to");
+
+    for rank in 0..diagnostics.len() {
+        let _ = write!(migrations, " {:>10}", rank);
+    }
+
+    for (rank, partition) in diagnostics.iter().enumerate() {
+        let _ = write!(migrations, "\n{:>10} |", rank);
+
+        for emigrations in &partition.emigrations {
+            let _ = write!(migrations, " {:>10}", emigrations);
+        }
+    }
+
+    info!(
+        "The lineages migrated between the partitions as follows:\n{}\n",
+        migrations
+    );
+
+    for (rank, partition) in diagnostics.iter().enumerate() {
+        info!(
+            "Partition {} rolled back {} times and took part in {} votes, in which it waited for \
+             {:.3}s.",
+            rank,
+            partition.rollbacks,
+            partition.vote_rounds,
+            partition.idle_time.as_secs_f64(),
+        );
+    }
+}
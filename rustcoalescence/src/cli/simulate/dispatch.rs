@@ -1,35 +1,36 @@
-use anyhow::Result;
-
-use rustcoalescence_algorithms::Algorithm;
+use std::{path::PathBuf, time::Duration};
 
-#[cfg(feature = "rustcoalescence-algorithms-cuda")]
-use rustcoalescence_algorithms_cuda::CudaAlgorithm;
-#[cfg(feature = "rustcoalescence-algorithms-independent")]
-use rustcoalescence_algorithms_independent::IndependentAlgorithm;
-#[cfg(feature = "rustcoalescence-algorithms-monolithic")]
-use rustcoalescence_algorithms_monolithic::{
-    classical::ClassicalAlgorithm, gillespie::GillespieAlgorithm,
-    skipping_gillespie::SkippingGillespieAlgorithm,
-};
+use anyhow::Result;
 
 use necsim_core::reporter::Reporter;
 use necsim_core_bond::NonNegativeF64;
-use necsim_impls_no_std::cogs::origin_sampler::pre_sampler::OriginPreSampler;
-use necsim_partitioning_core::LocalPartition;
+use necsim_impls_no_std::parallelisation::SimulationLimits;
+use necsim_partitioning_core::{
+    interrupt::{reduce_vote_interrupted, request_interrupt},
+    LocalPartition,
+};
 
-use rustcoalescence_scenarios::{
-    almost_infinite::AlmostInfiniteScenario, non_spatial::NonSpatialScenario,
-    spatially_explicit::SpatiallyExplicitScenario, spatially_implicit::SpatiallyImplicitScenario,
-    Scenario,
+use rustcoalescence_embed::{
+    simulate_with_local_partition_and_hooks, AlgorithmConfig, ScenarioConfig, SimulationConfig,
 };
 
-use crate::args::{Algorithm as AlgorithmArgs, CommonArgs, Scenario as ScenarioArgs};
+use crate::{
+    args::{Algorithm as AlgorithmArgs, CommonArgs, Scenario as ScenarioArgs},
+    signal,
+};
+
+use super::{
+    decomposition::PartitionMapWriter,
+    diagnostics::log_migrations,
+    results::{PartitionResults, SimulationResults},
+};
 
 #[allow(clippy::too_many_lines, clippy::boxed_local)]
 pub fn simulate_with_logger<R: Reporter, P: LocalPartition<R>>(
     mut local_partition: Box<P>,
     common_args: CommonArgs,
     scenario: ScenarioArgs,
+    results: Option<PathBuf>,
 ) -> Result<()> {
     if local_partition.get_number_of_partitions().get() <= 1 {
         info!("The simulation will be run in monolithic mode.");
@@ -40,112 +41,158 @@ pub fn simulate_with_logger<R: Reporter, P: LocalPartition<R>>(
         );
     }
 
-    let pre_sampler = OriginPreSampler::all().percentage(common_args.sample_percentage.get());
+    if let Some(max_wall_time) = common_args.limits.max_wall_time {
+        signal::interrupt_after(Duration::from_secs_f64(max_wall_time.get()));
+    }
 
-    let (time, steps): (NonNegativeF64, u64) = crate::match_scenario_algorithm!(
-        (common_args.algorithm, scenario => scenario)
+    if common_args.decomposition_map.is_some()
+        && !matches!(
+            scenario,
+            ScenarioArgs::SpatiallyExplicit(_) | ScenarioArgs::Islands(_) | ScenarioArgs::Torus(_)
+        )
     {
-        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
-        AlgorithmArgs::Classical(algorithm_args) => {
-            ClassicalAlgorithm::initialise_and_simulate(
-                algorithm_args,
-                common_args.seed,
-                scenario,
-                pre_sampler,
-                &mut *local_partition,
-            )
-            .into_ok()
-        },
-        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
-        AlgorithmArgs::Gillespie(algorithm_args) => {
-            GillespieAlgorithm::initialise_and_simulate(
-                algorithm_args,
-                common_args.seed,
-                scenario,
-                pre_sampler,
-                &mut *local_partition,
-            )
-            .into_ok()
-        },
-        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
-        AlgorithmArgs::SkippingGillespie(algorithm_args) => {
-            SkippingGillespieAlgorithm::initialise_and_simulate(
-                algorithm_args,
-                common_args.seed,
-                scenario,
-                pre_sampler,
-                &mut *local_partition,
-            )
-            .into_ok()
-        },
-        #[cfg(feature = "rustcoalescence-algorithms-independent")]
-        AlgorithmArgs::Independent(algorithm_args) => {
-            IndependentAlgorithm::initialise_and_simulate(
-                algorithm_args,
-                common_args.seed,
-                scenario,
-                pre_sampler,
-                &mut *local_partition,
-            )
-            .into_ok()
-        },
-        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
-        AlgorithmArgs::Cuda(algorithm_args) => {
-            CudaAlgorithm::initialise_and_simulate(
-                algorithm_args,
-                common_args.seed,
-                scenario,
-                pre_sampler,
-                &mut *local_partition,
-            )?
-        }
-        <=>
-        ScenarioArgs::SpatiallyExplicit(scenario_args) => {
-            SpatiallyExplicitScenario::initialise(
-                scenario_args,
-                common_args.speciation_probability_per_generation,
-            )?
-        },
-        ScenarioArgs::NonSpatial(scenario_args) => {
-            NonSpatialScenario::initialise(
-                scenario_args,
-                common_args.speciation_probability_per_generation,
-            )
-            .into_ok()
+        warn!("The partition map can only be written for bounded habitats and will be skipped.");
+    }
+
+    let (time, steps): (NonNegativeF64, u64) = simulate_with_local_partition_and_hooks(
+        SimulationConfig {
+            speciation_probability_per_generation: common_args
+                .speciation_probability_per_generation,
+            sample_percentage: common_args.sample_percentage,
+            seed: common_args.seed,
+            limits: SimulationLimits::from(common_args.limits),
+            algorithm: algorithm_config(common_args.algorithm),
+            scenario: scenario_config(scenario),
         },
-        ScenarioArgs::AlmostInfinite(scenario_args) => {
-            AlmostInfiniteScenario::initialise(
-                scenario_args,
-                common_args.speciation_probability_per_generation,
-            )
-            .into_ok()
+        &mut *local_partition,
+        &mut PartitionMapWriter {
+            path: common_args.decomposition_map.as_deref(),
         },
-        ScenarioArgs::SpatiallyImplicit(scenario_args) => {
-            SpatiallyImplicitScenario::initialise(
-                scenario_args,
-                common_args.speciation_probability_per_generation,
-            )
-            .into_ok()
-        }
-    });
+    )?;
+
+    // The simulation was interrupted on all partitions iff it was on any,
+    //  such that all partitions exit with the same status
+    let interrupted = reduce_vote_interrupted(&*local_partition);
+
+    if interrupted {
+        request_interrupt();
+    }
+
+    // Partitions which were only interrupted by the others' vote exit with
+    //  the same status as the partitions which received a signal
+    let signalled = local_partition.reduce_vote_continue(signal::was_signalled());
+
+    if signalled {
+        signal::adopt_interrupt();
+    }
+
+    // Only the root partition receives the diagnostics of all partitions
+    let diagnostics = local_partition.gather_diagnostics();
 
     if log::log_enabled!(log::Level::Info) {
         println!("\n");
         println!("{:=^80}", " Reporter Summary ");
         println!();
     }
-    local_partition.finalise_reporting();
+    let partition = PartitionResults {
+        rank: local_partition.get_partition_rank(),
+        partitions: local_partition.get_number_of_partitions().get(),
+    };
+    let is_root = local_partition.is_root();
+    let reporters = local_partition.finalise_reporting()?;
     if log::log_enabled!(log::Level::Info) {
         println!();
         println!("{:=^80}", " Reporter Summary ");
         println!();
     }
 
-    info!(
-        "The simulation finished at time {} after {} steps.\n",
-        time.get(),
-        steps
-    );
+    if signalled {
+        warn!(
+            "The simulation was interrupted at time {} after {} steps.\n",
+            time.get(),
+            steps
+        );
+    } else if interrupted {
+        warn!(
+            "The simulation exceeded its maximum wall time at time {} after {} steps.\n",
+            time.get(),
+            steps
+        );
+    } else {
+        info!(
+            "The simulation finished at time {} after {} steps.\n",
+            time.get(),
+            steps
+        );
+    }
+
+    if let Some(diagnostics) = &diagnostics {
+        if diagnostics.len() > 1 {
+            log_migrations(diagnostics);
+        }
+    }
+
+    // Only the root partition has the combined results of the simulation
+    if let (Some(results), true) = (results, is_root) {
+        SimulationResults {
+            version: format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+            partition,
+            time: time.get(),
+            steps,
+            interrupted,
+            reporters,
+            diagnostics: diagnostics.unwrap_or_default(),
+        }
+        .write_to_file(&results)?;
+
+        info!("The simulation results have been written to {:?}.", results);
+    }
 
     Ok(())
 }
+
+fn algorithm_config(algorithm: AlgorithmArgs) -> AlgorithmConfig {
+    match algorithm {
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmArgs::Classical(algorithm_args) => AlgorithmConfig::Classical(algorithm_args),
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmArgs::Gillespie(algorithm_args) => AlgorithmConfig::Gillespie(algorithm_args),
+        #[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+        AlgorithmArgs::SkippingGillespie(algorithm_args) => {
+            AlgorithmConfig::SkippingGillespie(algorithm_args)
+        },
+        #[cfg(feature = "rustcoalescence-algorithms-cuda")]
+        AlgorithmArgs::Cuda(algorithm_args) => AlgorithmConfig::Cuda(algorithm_args),
+        #[cfg(feature = "rustcoalescence-algorithms-independent")]
+        AlgorithmArgs::Independent(algorithm_args) => AlgorithmConfig::Independent(algorithm_args),
+    }
+}
+
+fn scenario_config(scenario: ScenarioArgs) -> ScenarioConfig {
+    match scenario {
+        ScenarioArgs::SpatiallyExplicit(scenario_args) => {
+            ScenarioConfig::SpatiallyExplicit(scenario_args)
+        },
+        ScenarioArgs::NonSpatial(scenario_args) => ScenarioConfig::NonSpatial(scenario_args),
+        ScenarioArgs::SpatiallyImplicit(scenario_args) => {
+            ScenarioConfig::SpatiallyImplicit(scenario_args)
+        },
+        ScenarioArgs::AlmostInfiniteNormal(scenario_args) => {
+            ScenarioConfig::AlmostInfiniteNormal(scenario_args)
+        },
+        ScenarioArgs::AlmostInfiniteClark2Dt(scenario_args) => {
+            ScenarioConfig::AlmostInfiniteClark2Dt(scenario_args)
+        },
+        ScenarioArgs::AlmostInfiniteCauchy(scenario_args) => {
+            ScenarioConfig::AlmostInfiniteCauchy(scenario_args)
+        },
+        ScenarioArgs::AlmostInfiniteExpPower(scenario_args) => {
+            ScenarioConfig::AlmostInfiniteExpPower(scenario_args)
+        },
+        ScenarioArgs::AlmostInfiniteLaplace(scenario_args) => {
+            ScenarioConfig::AlmostInfiniteLaplace(scenario_args)
+        },
+        ScenarioArgs::Islands(scenario_args) => ScenarioConfig::Islands(scenario_args),
+        ScenarioArgs::Torus(scenario_args) => ScenarioConfig::Torus(scenario_args),
+    }
+}
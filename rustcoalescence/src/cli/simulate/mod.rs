@@ -1,38 +1,69 @@
+use std::path::PathBuf;
+
 use anyhow::Result;
 
 use necsim_core::reporter::Reporter;
 use necsim_partitioning_core::LocalPartition;
 
-use crate::args::{CommonArgs, Scenario as ScenarioArgs};
+use crate::{
+    args::{CommonArgs, Scenario as ScenarioArgs},
+    signal::install_interrupt_handlers,
+};
 
 #[cfg(not(feature = "necsim-partitioning-mpi"))]
 pub mod monolithic;
 #[cfg(feature = "necsim-partitioning-mpi")]
 pub mod mpi;
 
-#[macro_use]
-mod r#impl;
-
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+mod decomposition;
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+mod diagnostics;
 #[cfg(any(
     feature = "rustcoalescence-algorithms-monolithic",
     feature = "rustcoalescence-algorithms-independent",
     feature = "rustcoalescence-algorithms-cuda"
 ))]
 mod dispatch;
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+mod results;
 
 #[allow(clippy::module_name_repetitions)]
 pub fn simulate_with_logger<R: Reporter, P: LocalPartition<R>>(
     local_partition: Box<P>,
     common_args: CommonArgs,
     scenario: ScenarioArgs,
+    results: Option<PathBuf>,
 ) -> Result<()> {
+    // Uninterruptible simulations are terminated by signals as usual
+    if common_args.algorithm.is_interruptible() {
+        install_interrupt_handlers()?;
+    } else {
+        info!(
+            "The simulation cannot be interrupted and will be terminated immediately by SIGINT or \
+             SIGTERM."
+        );
+    }
+
     #[cfg(any(
         feature = "rustcoalescence-algorithms-monolithic",
         feature = "rustcoalescence-algorithms-independent",
         feature = "rustcoalescence-algorithms-cuda"
     ))]
     {
-        dispatch::simulate_with_logger(local_partition, common_args, scenario)
+        dispatch::simulate_with_logger(local_partition, common_args, scenario, results)
     }
 
     #[cfg(not(any(
@@ -44,6 +75,7 @@ pub fn simulate_with_logger<R: Reporter, P: LocalPartition<R>>(
         std::mem::drop(local_partition);
         std::mem::drop(common_args);
         std::mem::drop(scenario);
+        std::mem::drop(results);
 
         Err(anyhow::anyhow!(
             "rustcoalescence must be compiled to support at least one algorithm."
@@ -40,11 +40,13 @@ pub fn simulate_with_logger_monolithic(simulate_args: CommandArgs) -> Result<()>
                 ),
                 simulate_args.common,
                 simulate_args.scenario,
+                simulate_args.results,
             ),
             None => super::simulate_with_logger(
                 Box::new(LiveMonolithicLocalPartition::try_from_context(DynamicReporterContext::new(reporter))?),
                 simulate_args.common,
                 simulate_args.scenario,
+                simulate_args.results,
             ),
         }
     })
@@ -44,16 +44,36 @@ pub fn simulate_with_logger_mpi(simulate_args: CommandArgs) -> Result<()> {
             .with_context(|| "Failed to initialise the local MPI partition.")?
         {
             MpiLocalPartition::LiveMonolithic(partition) => {
-                super::simulate_with_logger(partition, simulate_args.common, simulate_args.scenario)
+                super::simulate_with_logger(
+                    partition,
+                    simulate_args.common,
+                    simulate_args.scenario,
+                    simulate_args.results,
+                )
             },
             MpiLocalPartition::RecordedMonolithic(partition) => {
-                super::simulate_with_logger(partition, simulate_args.common, simulate_args.scenario)
+                super::simulate_with_logger(
+                    partition,
+                    simulate_args.common,
+                    simulate_args.scenario,
+                    simulate_args.results,
+                )
             },
             MpiLocalPartition::Root(partition) => {
-                super::simulate_with_logger(partition, simulate_args.common, simulate_args.scenario)
+                super::simulate_with_logger(
+                    partition,
+                    simulate_args.common,
+                    simulate_args.scenario,
+                    simulate_args.results,
+                )
             },
             MpiLocalPartition::Parallel(partition) => {
-                super::simulate_with_logger(partition, simulate_args.common, simulate_args.scenario)
+                super::simulate_with_logger(
+                    partition,
+                    simulate_args.common,
+                    simulate_args.scenario,
+                    simulate_args.results,
+                )
             },
         }
     })
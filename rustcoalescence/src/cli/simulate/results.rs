@@ -0,0 +1,40 @@
+use std::{fs::OpenOptions, io::BufWriter, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use necsim_core::reporter::ReporterSummary;
+use necsim_partitioning_core::diagnostics::PartitionDiagnostics;
+
+#[derive(Debug, Serialize)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SimulationResults {
+    pub version: String,
+    pub partition: PartitionResults,
+    pub time: f64,
+    pub steps: u64,
+    pub interrupted: bool,
+    pub reporters: ReporterSummary,
+    pub diagnostics: Vec<PartitionDiagnostics>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(clippy::module_name_repetitions)]
+pub struct PartitionResults {
+    pub rank: u32,
+    pub partitions: u32,
+}
+
+impl SimulationResults {
+    pub fn write_to_file(&self, path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(path)
+            .with_context(|| format!("Failed to create the simulation results file {:?}.", path))?;
+
+        serde_json::to_writer_pretty(BufWriter::new(file), self)
+            .with_context(|| format!("Failed to write the simulation results to {:?}.", path))
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    fmt::Write as _,
+    fs::File,
+    os::unix::process::CommandExt,
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use log::LevelFilter;
+
+use necsim_partitioning_core::Partitioning;
+
+use crate::{
+    args::{
+        sweep::{job_config, SweepArgs},
+        CommandArgs,
+    },
+    signal,
+};
+
+/// Interval at which a running sweep job checks for received signals
+const SIGNAL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[allow(clippy::module_name_repetitions, clippy::needless_pass_by_value)]
+pub fn sweep_with_logger<P: Partitioning>(sweep_args: CommandArgs, partitioning: P) -> Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    let sweep_args = SweepArgs::try_parse(sweep_args, &partitioning)?;
+    info!(
+        "Parsed sweep arguments with {} jobs over {:?} from the base configuration {:?}.",
+        sweep_args.combinations.len(),
+        sweep_args.parameters,
+        sweep_args.base
+    );
+
+    if std::fs::read_dir(&sweep_args.output).map_or(false, |mut dir| dir.next().is_some()) {
+        return Err(anyhow::anyhow!(
+            "The sweep output directory {:?} is not empty.",
+            sweep_args.output
+        ));
+    }
+
+    let executable =
+        std::env::current_exe().context("Failed to locate the rustcoalescence executable.")?;
+
+    let jobs = prepare_jobs(&sweep_args)?;
+
+    // SIGINT and SIGTERM are forwarded to the running jobs, which are then
+    //  awaited, and no further jobs are started
+    signal::install_interrupt_handlers()?;
+
+    info!(
+        "Starting to run {} sweep jobs on up to {} cores ...",
+        jobs.len(),
+        sweep_args.jobs
+    );
+
+    let outcomes = run_jobs(jobs, sweep_args.jobs.get(), &executable)?;
+
+    let summary_path = sweep_args.output.join("summary.csv");
+
+    std::fs::write(&summary_path, summary_table(&sweep_args, &outcomes))
+        .with_context(|| format!("Failed to write the sweep summary to {:?}.", summary_path))?;
+
+    info!("The sweep summary has been written to {:?}.", summary_path);
+
+    if signal::received().is_some() {
+        warn!(
+            "The sweep was interrupted after running {} out of {} jobs.",
+            outcomes.len(),
+            sweep_args.combinations.len()
+        );
+
+        return Ok(());
+    }
+
+    let failed = outcomes
+        .iter()
+        .filter(|(_, outcome)| matches!(outcome, JobOutcome::Failed(_)))
+        .count();
+
+    if failed == 0 {
+        info!("All {} sweep jobs finished successfully.", outcomes.len());
+
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "{} out of {} sweep jobs failed.",
+            failed,
+            outcomes.len()
+        ))
+    }
+}
+
+/// Creates the directories and configurations of all sweep jobs
+fn prepare_jobs(sweep_args: &SweepArgs) -> Result<Vec<SweepJob>> {
+    let digits = sweep_args
+        .combinations
+        .len()
+        .saturating_sub(1)
+        .to_string()
+        .len();
+
+    let mut jobs = Vec::with_capacity(sweep_args.combinations.len());
+
+    for (index, combination) in sweep_args.combinations.iter().enumerate() {
+        let directory = sweep_args
+            .output
+            .join(format!("{:0width$}", index, width = digits));
+
+        std::fs::create_dir_all(&directory).with_context(|| {
+            format!("Failed to create the sweep job directory {:?}.", directory)
+        })?;
+
+        // The job runs inside its directory, which must thus be absolute
+        let directory = directory.canonicalize().with_context(|| {
+            format!("Failed to resolve the sweep job directory {:?}.", directory)
+        })?;
+
+        let config = job_config(&sweep_args.substitute(combination), &directory)?;
+
+        std::fs::write(directory.join("config.ron"), &config).with_context(|| {
+            format!(
+                "Failed to write the sweep job configuration to {:?}.",
+                directory
+            )
+        })?;
+
+        jobs.push(SweepJob { directory, config });
+    }
+
+    Ok(jobs)
+}
+
+/// Runs all `jobs` on up to `parallelism` worker threads and returns their
+///  outcomes in job order
+fn run_jobs(
+    jobs: Vec<SweepJob>,
+    parallelism: usize,
+    executable: &Path,
+) -> Result<Vec<(usize, JobOutcome)>> {
+    let jobs = Arc::new(jobs);
+    let next_job = Arc::new(AtomicUsize::new(0));
+
+    let workers = (0..parallelism.min(jobs.len()))
+        .map(|_| {
+            let jobs = jobs.clone();
+            let next_job = next_job.clone();
+            let executable = executable.to_owned();
+
+            std::thread::spawn(move || {
+                let mut outcomes = Vec::new();
+
+                loop {
+                    // No new jobs are started once the sweep has been interrupted
+                    if signal::received().is_some() {
+                        break outcomes;
+                    }
+
+                    let index = next_job.fetch_add(1, Ordering::Relaxed);
+
+                    let job = match jobs.get(index) {
+                        Some(job) => job,
+                        None => break outcomes,
+                    };
+
+                    let outcome = job.run(&executable);
+
+                    match &outcome {
+                        JobOutcome::Finished { time, steps } => info!(
+                            "Sweep job {:?} finished at time {} after {} steps.",
+                            job.directory, time, steps
+                        ),
+                        JobOutcome::Failed(reason) => {
+                            error!("Sweep job {:?} failed: {}", job.directory, reason);
+                        },
+                    }
+
+                    outcomes.push((index, outcome));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut outcomes = Vec::with_capacity(jobs.len());
+
+    for worker in workers {
+        outcomes.extend(
+            worker
+                .join()
+                .map_err(|_| anyhow::anyhow!("A sweep worker thread panicked."))?,
+        );
+    }
+
+    outcomes.sort_by_key(|(index, _)| *index);
+
+    Ok(outcomes)
+}
+
+struct SweepJob {
+    directory: PathBuf,
+    config: String,
+}
+
+enum JobOutcome {
+    Finished { time: f64, steps: u64 },
+    Failed(String),
+}
+
+impl SweepJob {
+    fn run(&self, executable: &Path) -> JobOutcome {
+        match self.try_run(executable) {
+            Ok(outcome) => outcome,
+            Err(err) => JobOutcome::Failed(format!("{:#}", err)),
+        }
+    }
+
+    fn try_run(&self, executable: &Path) -> Result<JobOutcome> {
+        let log_path = self.directory.join("simulate.log");
+        let log = File::create(&log_path)
+            .with_context(|| format!("Failed to create the job log file {:?}.", log_path))?;
+
+        let mut command = Command::new(executable);
+
+        // Relative output paths, e.g. of reporters, are resolved inside the
+        //  job's own directory
+        command
+            .arg("simulate")
+            .arg(&self.config)
+            .current_dir(&self.directory)
+            .stdin(Stdio::null())
+            .stdout(log.try_clone()?)
+            .stderr(log);
+
+        // The job runs in its own process group such that a terminal's SIGINT
+        //  only reaches it once, when it is forwarded by the sweep, as a
+        //  second signal would terminate it before it finalises
+        unsafe {
+            command.pre_exec(|| {
+                if libc::setpgid(0, 0) == 0 {
+                    Ok(())
+                } else {
+                    Err(std::io::Error::last_os_error())
+                }
+            });
+        }
+
+        let mut child = command
+            .spawn()
+            .context("Failed to launch the simulation.")?;
+
+        let status = loop {
+            if let Some(status) = child
+                .try_wait()
+                .context("Failed to wait for the simulation.")?
+            {
+                break status;
+            }
+
+            if let Some(signal) = signal::received() {
+                // The interrupted simulation still finalises its reporters
+                #[allow(clippy::cast_possible_wrap)]
+                unsafe {
+                    libc::kill(child.id() as libc::pid_t, signal);
+                }
+
+                break child.wait().context("Failed to wait for the simulation.")?;
+            }
+
+            std::thread::sleep(SIGNAL_POLL_INTERVAL);
+        };
+
+        if !status.success() {
+            return Ok(JobOutcome::Failed(describe_exit_status(status)));
+        }
+
+        let results_path = self.directory.join("results.json");
+        let results: serde_json::Value = serde_json::from_reader(
+            File::open(&results_path)
+                .with_context(|| format!("Failed to open the results file {:?}.", results_path))?,
+        )
+        .with_context(|| format!("Failed to read the results file {:?}.", results_path))?;
+
+        match (results["time"].as_f64(), results["steps"].as_u64()) {
+            (Some(time), Some(steps)) => Ok(JobOutcome::Finished { time, steps }),
+            _ => Err(anyhow::anyhow!(
+                "The results file {:?} is missing the time or steps.",
+                results_path
+            )),
+        }
+    }
+}
+
+fn describe_exit_status(status: ExitStatus) -> String {
+    match status.code() {
+        Some(code) => format!("the simulation exited with status code {}", code),
+        None => String::from("the simulation was terminated by a signal"),
+    }
+}
+
+fn summary_table(sweep_args: &SweepArgs, outcomes: &[(usize, JobOutcome)]) -> String {
+    let mut table = String::from("job");
+
+    for parameter in &sweep_args.parameters {
+        table.push(',');
+        table.push_str(&csv_field(parameter));
+    }
+
+    table.push_str(",status,time,steps\n");
+
+    for (index, outcome) in outcomes {
+        table.push_str(&index.to_string());
+
+        for value in &sweep_args.combinations[*index] {
+            table.push(',');
+            table.push_str(&csv_field(value));
+        }
+
+        match outcome {
+            JobOutcome::Finished { time, steps } => {
+                let _ = writeln!(table, ",finished,{},{}", time, steps);
+            },
+            JobOutcome::Failed(reason) => {
+                let _ = writeln!(table, ",{},,", csv_field(&format!("failed: {}", reason)));
+            },
+        }
+    }
+
+    table
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        String::from(field)
+    }
+}
@@ -1,5 +1,7 @@
 #![deny(clippy::pedantic)]
 #![feature(associated_type_bounds)]
+#![feature(available_concurrency)]
+#![feature(bindings_after_at)]
 #![feature(unwrap_infallible)]
 
 #[macro_use]
@@ -8,6 +10,15 @@ extern crate serde_derive_state;
 #[macro_use]
 extern crate log;
 
+// The algorithms are renamed dependencies such that their features can also
+//  enable them in `rustcoalescence-embed`
+#[cfg(feature = "rustcoalescence-algorithms-cuda")]
+extern crate algorithms_cuda as rustcoalescence_algorithms_cuda;
+#[cfg(feature = "rustcoalescence-algorithms-independent")]
+extern crate algorithms_independent as rustcoalescence_algorithms_independent;
+#[cfg(feature = "rustcoalescence-algorithms-monolithic")]
+extern crate algorithms_monolithic as rustcoalescence_algorithms_monolithic;
+
 use anyhow::{Context, Result};
 use log::LevelFilter;
 use structopt::StructOpt;
@@ -17,6 +28,7 @@ mod cli;
 mod maps;
 mod minimal_logger;
 mod reporter;
+mod signal;
 mod tiff;
 
 use args::RustcoalescenceArgs;
@@ -58,8 +70,64 @@ fn main() -> Result<()> {
             }
         }
         .context("Failed to replay the simulation."),
+        RustcoalescenceArgs::Check(check_args) => {
+            #[cfg(feature = "necsim-partitioning-mpi")]
+            {
+                use necsim_partitioning_mpi::MpiPartitioning;
+
+                cli::check::check_with_logger(check_args, MpiPartitioning::initialise()?)
+            }
+            #[cfg(not(feature = "necsim-partitioning-mpi"))]
+            {
+                use necsim_partitioning_monolithic::live::LiveMonolithicPartitioning;
+
+                cli::check::check_with_logger(check_args, LiveMonolithicPartitioning::default())
+            }
+        }
+        .context("Failed to check the simulation configuration."),
+        RustcoalescenceArgs::CompareLogs(compare_args) => {
+            #[cfg(feature = "necsim-partitioning-mpi")]
+            {
+                use necsim_partitioning_mpi::MpiPartitioning;
+
+                cli::compare_logs::compare_logs_with_logger(
+                    compare_args,
+                    MpiPartitioning::initialise()?,
+                )
+            }
+            #[cfg(not(feature = "necsim-partitioning-mpi"))]
+            {
+                use necsim_partitioning_monolithic::live::LiveMonolithicPartitioning;
+
+                cli::compare_logs::compare_logs_with_logger(
+                    compare_args,
+                    LiveMonolithicPartitioning::default(),
+                )
+            }
+        }
+        .context("Failed to compare the event logs."),
+        RustcoalescenceArgs::Sweep(sweep_args) => {
+            #[cfg(feature = "necsim-partitioning-mpi")]
+            {
+                use necsim_partitioning_mpi::MpiPartitioning;
+
+                cli::sweep::sweep_with_logger(sweep_args, MpiPartitioning::initialise()?)
+            }
+            #[cfg(not(feature = "necsim-partitioning-mpi"))]
+            {
+                use necsim_partitioning_monolithic::live::LiveMonolithicPartitioning;
+
+                cli::sweep::sweep_with_logger(sweep_args, LiveMonolithicPartitioning::default())
+            }
+        }
+        .context("Failed to perform the parameter sweep."),
     };
 
+    // An interrupted simulation exits with a distinct status
+    if let (Ok(()), Some(exit_code)) = (&result, signal::interrupted_exit_code()) {
+        std::process::exit(exit_code);
+    }
+
     // Hide non-root error messages
     if log::max_level() == LevelFilter::Off {
         Ok(())
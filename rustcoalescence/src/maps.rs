@@ -16,6 +16,30 @@ pub fn load_dispersal_map(path: &Path, loading_mode: MapLoadingMode) -> Result<A
     .with_context(|| format!("Failed to load the dispersal map from {:?}.", path))
 }
 
+pub fn load_sample_map(path: &Path, loading_mode: MapLoadingMode) -> Result<Array2D<f64>> {
+    crate::tiff::load_map_from_tiff::<f64>(
+        path,
+        match loading_mode {
+            MapLoadingMode::FixMe | MapLoadingMode::OffByOne => false,
+            MapLoadingMode::Strict => true,
+        },
+    )
+    .with_context(|| format!("Failed to load the sample map from {:?}.", path))
+}
+
+pub fn load_sample_mask(path: &Path) -> Result<Array2D<bool>> {
+    let mask = crate::tiff::load_map_from_tiff::<u32>(path, false)
+        .with_context(|| format!("Failed to load the sample mask from {:?}.", path))?;
+
+    // All non-zero cells of the mask are sampled
+    Ok(Array2D::from_iter_row_major(
+        mask.elements_row_major_iter().map(|cell| *cell > 0),
+        mask.num_rows(),
+        mask.num_columns(),
+    )
+    .unwrap())
+}
+
 pub fn load_habitat_map(
     path: &Path,
     dispersal: &mut Array2D<f64>,
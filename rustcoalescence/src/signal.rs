@@ -0,0 +1,87 @@
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use anyhow::Result;
+
+use necsim_partitioning_core::interrupt::request_interrupt;
+
+static SIGNAL: AtomicI32 = AtomicI32::new(0);
+
+/// Installs handlers for SIGINT and SIGTERM which interrupt the simulation,
+///  such that it stops early but still finalises its reporters. A second
+///  signal terminates the process immediately.
+pub fn install_interrupt_handlers() -> Result<()> {
+    for signal in [libc::SIGINT, libc::SIGTERM] {
+        let handler = handle_interrupt_signal as extern "C" fn(libc::c_int);
+
+        if unsafe { libc::signal(signal, handler as libc::sighandler_t) } == libc::SIG_ERR {
+            anyhow::bail!(
+                "Failed to install the handler for signal {}: {}",
+                signal,
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Interrupts the simulation once the `wall_time` has elapsed, without
+///  affecting the exit status of the process
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+pub fn interrupt_after(wall_time: std::time::Duration) {
+    std::thread::spawn(move || {
+        std::thread::sleep(wall_time);
+
+        request_interrupt();
+    });
+}
+
+/// Checks if this process has received a SIGINT or SIGTERM signal
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+pub fn was_signalled() -> bool {
+    SIGNAL.load(Ordering::SeqCst) != 0
+}
+
+/// Adopts SIGINT as the received signal iff this process has not received
+///  any, e.g. because it was only interrupted by the other partitions' vote
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+pub fn adopt_interrupt() {
+    let _ = SIGNAL.compare_exchange(0, libc::SIGINT, Ordering::SeqCst, Ordering::SeqCst);
+}
+
+/// The SIGINT or SIGTERM signal which this process has received, if any
+pub fn received() -> Option<libc::c_int> {
+    match SIGNAL.load(Ordering::SeqCst) {
+        0 => None,
+        signal => Some(signal),
+    }
+}
+
+/// Exit status of the process iff the simulation has been interrupted by a
+///  signal, which follows the shell convention of 128 plus the signal number
+pub fn interrupted_exit_code() -> Option<i32> {
+    received().map(|signal| 128 + signal)
+}
+
+extern "C" fn handle_interrupt_signal(signal: libc::c_int) {
+    // Only async-signal-safe operations may be performed here
+    SIGNAL.store(signal, Ordering::SeqCst);
+
+    request_interrupt();
+
+    unsafe {
+        libc::signal(signal, libc::SIG_DFL);
+    }
+}
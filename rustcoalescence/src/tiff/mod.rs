@@ -125,3 +125,38 @@ pub fn load_map_from_tiff<D: TiffDataType>(path: &Path, strict_load: bool) -> Re
 
     Ok(Array2D::from_row_major(&image_data, height as usize, width as usize).unwrap())
 }
+
+#[cfg(any(
+    feature = "rustcoalescence-algorithms-monolithic",
+    feature = "rustcoalescence-algorithms-independent",
+    feature = "rustcoalescence-algorithms-cuda"
+))]
+#[allow(clippy::module_name_repetitions)]
+/// Writes a 2D `u32` map to a new TIFF file at `path`.
+///
+/// If `no_data` is `Some(_)`, it is recorded as the GDAL no data value.
+pub fn write_map_to_tiff(path: &Path, map: &Array2D<u32>, no_data: Option<u32>) -> Result<()> {
+    use tiff::encoder::{colortype::Gray32, TiffEncoder};
+
+    let file = File::create(path).context("Could not create file.")?;
+
+    let mut encoder = TiffEncoder::new(file).context("Could not encode TIFF file.")?;
+
+    #[allow(clippy::cast_possible_truncation)]
+    let mut image = encoder
+        .new_image::<Gray32>(map.num_columns() as u32, map.num_rows() as u32)
+        .context("Could not create TIFF image.")?;
+
+    if let Some(no_data) = no_data {
+        image
+            .encoder()
+            .write_tag(Tag::GdalNodata, no_data.to_string().as_str())
+            .context("Could not write GDAL no data tag.")?;
+    }
+
+    image
+        .write_data(&map.as_row_major())
+        .context("Could not write image data.")?;
+
+    Ok(())
+}